@@ -0,0 +1,91 @@
+//! Benchmark of diffing trees with a lot of attributes,
+//! which also reports the number of allocations done per diff
+#[macro_use]
+extern crate criterion;
+
+use criterion::Criterion;
+use sauron::{
+    html::{
+        attributes::{title, *},
+        *,
+    },
+    vdom::diff,
+    vdom::Node,
+};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// counts the number of allocations
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// 100 rows with 20 cells each, where every cell has several attributes
+fn attribute_heavy_view(selected: usize) -> Node<()> {
+    table(
+        vec![class("grid"), id("grid")],
+        (0..100)
+            .map(|row| {
+                tr(
+                    vec![
+                        class("row"),
+                        class(if row == selected { "selected" } else { "" }),
+                        key(row),
+                    ],
+                    (0..20)
+                        .map(|col| {
+                            td(
+                                vec![
+                                    class("cell"),
+                                    id(format!("cell-{row}-{col}")),
+                                    title(format!("row {row}, column {col}")),
+                                    tabindex(-1),
+                                    colspan(1),
+                                    styles([("width", "40px"), ("height", "20px")]),
+                                ],
+                                vec![text(col)],
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn diff_attribute_heavy(old: &Node<()>, new: &Node<()>) {
+    let patches = diff(old, new);
+    assert_eq!(patches.len(), 2);
+}
+
+fn bench_diff_attributes(c: &mut Criterion) {
+    let old = attribute_heavy_view(1);
+    let new = attribute_heavy_view(2);
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    diff_attribute_heavy(&old, &new);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    println!("allocations per attribute heavy diff: {allocations}");
+
+    c.bench_function("diff_attribute_heavy", |b| {
+        b.iter(|| diff_attribute_heavy(&old, &new))
+    });
+}
+
+criterion_group!(benches, bench_diff_attributes);
+
+criterion_main!(benches);
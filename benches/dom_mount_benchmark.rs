@@ -0,0 +1,71 @@
+//! Compares building the rows in a `DocumentFragment` before attaching it,
+//! which is how the program mounts its view, against appending the rows
+//! one by one to an element which is already in the document.
+//!
+//! Run it in the browser with `cargo bench --target wasm32-unknown-unknown --bench dom_mount_benchmark`
+use sauron::*;
+use wasm_bindgen_test::{wasm_bindgen_bench, wasm_bindgen_test_configure, Criterion};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const ROWS: usize = 1000;
+
+struct Rows;
+
+impl Application for Rows {
+    type MSG = ();
+
+    fn update(&mut self, _msg: ()) -> Cmd<()> {
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<()> {
+        ul([], (0..ROWS).map(|row| li([], [text(row)])))
+    }
+}
+
+fn mount_node() -> web_sys::Element {
+    let document = sauron_core::dom::document();
+    let mount_node = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&mount_node).unwrap();
+    mount_node
+}
+
+fn create_row(row: usize) -> web_sys::Element {
+    let li = sauron_core::dom::document().create_element("li").unwrap();
+    li.set_text_content(Some(&row.to_string()));
+    li
+}
+
+#[wasm_bindgen_bench]
+fn bench_mount_rows(c: &mut Criterion) {
+    c.bench_function("append 1000 rows directly", |b| {
+        b.iter(|| {
+            let direct = mount_node();
+            for row in 0..ROWS {
+                direct.append_child(&create_row(row)).unwrap();
+            }
+            direct.remove();
+        })
+    });
+
+    c.bench_function("append 1000 rows in a fragment", |b| {
+        b.iter(|| {
+            let batched = mount_node();
+            let fragment = sauron_core::dom::document().create_document_fragment();
+            for row in 0..ROWS {
+                fragment.append_child(&create_row(row)).unwrap();
+            }
+            batched.append_child(&fragment).unwrap();
+            batched.remove();
+        })
+    });
+
+    c.bench_function("mount 1000 rows with the program", |b| {
+        b.iter(|| {
+            let mount = mount_node();
+            let _program = Program::append_to_mount(Rows, &mount);
+            mount.remove();
+        })
+    });
+}
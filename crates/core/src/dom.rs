@@ -12,37 +12,48 @@ mod effects;
 use cfg_if::cfg_if;
 
 cfg_if! {if #[cfg(feature = "with-dom")] {
-    pub use application::{Application, Measurements, SkipDiff, skip_if, skip_diff, SkipPath};
+    pub use application::{Application, Embedded, Measurements, SkipDiff, skip_if, skip_diff, SkipPath};
     pub use component::{stateful_component, StatefulComponent, StatefulModel, StatelessModel};
     pub use component::component;
     pub use dom_patch::{DomPatch, PatchVariant};
     pub use dom_attr::{DomAttr, DomAttrValue, GroupedDomAttrValues};
     pub use http::Http;
-    pub use program::{MountAction, MountTarget, Program, MountProcedure};
+    pub use file::FileInfo;
+    pub use measure::RectInfo;
+    pub use program::{MountAction, MountTarget, Program, MountProcedure, DEFAULT_MAX_UPDATES_PER_FRAME};
+    #[cfg(feature = "test-fixtures")]
+    pub use program::SimulatedEvent;
     pub use util::{
-        document, history, now, performance,
+        current_url, document, history, now, performance,
         spawn_local, window, inject_style,
     };
     pub use raf::{request_animation_frame, AnimationFrameHandle};
     pub use ric::{request_idle_callback, IdleCallbackHandle, IdleDeadline};
     pub use timeout::{delay, request_timeout_callback, TimeoutCallbackHandle};
     pub use dispatch::Dispatch;
+    pub use context::use_context;
     use crate::dom::events::MountEvent;
     pub use window::Window;
-    pub use dom_node::DomNode;
+    pub use dom_node::{create_dom_node, DomNode};
     pub use document::Document;
     pub use time::Time;
 
     mod application;
+    mod context;
     pub mod dispatch;
     mod dom_node;
     mod dom_patch;
     mod dom_attr;
     pub mod events;
+    mod file;
+    pub mod focus;
     mod http;
+    mod measure;
     mod program;
     pub mod util;
     mod raf;
+    mod intersection_observer;
+    mod resize_observer;
     mod ric;
     mod window;
     mod document;
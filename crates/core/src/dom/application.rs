@@ -1,7 +1,10 @@
 use crate::dom::Cmd;
 use crate::vdom::Node;
+pub use embedded::Embedded;
 pub use skip_diff::{skip_if, SkipDiff, SkipPath};
 
+mod embedded;
+
 ///
 pub mod skip_diff;
 
@@ -21,6 +24,9 @@ pub trait Application: Sized + 'static {
     /// The update function returns a Dispatch, which can be executed by the runtime.
     ///
     /// Called each time an action is triggered from the view
+    ///
+    /// An update which has no command to execute can end with `().into()`,
+    /// since `Cmd` can be converted from `()`.
     fn update(&mut self, _msg: Self::MSG) -> Cmd<Self::MSG>;
 
     /// Returns a node on how the component is presented.
@@ -36,6 +42,53 @@ pub trait Application: Sized + 'static {
         vec![]
     }
 
+    /// The title of the document for the current state of the application,
+    /// `None` leaves the title of the document as it is.
+    ///
+    /// This is applied to the document right after the app is initialized,
+    /// and each time the view is updated, right after the patches of the new view are queued.
+    fn title(&self) -> Option<String> {
+        None
+    }
+
+    /// The `<meta>` tags of the document for the current state of the application,
+    /// as pairs of the `name` and the `content` of the meta tag, ie: `("description", "..")`.
+    ///
+    /// The meta tags with the same name in the document head are updated, otherwise they are
+    /// created. The meta tags which are no longer returned are left as they are.
+    /// This is applied at the same time as [`Application::title`].
+    fn meta(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+
+    /// The url of the route which the application is showing, such as `/todos/1?filter=done`,
+    /// `None` when the application doesn't use the integrated routing, which is the default.
+    ///
+    /// When this is `Some` when the app is mounted, the program listens to the clicks on the
+    /// links in the view and to the back and forward buttons of the browser,
+    /// and calls [`Application::on_route_change`] with the url which the user navigated to.
+    /// After each update, the route is pushed into the browser history when it is different
+    /// from the current url, so changing the route in `update` navigates to it.
+    fn route(&self) -> Option<String> {
+        None
+    }
+
+    /// Create the msg for the `url` which the user navigated to, the `url` is the path
+    /// with the query string and the hash, such as `/todos/1?filter=done`.
+    /// This is also called with the current url when the app is mounted.
+    ///
+    /// Returning `None` lets the browser load the url as usual, such as for the links to the
+    /// pages which are not part of the app. For a url which the app doesn't recognize,
+    /// return a msg which shows a not found page instead, since the browser would load
+    /// the same page again.
+    ///
+    /// The links which are always left to the browser are the links to another origin,
+    /// the links to a hash in the current page, the links with a `target` such as `_blank`,
+    /// a `download` or a `rel="external"` attribute, and the clicks with a modifier key.
+    fn on_route_change(&self, _url: &str) -> Option<Self::MSG> {
+        None
+    }
+
     /// This is called after dispatching and updating the dom for the component
     /// This is for diagnostic and performance measurement purposes.
     ///
@@ -0,0 +1,82 @@
+use crate::dom::{Application, Cmd};
+use crate::vdom::Node;
+use std::rc::Rc;
+
+/// An application which is embedded inside a parent application.
+///
+/// The msgs of the child are wrapped into the msg of the parent with the mapper,
+/// including the msgs produced by the child's commands. The parent routes them back
+/// to the child through its own `update`, so each child instance only receives its own msgs.
+///
+/// # Example
+/// ```rust,ignore
+/// enum Msg {
+///     Counter(usize, counter::Msg),
+/// }
+///
+/// struct App {
+///     counters: Vec<Embedded<Counter, Msg>>,
+/// }
+///
+/// impl Application for App {
+///     type MSG = Msg;
+///
+///     fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+///         match msg {
+///             Msg::Counter(index, msg) => self.counters[index].update(msg),
+///         }
+///     }
+///
+///     fn view(&self) -> Node<Msg> {
+///         div([], self.counters.iter().map(|counter| counter.view()))
+///     }
+/// }
+///
+/// let counters = (0..3)
+///     .map(|index| Embedded::new(Counter::default(), move |msg| Msg::Counter(index, msg)))
+///     .collect();
+/// ```
+pub struct Embedded<APP, MSG>
+where
+    APP: Application,
+{
+    /// the embedded child application
+    pub app: APP,
+    mapper: Rc<dyn Fn(APP::MSG) -> MSG>,
+}
+
+impl<APP, MSG> Embedded<APP, MSG>
+where
+    APP: Application,
+    MSG: 'static,
+{
+    /// embed `app`, where its msgs are wrapped into the parent msg with `mapper`
+    pub fn new(app: APP, mapper: impl Fn(APP::MSG) -> MSG + 'static) -> Self {
+        Self {
+            app,
+            mapper: Rc::new(mapper),
+        }
+    }
+
+    /// the init of the child application, where the resulting msgs are mapped to the parent msg.
+    /// The parent should call this from its own `init`
+    pub fn init(&mut self) -> Cmd<MSG> {
+        self.app.init().map_msg(self.mapper_fn())
+    }
+
+    /// update the child application with its msg and map the resulting Cmd to the parent msg
+    pub fn update(&mut self, msg: APP::MSG) -> Cmd<MSG> {
+        self.app.update(msg).map_msg(self.mapper_fn())
+    }
+
+    /// the view of the child application, where the msgs of the event listeners are mapped
+    /// to the parent msg
+    pub fn view(&self) -> Node<MSG> {
+        self.app.view().map_msg(self.mapper_fn())
+    }
+
+    fn mapper_fn(&self) -> impl Fn(APP::MSG) -> MSG + Clone + 'static {
+        let mapper = Rc::clone(&self.mapper);
+        move |msg| mapper(msg)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::vdom::TreePath;
+use crate::vdom::{DiffOptions, TreePath};
 
 /// specifies how attributes will be skipped
 #[derive(Debug, PartialEq, Clone)]
@@ -106,13 +106,15 @@ pub fn skip_if(shall: bool, children: impl IntoIterator<Item = SkipDiff>) -> Ski
 pub struct SkipPath {
     pub(crate) path: TreePath,
     pub(crate) skip_diff: Option<SkipDiff>,
+    pub(crate) options: DiffOptions,
 }
 
 impl SkipPath {
-    pub(crate) fn new(path: TreePath, skip_diff: SkipDiff) -> Self {
+    pub(crate) fn new(path: TreePath, skip_diff: SkipDiff, options: DiffOptions) -> Self {
         Self {
             path,
             skip_diff: Some(skip_diff),
+            options,
         }
     }
 
@@ -124,6 +126,7 @@ impl SkipPath {
             } else {
                 None
             },
+            options: self.options,
         }
     }
 
@@ -132,6 +135,7 @@ impl SkipPath {
             path: self.path.backtrack(),
             //TODO: here the skip_diff can not back track as we lose that info already
             skip_diff: None,
+            options: self.options,
         }
     }
 }
@@ -83,6 +83,28 @@ where
     }
 }
 
+/// An empty Cmd from `()`, so an update with nothing else to do can just return `().into()`,
+/// or pass the result of a function which returns `()`.
+///
+/// Example:
+/// ```rust,ignore
+/// fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+///     match msg {
+///         Msg::Increment => self.count += 1,
+///         Msg::Decrement => self.count -= 1,
+///     }
+///     ().into()
+/// }
+/// ```
+impl<MSG> From<()> for Cmd<MSG>
+where
+    MSG: 'static,
+{
+    fn from(_: ()) -> Self {
+        Cmd::none()
+    }
+}
+
 impl<MSG> From<Effects<MSG, ()>> for Cmd<MSG>
 where
     MSG: 'static,
@@ -0,0 +1,78 @@
+//! Share values such as the theme or the locale with the views of the descendants,
+//! without passing them down through each of the view functions.
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+};
+
+thread_local! {
+    /// the contexts of the programs whose view is being called, the innermost is last
+    static CONTEXT_STACK: RefCell<Vec<Contexts>> = const { RefCell::new(vec![]) };
+}
+
+/// The context values provided to a program, at most one value for each type
+#[derive(Default, Clone)]
+pub(crate) struct Contexts {
+    values: HashMap<TypeId, Rc<dyn Any>>,
+}
+
+impl Contexts {
+    /// provide the `value`, replacing the previous value of the same type
+    pub(crate) fn insert<T: 'static>(&mut self, value: T) {
+        self.values.insert(TypeId::of::<T>(), Rc::new(value));
+    }
+
+    fn get<T: 'static>(&self) -> Option<Rc<T>> {
+        let value = self.values.get(&TypeId::of::<T>())?;
+        Rc::clone(value).downcast::<T>().ok()
+    }
+
+    /// call `f` with these contexts available to [`use_context`]
+    pub(crate) fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        /// pop the contexts even when `f` panics
+        struct PopGuard;
+        impl Drop for PopGuard {
+            fn drop(&mut self) {
+                CONTEXT_STACK.with_borrow_mut(|stack| stack.pop());
+            }
+        }
+        CONTEXT_STACK.with_borrow_mut(|stack| stack.push(self.clone()));
+        let _guard = PopGuard;
+        f()
+    }
+}
+
+/// Get the context value of type `T` which is provided with [`Program::provide`].
+///
+/// The context is only available while the `view` of the program is being called,
+/// which includes the view functions and the stateless components the view calls.
+/// Calling this from anywhere else, such as in `update`, in an event listener or in a `Cmd`,
+/// returns `None`, so the value should be read in the view and passed into the msg if needed.
+///
+/// The context is kept per thread, and the view of a program which is called from
+/// the view of another program sees the contexts of both, where the value provided by the
+/// inner program wins. A stateful component is rendered on its own, after the view of its
+/// parent, so it only sees the values provided to its own program.
+///
+/// # Example
+/// ```rust,ignore
+/// struct Theme {
+///     accent: String,
+/// }
+///
+/// fn view_button(label: &str) -> Node<Msg> {
+///     let accent = use_context::<Theme>().map(|theme| theme.accent.clone());
+///     button([style! {color: accent.unwrap_or_default()}], [text(label)])
+/// }
+///
+/// let mut program = Program::new(App::default());
+/// program.provide(Theme { accent: "tomato".into() });
+/// program.mount(&mount_node, MountProcedure::append());
+/// ```
+///
+/// [`Program::provide`]: crate::dom::Program::provide
+pub fn use_context<T: 'static>() -> Option<Rc<T>> {
+    CONTEXT_STACK.with_borrow(|stack| stack.iter().rev().find_map(Contexts::get::<T>))
+}
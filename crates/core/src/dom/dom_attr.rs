@@ -1,15 +1,16 @@
+use crate::dom::dom_node::EventClosure;
 use crate::vdom::AttributeName;
+use crate::vdom::EventOptions;
 use crate::vdom::Namespace;
 use crate::vdom::Style;
 use crate::vdom::Value;
 #[cfg(feature = "ensure-attr-set")]
 use crate::vdom::{CHECKED, DISABLED, OPEN, VALUE};
 use wasm_bindgen::intern;
-#[cfg(feature = "ensure-attr-set")]
 use wasm_bindgen::JsCast;
-use wasm_bindgen::{closure::Closure, JsValue};
+use wasm_bindgen::JsValue;
 use web_sys;
-use web_sys::Element;
+use web_sys::{CssStyleDeclaration, Element};
 #[cfg(feature = "ensure-attr-set")]
 use web_sys::{
     HtmlButtonElement, HtmlDataElement, HtmlDetailsElement, HtmlFieldSetElement, HtmlInputElement,
@@ -36,8 +37,8 @@ pub enum DomAttrValue {
     Simple(Value),
     /// a style
     Style(Vec<Style>),
-    /// event listeners
-    EventListener(Closure<dyn FnMut(web_sys::Event)>),
+    /// event listeners, together with the options of how it is attached
+    EventListener(EventClosure, EventOptions),
     /// an empty value, can also represents null values from JsValue
     Empty,
 }
@@ -45,8 +46,8 @@ pub enum DomAttrValue {
 /// a struct where the listeners, plain values, styles and function call values are grouped
 /// separately
 pub struct GroupedDomAttrValues {
-    /// the listeners of the event listeners
-    pub listeners: Vec<Closure<dyn FnMut(web_sys::Event)>>,
+    /// the listeners of the event listeners, together with the options of how it is attached
+    pub listeners: Vec<(EventClosure, EventOptions)>,
     /// plain attribute values
     pub plain_values: Vec<Value>,
     /// style attribute values
@@ -54,6 +55,13 @@ pub struct GroupedDomAttrValues {
 }
 
 impl DomAttr {
+    /// whether there is an event listener among the values of this attribute
+    pub(crate) fn has_listeners(&self) -> bool {
+        self.value
+            .iter()
+            .any(|v| matches!(v, DomAttrValue::EventListener(..)))
+    }
+
     /// return the values grouped into listeners, plain, styles and function calls
     pub(crate) fn group_values(self) -> GroupedDomAttrValues {
         let mut listeners = vec![];
@@ -67,8 +75,8 @@ impl DomAttr {
                 DomAttrValue::Style(s) => {
                     styles.extend(s);
                 }
-                DomAttrValue::EventListener(cb) => {
-                    listeners.push(cb);
+                DomAttrValue::EventListener(cb, options) => {
+                    listeners.push((cb, options));
                 }
                 DomAttrValue::Empty => (),
             }
@@ -100,6 +108,43 @@ impl DomAttr {
         }
     }
 
+    /// update the inline style of the element declaration by declaration,
+    /// removing the properties of the `old_styles` which are not in the `new_styles`
+    /// and setting the properties of the `new_styles` which are changed.
+    /// The other properties of the inline style, such as the ones set by javascript, are kept.
+    pub(crate) fn patch_element_style(
+        element: &Element,
+        old_styles: &[Style],
+        new_styles: &[Style],
+    ) -> Result<(), JsValue> {
+        let declaration: CssStyleDeclaration =
+            js_sys::Reflect::get(element, &JsValue::from_str("style"))?.unchecked_into();
+        for old_style in old_styles {
+            if !new_styles
+                .iter()
+                .any(|new_style| new_style.name == old_style.name)
+            {
+                declaration.remove_property(&old_style.name)?;
+            }
+        }
+        for new_style in new_styles {
+            if old_styles.contains(new_style) {
+                continue;
+            }
+            let value = new_style.value.to_string();
+            if let Some(value) = value.strip_suffix("!important") {
+                declaration.set_property_with_priority(
+                    &new_style.name,
+                    value.trim_end(),
+                    "important",
+                )?;
+            } else {
+                declaration.set_property(&new_style.name, &value)?;
+            }
+        }
+        Ok(())
+    }
+
     /// set simple values
     pub(crate) fn set_element_simple_values(
         element: &Element,
@@ -125,9 +170,10 @@ impl DomAttr {
                     Self::set_value_str(element, &merged_plain_values);
                     Self::set_numeric_values(element, &plain_values);
                 } else if *OPEN == attr_name {
+                    // a value other than a bool, such as `disabled="disabled"`, is present
                     let is_open: bool = plain_values
                         .first()
-                        .and_then(|v| v.as_bool())
+                        .map(|v| v.as_bool().unwrap_or(true))
                         .unwrap_or(false);
 
                     element
@@ -137,7 +183,7 @@ impl DomAttr {
                 } else if *CHECKED == attr_name {
                     let is_checked: bool = plain_values
                         .first()
-                        .and_then(|v| v.as_bool())
+                        .map(|v| v.as_bool().unwrap_or(true))
                         .unwrap_or(false);
 
                     element
@@ -147,7 +193,7 @@ impl DomAttr {
                 } else if *DISABLED == attr_name {
                     let is_disabled: bool = plain_values
                         .first()
-                        .and_then(|v| v.as_bool())
+                        .map(|v| v.as_bool().unwrap_or(true))
                         .unwrap_or(false);
 
                     element
@@ -185,7 +231,11 @@ impl DomAttr {
             DomAttr::set_disabled(element, false);
         }
         //actually remove the element
-        element.remove_attribute(intern(attr.name))?;
+        if let Some(namespace) = attr.namespace {
+            element.remove_attribute_ns(Some(namespace), intern(attr.name))?;
+        } else {
+            element.remove_attribute(intern(attr.name))?;
+        }
 
         Ok(())
     }
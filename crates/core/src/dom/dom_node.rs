@@ -1,5 +1,6 @@
 use crate::dom::component::StatelessModel;
 use crate::dom::DomAttr;
+use crate::dom::DomAttrValue;
 use crate::dom::GroupedDomAttrValues;
 use crate::dom::StatefulComponent;
 use crate::dom::StatefulModel;
@@ -7,10 +8,10 @@ use crate::html::lookup;
 use crate::vdom::TreePath;
 use crate::{
     dom::document,
-    dom::events::MountEvent,
+    dom::events::{create_node_ref_event, MountEvent, NODE_REF_EVENT},
     dom::{Application, Program},
     vdom,
-    vdom::{Attribute, Leaf},
+    vdom::{Attribute, EventOptions, Leaf, Style, PORTAL_TARGET},
 };
 use indexmap::IndexMap;
 use std::borrow::Cow;
@@ -21,8 +22,15 @@ use std::rc::Rc;
 use wasm_bindgen::{closure::Closure, JsCast, JsValue};
 use web_sys::{self, Node};
 
-pub(crate) type EventClosure = Closure<dyn FnMut(web_sys::Event)>;
-pub type NamedEventClosures = IndexMap<&'static str, EventClosure>;
+/// the attribute which holds the inline style of an element
+const STYLE: &str = "style";
+
+/// the closure of an event listener, which is shared by the elements
+/// when its callback has a stable id, see [`Callback::with_id`](crate::vdom::Callback::with_id)
+pub(crate) type EventClosure = Rc<Closure<dyn FnMut(web_sys::Event)>>;
+/// a weak reference to an [`EventClosure`], which doesn't keep the closure alive
+pub(crate) type WeakEventClosure = std::rc::Weak<Closure<dyn FnMut(web_sys::Event)>>;
+pub type NamedEventClosures = IndexMap<&'static str, (EventClosure, EventOptions)>;
 
 /// A counter part of the vdom Node
 /// This is needed, so that we can
@@ -47,6 +55,9 @@ pub enum DomInner {
         children: Rc<RefCell<Vec<DomNode>>>,
         /// determine if this element needs to dispatch a mount event
         has_mount_callback: bool,
+        /// the declarations of the `style` attribute which are set to the inline style of this
+        /// element, so only the changed declarations are updated in the next patch
+        inline_styles: Rc<RefCell<Vec<Style>>>,
     },
     /// text node
     Text(web_sys::Text),
@@ -65,6 +76,13 @@ pub enum DomInner {
         comp: Rc<RefCell<dyn StatefulComponent>>,
         dom_node: Rc<DomNode>,
     },
+    /// Portal, where the children are mounted into a different element
+    Portal {
+        /// takes the place of the portal in its parent
+        placeholder: web_sys::Comment,
+        /// the element which holds the children, appended into the portal target
+        container: Rc<DomNode>,
+    },
 }
 
 impl fmt::Debug for DomInner {
@@ -94,6 +112,7 @@ impl fmt::Debug for DomInner {
             Self::Comment(_) => write!(f, "Comment"),
             Self::Fragment { .. } => write!(f, "Fragment"),
             Self::StatefulComponent { .. } => write!(f, "StatefulComponent"),
+            Self::Portal { container, .. } => f.debug_tuple("Portal").field(container).finish(),
         }
     }
 }
@@ -117,6 +136,7 @@ impl From<web_sys::Node> for DomNode {
                         listeners: Rc::new(RefCell::new(None)),
                         children: Rc::new(RefCell::new(children)),
                         has_mount_callback: false,
+                        inline_styles: Rc::new(RefCell::new(vec![])),
                     },
                 }
             }
@@ -157,6 +177,9 @@ impl PartialEq for DomNode {
             (DomInner::Symbol(v), DomInner::Symbol(o)) => v == o,
             (DomInner::Comment(v), DomInner::Comment(o)) => v == o,
             (DomInner::StatefulComponent { .. }, DomInner::StatefulComponent { .. }) => todo!(),
+            (DomInner::Portal { placeholder: v, .. }, DomInner::Portal { placeholder: o, .. }) => {
+                v == o
+            }
             _ => false,
         }
     }
@@ -167,6 +190,7 @@ impl DomNode {
         match &self.inner {
             DomInner::Element { children, .. } => Some(children.borrow()),
             DomInner::Fragment { children, .. } => Some(children.borrow()),
+            DomInner::Portal { container, .. } => container.children(),
             _ => None,
         }
     }
@@ -201,6 +225,11 @@ impl DomNode {
         matches!(&self.inner, DomInner::StatefulComponent { .. })
     }
 
+    /// returns true if this is a portal
+    pub fn is_portal(&self) -> bool {
+        matches!(&self.inner, DomInner::Portal { .. })
+    }
+
     pub(crate) fn tag(&self) -> Option<String> {
         match &self.inner {
             DomInner::Element { element, .. } => Some(element.tag_name().to_lowercase()),
@@ -217,6 +246,7 @@ impl DomNode {
             DomInner::Symbol(_) => unreachable!("symbol should be handled separately"),
             DomInner::Comment(comment_node) => comment_node.clone().unchecked_into(),
             DomInner::StatefulComponent { dom_node, .. } => dom_node.as_node(),
+            DomInner::Portal { placeholder, .. } => placeholder.clone().unchecked_into(),
         }
     }
 
@@ -233,6 +263,17 @@ impl DomNode {
             DomInner::Symbol(_) => unreachable!("symbol should be handled separately"),
             DomInner::Comment(comment_node) => comment_node.clone().unchecked_into(),
             DomInner::StatefulComponent { dom_node, .. } => dom_node.as_element(),
+            DomInner::Portal { container, .. } => container.as_element(),
+        }
+    }
+
+    /// the element which the attributes of this node are set to,
+    /// which is the container of a portal
+    pub(crate) fn attributes_element(&self) -> Option<web_sys::Element> {
+        match &self.inner {
+            DomInner::Element { element, .. } => Some(element.clone()),
+            DomInner::Portal { container, .. } => container.attributes_element(),
+            _ => None,
         }
     }
 
@@ -283,6 +324,7 @@ impl DomNode {
                     children.borrow_mut().push(child);
                 }
             }
+            DomInner::Portal { container, .. } => container.append_children(for_append),
             _ => unreachable!(
                 "appending should only be called to Element and Fragment, found: {:#?}",
                 self
@@ -292,6 +334,9 @@ impl DomNode {
 
     /// Insert the DomNode `for_insert` before `self` DomNode
     pub(crate) fn insert_before(&self, target_element: &DomNode, for_insert: Vec<DomNode>) {
+        if let DomInner::Portal { container, .. } = &self.inner {
+            return container.insert_before(target_element, for_insert);
+        }
         let DomInner::Element { children, .. } = &self.inner else {
             unreachable!("parent must be an element");
         };
@@ -306,9 +351,8 @@ impl DomNode {
         // NOTE: This is not reverse since inserting the last insert_node will always be next
         // before the target element
         for insert_node in for_insert.iter() {
-            target_element
-                .as_element()
-                .insert_adjacent_element(intern("beforebegin"), &insert_node.as_element())
+            self.as_node()
+                .insert_before(&insert_node.as_node(), Some(&target_element.as_node()))
                 .expect("must insert before this element");
             insert_node.dispatch_mount_event();
         }
@@ -326,6 +370,9 @@ impl DomNode {
 
     /// Insert the DomNode `for_insert` after `self` DomNode
     pub(crate) fn insert_after(&self, target_element: &DomNode, for_insert: Vec<DomNode>) {
+        if let DomInner::Portal { container, .. } = &self.inner {
+            return container.insert_after(target_element, for_insert);
+        }
         let DomInner::Element { children, .. } = &self.inner else {
             unreachable!("parent must be an element");
         };
@@ -337,9 +384,9 @@ impl DomNode {
             }
         }
         for insert_node in for_insert.into_iter().rev() {
-            target_element
-                .as_element()
-                .insert_adjacent_element(intern("afterend"), &insert_node.as_element())
+            let next_sibling = target_element.as_node().next_sibling();
+            self.as_node()
+                .insert_before(&insert_node.as_node(), next_sibling.as_ref())
                 .expect("must insert after this element");
             insert_node.dispatch_mount_event();
 
@@ -364,9 +411,9 @@ impl DomNode {
                 }
                 if let Some(child_index) = child_index {
                     children.borrow_mut().remove(child_index);
-                    target_child
-                        .as_element()
-                        .replace_with_with_node_1(&replacement.as_node())
+                    target_child.release_removed();
+                    self.as_node()
+                        .replace_child(&replacement.as_node(), &target_child.as_node())
                         .expect("must replace child");
                     replacement.dispatch_mount_event();
                     children.borrow_mut().insert(child_index, replacement);
@@ -379,6 +426,7 @@ impl DomNode {
                     unreachable!("must find the child...");
                 }
             }
+            DomInner::Portal { container, .. } => container.replace_child(target_child, replacement),
             _ => todo!(),
         }
     }
@@ -410,6 +458,7 @@ impl DomNode {
                         .expect("remove child");
                 }
             }
+            DomInner::Portal { container, .. } => container.remove_children(for_remove),
             _ => todo!(),
         }
     }
@@ -420,6 +469,9 @@ impl DomNode {
             DomInner::Element {
                 element, children, ..
             } => {
+                for child in children.borrow().iter() {
+                    child.release_removed();
+                }
                 children.borrow_mut().clear();
                 // NOTE: It is faster to remove from the last
                 // This is removing the children of the actual node
@@ -430,6 +482,7 @@ impl DomNode {
                         .expect("must remove child");
                 }
             }
+            DomInner::Portal { container, .. } => container.clear_children(),
             _ => todo!(),
         }
     }
@@ -451,9 +504,16 @@ impl DomNode {
 
     /// set the attribute of the dom element
     pub fn set_dom_attr(&self, attr: DomAttr) -> Result<(), JsValue> {
+        // the portal target only tells where the portal is mounted into
+        if attr.name == *PORTAL_TARGET {
+            return Ok(());
+        }
         match &self.inner {
             DomInner::Element {
-                element, listeners, ..
+                element,
+                listeners,
+                inline_styles,
+                ..
             } => {
                 let attr_name = intern(attr.name);
                 let attr_namespace = attr.namespace;
@@ -464,22 +524,30 @@ impl DomNode {
                     styles,
                 } = attr.group_values();
 
-                Self::add_event_dom_listeners(element, attr_name, &event_callbacks)
-                    .expect("event listeners");
-                let is_none = listeners.borrow().is_none();
-                if is_none {
-                    let listener_closures: IndexMap<
-                        &'static str,
-                        Closure<dyn FnMut(web_sys::Event)>,
-                    > = IndexMap::from_iter(event_callbacks.into_iter().map(|c| (attr_name, c)));
-                    *listeners.borrow_mut() = Some(listener_closures);
-                } else if let Some(listeners) = listeners.borrow_mut().as_mut() {
-                    for event_cb in event_callbacks.into_iter() {
-                        listeners.insert(attr_name, event_cb);
+                {
+                    let mut listeners = listeners.borrow_mut();
+                    let listeners = listeners.get_or_insert_with(IndexMap::new);
+                    for (event_cb, options) in event_callbacks.into_iter() {
+                        // the replaced listener is detached first, since it could be
+                        // the same closure which is shared with the other elements
+                        if let Some((old_cb, old_options)) =
+                            listeners.insert(attr_name, (Rc::clone(&event_cb), options))
+                        {
+                            Self::remove_event_listener(element, attr_name, &old_cb, old_options)?;
+                        }
+                        Self::add_event_listener(element, attr_name, &event_cb, options)
+                            .expect("event listeners");
                     }
                 }
-
-                DomAttr::set_element_style(element, attr_name, styles);
+                if attr_name == STYLE && !styles.is_empty() {
+                    let old_styles = inline_styles.replace(styles.clone());
+                    DomAttr::patch_element_style(element, &old_styles, &styles)?;
+                } else {
+                    if attr_name == STYLE {
+                        inline_styles.borrow_mut().clear();
+                    }
+                    DomAttr::set_element_style(element, attr_name, styles);
+                }
                 DomAttr::set_element_simple_values(
                     element,
                     attr_name,
@@ -491,6 +559,9 @@ impl DomNode {
                 log::info!("applying attribute change for stateful component...{attr:?}");
                 comp.borrow_mut().attribute_changed(attr);
             }
+            DomInner::Portal { container, .. } => {
+                container.set_dom_attr(attr)?;
+            }
             _ => {
                 log::info!("set the dom attr for {self:?}, with dom_attr: {attr:?}");
                 unreachable!("should only be called for element");
@@ -500,35 +571,82 @@ impl DomNode {
     }
 
     pub(crate) fn remove_dom_attr(&self, attr: &DomAttr) -> Result<(), JsValue> {
-        let DomInner::Element { element, .. } = &self.inner else {
+        if attr.name == *PORTAL_TARGET {
+            return Ok(());
+        }
+        if let DomInner::Portal { container, .. } = &self.inner {
+            return container.remove_dom_attr(attr);
+        }
+        let DomInner::Element {
+            element,
+            inline_styles,
+            ..
+        } = &self.inner
+        else {
             unreachable!("expecting an element");
         };
-        DomAttr::remove_element_dom_attr(element, attr)
+        let is_structured_style = attr.name == STYLE
+            && attr
+                .value
+                .iter()
+                .all(|value| matches!(value, DomAttrValue::Style(_)));
+        if is_structured_style {
+            let old_styles = inline_styles.take();
+            DomAttr::patch_element_style(element, &old_styles, &[])?;
+            // only remove the attribute when there are no more inline styles
+            // such as the ones set by javascript
+            let is_blank = element
+                .get_attribute(STYLE)
+                .map(|style| style.trim().is_empty())
+                .unwrap_or(true);
+            if is_blank {
+                element.remove_attribute(STYLE)?;
+            }
+            return Ok(());
+        }
+        DomAttr::remove_element_dom_attr(element, attr)?;
+        Ok(())
     }
 
-    /// attach and event listener to an event target
-    pub(crate) fn add_event_dom_listeners(
-        target: &web_sys::EventTarget,
-        attr_name: &'static str,
-        event_listeners: &[EventClosure],
+    /// add a event listener to a target element,
+    /// `passive` and `capture` of the `options` are passed to `addEventListener`
+    pub(crate) fn add_event_listener(
+        event_target: &web_sys::EventTarget,
+        event_name: &str,
+        listener: &EventClosure,
+        options: EventOptions,
     ) -> Result<(), JsValue> {
-        for event_cb in event_listeners.iter() {
-            Self::add_event_listener(target, attr_name, event_cb)?;
+        let listener: &JsValue = (**listener).as_ref();
+        if options.passive || options.capture {
+            let listener_options = web_sys::AddEventListenerOptions::new();
+            listener_options.set_passive(options.passive);
+            listener_options.set_capture(options.capture);
+            event_target.add_event_listener_with_callback_and_add_event_listener_options(
+                intern(event_name),
+                listener.unchecked_ref(),
+                &listener_options,
+            )?;
+        } else {
+            event_target
+                .add_event_listener_with_callback(intern(event_name), listener.unchecked_ref())?;
         }
         Ok(())
     }
 
-    /// add a event listener to a target element
-    pub(crate) fn add_event_listener(
+    /// remove an event listener which is added with [`add_event_listener`](Self::add_event_listener),
+    /// the `capture` of the `options` has to be the same as when it is added
+    pub(crate) fn remove_event_listener(
         event_target: &web_sys::EventTarget,
         event_name: &str,
         listener: &EventClosure,
+        options: EventOptions,
     ) -> Result<(), JsValue> {
-        event_target.add_event_listener_with_callback(
+        let listener: &JsValue = (**listener).as_ref();
+        event_target.remove_event_listener_with_callback_and_bool(
             intern(event_name),
-            listener.as_ref().unchecked_ref(),
-        )?;
-        Ok(())
+            listener.unchecked_ref(),
+            options.capture,
+        )
     }
 
     /// always dispatch the mount event on stateful component
@@ -543,13 +661,95 @@ impl DomNode {
         }
     }
 
-    fn dispatch_mount_event(&self) {
+    pub(crate) fn dispatch_mount_event(&self) {
         if self.should_dispatch_mount_event() {
             let event_target: web_sys::EventTarget = self.as_element().unchecked_into();
             event_target
                 .dispatch_event(&MountEvent::create_web_event())
                 .expect("must be ok");
         }
+        self.dispatch_node_ref_event(true);
+    }
+
+    /// returns true if this element has a node ref attached to it
+    fn has_node_ref(&self) -> bool {
+        match &self.inner {
+            DomInner::Element { listeners, .. } => listeners
+                .borrow()
+                .as_ref()
+                .map(|listeners| listeners.contains_key(NODE_REF_EVENT))
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// notify the node ref of this element whether it is mounted or removed
+    fn dispatch_node_ref_event(&self, is_mounted: bool) {
+        if self.has_node_ref() {
+            let event_target: web_sys::EventTarget = self.as_element().unchecked_into();
+            event_target
+                .dispatch_event(&create_node_ref_event(is_mounted))
+                .expect("must be ok");
+        }
+    }
+
+    /// set the `value` property of the `select` element which is this node or the closest
+    /// ancestor of it, from the `value` attribute of the select.
+    ///
+    /// The options must exist before the value is assigned, since the browser selects the first
+    /// option instead when there is no option with that value yet.
+    /// So this is done after the children of the select are created or patched.
+    pub(crate) fn sync_select_value(&self) {
+        let DomInner::Element { element, .. } = &self.inner else {
+            return;
+        };
+        let Ok(Some(select)) = element.closest("select") else {
+            return;
+        };
+        let Some(value) = select.get_attribute("value") else {
+            return;
+        };
+        let select: &web_sys::HtmlSelectElement = select.unchecked_ref();
+        if select.value() != value {
+            select.set_value(&value);
+        }
+    }
+
+    /// clear the node refs of this node and all of its descendants,
+    /// this is called when the node is removed from the dom.
+    /// The observers of the removed elements are dropped from the resource registry of the program
+    /// after the render
+    pub(crate) fn release_removed(&self) {
+        if let DomInner::Portal { container, .. } = &self.inner {
+            container.release_removed();
+            container.as_element().remove();
+            return;
+        }
+        self.dispatch_node_ref_event(false);
+        if let Some(children) = self.children() {
+            for child in children.iter() {
+                child.release_removed();
+            }
+        }
+    }
+
+    /// remove this node from its parent, the children of a fragment are removed
+    /// since they have been moved into the parent of the fragment
+    pub(crate) fn detach(&self) {
+        match &self.inner {
+            DomInner::Fragment { children, .. } => {
+                for child in children.borrow().iter() {
+                    child.detach();
+                }
+            }
+            DomInner::Symbol(_) => (),
+            _ => {
+                let node = self.as_node();
+                if let Some(parent) = node.parent_node() {
+                    parent.remove_child(&node).expect("must remove child");
+                }
+            }
+        }
     }
 
     #[allow(unused)]
@@ -626,6 +826,10 @@ impl DomNode {
                 dom_node.render(buffer)?;
                 Ok(())
             }
+            DomInner::Portal { container, .. } => {
+                container.render(buffer)?;
+                Ok(())
+            }
         }
     }
 }
@@ -642,11 +846,42 @@ pub fn intern(s: &str) -> &str {
     s
 }
 
+/// Create the actual DOM node of the `node` and all of its descendants,
+/// with the event listeners dispatching their msgs to the `program`,
+/// for inserting it into the document manually, outside of the view of the program.
+///
+/// The created node is not tracked by the program, so it is not patched when the view changes
+/// and is not released when it is removed from the document.
+/// The program must be kept alive for the event listeners to dispatch their msgs,
+/// the listeners do nothing once the program is dropped.
+/// A node list or a fragment is created as a `DocumentFragment`,
+/// and a symbol such as `&nbsp;` is created as the text node of its character.
+/// # Example
+/// ```rust,ignore
+/// let row = tr([on_click(|_| Msg::RowClicked)], [td([], [text("imperative row")])]);
+/// let row_node = create_dom_node(&row, &program);
+/// table_body.append_child(&row_node)?;
+/// ```
+pub fn create_dom_node<APP>(node: &vdom::Node<APP::MSG>, program: &Program<APP>) -> web_sys::Node
+where
+    APP: Application + 'static,
+{
+    let dom_node = program.create_dom_node(node);
+    if let Some(symbol) = dom_node.as_symbol() {
+        let container = document()
+            .create_element("span")
+            .expect("must create element");
+        container.set_inner_html(symbol);
+        return container.first_child().expect("must have the symbol");
+    }
+    dom_node.as_node()
+}
+
 impl<APP> Program<APP>
 where
     APP: Application + 'static,
 {
-    /// Create a dom node
+    /// Create a dom node, see the [`create_dom_node`] function which creates a `web_sys::Node`
     pub fn create_dom_node(&self, node: &vdom::Node<APP::MSG>) -> DomNode {
         match node {
             vdom::Node::Element(elm) => self.create_element_node(elm),
@@ -675,22 +910,49 @@ where
                 listeners: Rc::new(RefCell::new(None)),
                 children: Rc::new(RefCell::new(vec![])),
                 has_mount_callback: elm.has_mount_callback(),
+                inline_styles: Rc::new(RefCell::new(vec![])),
             },
         };
-        let dom_attrs = attrs.iter().map(|a| self.convert_attr(a));
-        dom_node.set_dom_attrs(dom_attrs).expect("set dom attrs");
+        let dom_attrs = attrs.iter().map(|a| self.convert_attr(a)).collect();
+        self.set_node_attrs(&dom_node, dom_attrs)
+            .expect("set dom attrs");
         let children: Vec<DomNode> = elm
             .children()
             .iter()
             .map(|child| self.create_dom_node(child))
             .collect();
         dom_node.append_children(children);
+        if elm.tag() == &"select" {
+            dom_node.sync_select_value();
+        }
+        if let Some(target_selector) = elm.first_value(PORTAL_TARGET).and_then(|v| v.as_str()) {
+            return Self::create_portal(target_selector, dom_node);
+        }
         dom_node
     }
 
+    /// mount the `container` into the element matching the `target_selector`,
+    /// leaving a placeholder in its place
+    fn create_portal(target_selector: &str, container: DomNode) -> DomNode {
+        let Some(target) = document().query_selector(target_selector).ok().flatten() else {
+            log::warn!("no portal target found for: {target_selector}, mounting it in place");
+            return container;
+        };
+        target
+            .append_child(&container.as_node())
+            .expect("append portal");
+        container.dispatch_mount_event();
+        DomNode {
+            inner: DomInner::Portal {
+                placeholder: document().create_comment("portal"),
+                container: Rc::new(container),
+            },
+        }
+    }
+
     fn create_leaf_node(&self, leaf: &vdom::Leaf<APP::MSG>) -> DomNode {
         match leaf {
-            Leaf::Text(txt) => DomNode {
+            Leaf::Text(txt) | Leaf::PreText(txt) => DomNode {
                 inner: DomInner::Text(document().create_text_node(txt)),
             },
             Leaf::Symbol(symbol) => DomNode {
@@ -719,6 +981,20 @@ where
             Leaf::TemplatedView(view) => {
                 unreachable!("template view should not be created: {:#?}", view)
             }
+            Leaf::Shared(node) => self.create_dom_node(node),
+            Leaf::Suspense(suspense) => {
+                if let Some(task) = suspense.task.take() {
+                    let program = self.downgrade();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        if task.await {
+                            if let Some(program) = program.upgrade() {
+                                program.redraw();
+                            }
+                        }
+                    });
+                }
+                self.create_dom_node(&suspense.view)
+            }
             Leaf::DocType(_) => unreachable!("doc type is never converted"),
         }
     }
@@ -1,15 +1,21 @@
 use crate::dom;
 use crate::dom::dom_node;
 use crate::dom::dom_node::DomInner;
+use crate::dom::dom_node::EventClosure;
 use crate::dom::DomAttr;
 use crate::dom::DomAttrValue;
 use crate::dom::DomNode;
 use crate::dom::{Application, Program};
+use crate::vdom;
 use crate::vdom::ComponentEventCallback;
 use crate::vdom::EventCallback;
+use crate::vdom::EventOptions;
+use crate::vdom::RenderPatch;
+use crate::vdom::Renderer;
 use crate::vdom::TreePath;
-use crate::vdom::{Attribute, AttributeValue, Patch, PatchType};
+use crate::vdom::{Attribute, AttributeValue, Patch};
 use indexmap::IndexMap;
+use std::rc::Rc;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsValue;
 
@@ -29,54 +35,8 @@ pub struct DomPatch {
     pub patch_variant: PatchVariant,
 }
 
-/// patch variant
-#[derive(Debug)]
-pub enum PatchVariant {
-    /// Insert nodes before the target node
-    InsertBeforeNode {
-        /// nodes to be inserted before the target node
-        nodes: Vec<DomNode>,
-    },
-    /// Insert nodes after the target node
-    InsertAfterNode {
-        /// the nodes to be inserted after the target node
-        nodes: Vec<DomNode>,
-    },
-    /// Append nodes into the target node
-    AppendChildren {
-        /// the children nodes to be appended into the target node
-        children: Vec<DomNode>,
-    },
-    /// Add attributes to the target node
-    AddAttributes {
-        /// the attributes to be added to the target node
-        attrs: Vec<DomAttr>,
-    },
-    /// Remove attributes from the target node
-    RemoveAttributes {
-        /// the attributes names to be removed
-        attrs: Vec<DomAttr>,
-    },
-    /// Replace the target node with the replacement node
-    ReplaceNode {
-        /// the replacement node
-        replacement: Vec<DomNode>,
-    },
-    /// Remove the target node
-    RemoveNode,
-    /// Clear the children of the target node
-    ClearChildren,
-    /// Move the target node before the node specified in the path location
-    MoveBeforeNode {
-        /// before the node at this location
-        for_moving: Vec<DomNode>,
-    },
-    /// Move the target node after the node specified in the path location
-    MoveAfterNode {
-        /// after the node at this location
-        for_moving: Vec<DomNode>,
-    },
-}
+/// patch variant, where the nodes and attributes are created in the document
+pub type PatchVariant = RenderPatch<DomNode, DomAttr>;
 
 impl DomNode {
     pub(crate) fn find_node(&self, path: &mut TreePath) -> Option<DomNode> {
@@ -153,26 +113,47 @@ where
             value: attr
                 .value
                 .iter()
-                .filter_map(|v| self.convert_attr_value(v))
+                .filter_map(|v| self.convert_attr_value(attr.name, v))
                 .collect(),
         }
     }
 
-    fn convert_attr_value(&self, attr_value: &AttributeValue<APP::MSG>) -> Option<DomAttrValue> {
+    fn convert_attr_value(
+        &self,
+        attr_name: &'static str,
+        attr_value: &AttributeValue<APP::MSG>,
+    ) -> Option<DomAttrValue> {
         match attr_value {
             AttributeValue::Simple(v) => Some(DomAttrValue::Simple(v.clone())),
             AttributeValue::Style(v) => Some(DomAttrValue::Style(v.clone())),
-            AttributeValue::EventListener(v) => {
-                Some(DomAttrValue::EventListener(self.convert_event_listener(v)))
-            }
+            AttributeValue::EventListener(v) => Some(DomAttrValue::EventListener(
+                self.convert_event_listener(attr_name, v),
+                v.options(),
+            )),
             AttributeValue::ComponentEventListener(v) => Some(DomAttrValue::EventListener(
-                self.convert_component_event_listener(v),
+                Rc::new(self.convert_component_event_listener(v)),
+                v.options(),
             )),
             AttributeValue::Empty => None,
         }
     }
 
+    /// the closures of the listeners which callbacks have an id are shared by the elements,
+    /// see [`Callback::with_id`](crate::vdom::Callback::with_id)
     fn convert_event_listener(
+        &self,
+        event_name: &'static str,
+        event_listener: &EventCallback<APP::MSG>,
+    ) -> EventClosure {
+        self.listener_pool.borrow_mut().get_or_create(
+            event_name,
+            event_listener.pool_id(),
+            event_listener.options(),
+            || Rc::new(self.create_event_closure(event_listener)),
+        )
+    }
+
+    fn create_event_closure(
         &self,
         event_listener: &EventCallback<APP::MSG>,
     ) -> Closure<dyn FnMut(web_sys::Event)> {
@@ -180,9 +161,18 @@ where
         let event_listener = event_listener.clone();
         let closure: Closure<dyn FnMut(web_sys::Event)> =
             Closure::new(move |event: web_sys::Event| {
-                let msg = event_listener.emit(dom::Event::from(event));
+                let options = event_listener.options();
+                apply_event_options(&event, options);
+                // a listener which produces no msg doesn't trigger an update
+                let Some(msg) = event_listener.try_emit(dom::Event::from(event)) else {
+                    return;
+                };
                 let mut program = program.upgrade().expect("must upgrade");
-                program.dispatch(msg);
+                if options.dispatch_sync {
+                    program.dispatch_sync(msg);
+                } else {
+                    program.dispatch(msg);
+                }
             });
         closure
     }
@@ -194,6 +184,7 @@ where
         let component_callback = component_callback.clone();
         let closure: Closure<dyn FnMut(web_sys::Event)> =
             Closure::new(move |event: web_sys::Event| {
+                apply_event_options(&event, component_callback.options());
                 component_callback.emit(dom::Event::from(event));
             });
         closure
@@ -216,11 +207,17 @@ where
             .collect();
 
         let nodes_lookup = target_node.find_all_nodes(&nodes_to_find);
+        let mount_node = self.mount_node.borrow();
 
         let dom_patches:Vec<DomPatch> = patches.iter().map(|patch|{
             let patch_path = patch.path();
             let patch_tag = patch.tag();
             if let Some((target_node, target_parent)) = nodes_lookup.get(patch_path) {
+                // the root node is replaced in the mount node
+                let target_parent = match mount_node.as_ref() {
+                    Some(mount_node) if patch_path.is_empty() => mount_node,
+                    _ => target_parent,
+                };
                 let target_tag = target_node.tag();
                 if let (Some(patch_tag), Some(target_tag)) = (patch_tag, target_tag) {
                     if **patch_tag != target_tag{
@@ -245,130 +242,15 @@ where
         target_parent: &DomNode,
         patch: &Patch<APP::MSG>,
     ) -> DomPatch {
-        let target_element = target_element.clone();
-        let target_parent = target_parent.clone();
-        let Patch {
-            patch_path,
-            patch_type,
-            ..
-        } = patch;
-
-        let patch_path = patch_path.clone();
-
-        match patch_type {
-            PatchType::InsertBeforeNode { nodes } => {
-                let nodes = nodes
-                    .iter()
-                    .map(|for_insert| self.create_dom_node(for_insert))
-                    .collect();
-                DomPatch {
-                    patch_path,
-                    target_element,
-                    target_parent,
-                    patch_variant: PatchVariant::InsertBeforeNode { nodes },
-                }
-            }
-            PatchType::InsertAfterNode { nodes } => {
-                let nodes = nodes
-                    .iter()
-                    .map(|for_insert| self.create_dom_node(for_insert))
-                    .collect();
-                DomPatch {
-                    patch_path,
-                    target_element,
-                    target_parent,
-                    patch_variant: PatchVariant::InsertAfterNode { nodes },
-                }
-            }
-
-            PatchType::AddAttributes { attrs } => {
-                // we merge the attributes here prior to conversion
-                let attrs = Attribute::merge_attributes_of_same_name(attrs.iter().copied());
-                DomPatch {
-                    patch_path,
-                    target_element,
-                    target_parent,
-                    patch_variant: PatchVariant::AddAttributes {
-                        attrs: attrs.iter().map(|a| self.convert_attr(a)).collect(),
-                    },
-                }
-            }
-            PatchType::RemoveAttributes { attrs } => DomPatch {
-                patch_path,
-                target_element,
-                target_parent,
-                patch_variant: PatchVariant::RemoveAttributes {
-                    attrs: attrs.iter().map(|a| self.convert_attr(a)).collect(),
-                },
-            },
-
-            PatchType::ReplaceNode { replacement } => {
-                let replacement = replacement
-                    .iter()
-                    .map(|node| self.create_dom_node(node))
-                    .collect();
-                DomPatch {
-                    patch_path,
-                    target_element,
-                    target_parent,
-                    patch_variant: PatchVariant::ReplaceNode { replacement },
-                }
-            }
-            PatchType::RemoveNode => DomPatch {
-                patch_path,
-                target_element,
-                target_parent,
-                patch_variant: PatchVariant::RemoveNode,
-            },
-            PatchType::ClearChildren => DomPatch {
-                patch_path,
-                target_element,
-                target_parent,
-                patch_variant: PatchVariant::ClearChildren,
-            },
-            PatchType::MoveBeforeNode { nodes_path } => {
-                let for_moving = nodes_path
-                    .iter()
-                    .map(|path| {
-                        let (node, _) = nodes_lookup.get(path).expect("must have found the node");
-                        node.clone()
-                    })
-                    .collect();
-                DomPatch {
-                    patch_path,
-                    target_element,
-                    target_parent,
-                    patch_variant: PatchVariant::MoveBeforeNode { for_moving },
-                }
-            }
-            PatchType::MoveAfterNode { nodes_path } => {
-                let for_moving = nodes_path
-                    .iter()
-                    .map(|path| {
-                        let (node, _) = nodes_lookup.get(path).expect("must have found the node");
-                        node.clone()
-                    })
-                    .collect();
-                DomPatch {
-                    patch_path,
-                    target_element,
-                    target_parent,
-                    patch_variant: PatchVariant::MoveAfterNode { for_moving },
-                }
-            }
-            PatchType::AppendChildren { children } => {
-                let children = children
-                    .iter()
-                    .map(|for_insert| self.create_dom_node(for_insert))
-                    .collect();
-
-                DomPatch {
-                    patch_path,
-                    target_element,
-                    target_parent,
-                    patch_variant: PatchVariant::AppendChildren { children },
-                }
-            }
+        let patch_variant = RenderPatch::create(self, patch, |path| {
+            let (node, _) = nodes_lookup.get(path).expect("must have found the node");
+            node.clone()
+        });
+        DomPatch {
+            patch_path: patch.patch_path.clone(),
+            target_element: target_element.clone(),
+            target_parent: target_parent.clone(),
+            patch_variant,
         }
     }
 
@@ -395,94 +277,229 @@ where
             patch_variant,
         } = dom_patch;
 
-        match patch_variant {
-            PatchVariant::InsertBeforeNode { nodes } => {
-                target_parent.insert_before(&target_element, nodes);
+        // the element which children are changed by this patch
+        let changed_parent = match &patch_variant {
+            PatchVariant::AddAttributes { .. } | PatchVariant::RemoveAttributes { .. } => None,
+            PatchVariant::AppendChildren { .. } | PatchVariant::ClearChildren => {
+                Some(target_element.clone())
             }
+            _ => Some(target_parent.clone()),
+        };
 
-            PatchVariant::InsertAfterNode { nodes } => {
-                target_parent.insert_after(&target_element, nodes);
-            }
-            PatchVariant::AppendChildren { children } => {
-                target_element.append_children(children);
+        if let PatchVariant::ReplaceNode { replacement } = &patch_variant {
+            if patch_path.is_empty() {
+                *self.root_node.borrow_mut() = Some(replacement[0].clone());
             }
+        }
+        patch_variant.apply(self, &target_parent, &target_element)?;
+        // the options of a select may have changed, so its value is assigned again
+        if let Some(changed_parent) = changed_parent {
+            changed_parent.sync_select_value();
+        }
+        Ok(())
+    }
+}
 
-            PatchVariant::AddAttributes { attrs } => {
-                target_element.set_dom_attrs(attrs).unwrap();
-            }
-            PatchVariant::RemoveAttributes { attrs } => {
-                for attr in attrs.iter() {
-                    for att_value in attr.value.iter() {
-                        match att_value {
-                            DomAttrValue::Simple(_) => {
-                                target_element.remove_dom_attr(attr)?;
-                            }
-                            // it is an event listener
-                            DomAttrValue::EventListener(_) => {
-                                let DomInner::Element { listeners, .. } = &target_element.inner
-                                else {
-                                    unreachable!("must be an element");
-                                };
-                                if let Some(listener) = listeners.borrow_mut().as_mut() {
-                                    listener.retain(|event, _| *event != attr.name)
-                                }
-                            }
-                            DomAttrValue::Style(_) => {
-                                target_element.remove_dom_attr(attr)?;
-                            }
-                            DomAttrValue::Empty => (),
+impl<APP> Program<APP>
+where
+    APP: Application + 'static,
+{
+    /// set the attributes of the `target` node, and start the observers such as of `on_resize`
+    /// which are needed by the attributes
+    pub(crate) fn set_node_attrs(
+        &self,
+        target: &DomNode,
+        attrs: Vec<DomAttr>,
+    ) -> Result<(), JsValue> {
+        let observed: Vec<(&'static str, bool)> = attrs
+            .iter()
+            .map(|attr| (attr.name, attr.has_listeners()))
+            .collect();
+        target.set_dom_attrs(attrs)?;
+        if let Some(element) = target.attributes_element() {
+            self.observe_set_attrs(&element, observed)?;
+        }
+        Ok(())
+    }
+
+    /// remove the attributes of the `target` node, and stop the observers of the attributes
+    pub(crate) fn remove_node_attrs(
+        &self,
+        target: &DomNode,
+        attrs: &[DomAttr],
+    ) -> Result<(), JsValue> {
+        target.remove_dom_attrs(attrs)?;
+        if let Some(element) = target.attributes_element() {
+            self.observe_removed_attrs(&element, attrs.iter().map(|attr| attr.name))?;
+        }
+        Ok(())
+    }
+}
+
+impl DomNode {
+    /// remove the attributes of this element, the event listeners among the values
+    /// are removed from the listeners of the element
+    pub(crate) fn remove_dom_attrs(&self, attrs: &[DomAttr]) -> Result<(), JsValue> {
+        for attr in attrs.iter() {
+            for att_value in attr.value.iter() {
+                match att_value {
+                    DomAttrValue::Simple(_) => {
+                        self.remove_dom_attr(attr)?;
+                    }
+                    // it is an event listener
+                    DomAttrValue::EventListener(..) => {
+                        let target = match &self.inner {
+                            DomInner::Portal { container, .. } => container,
+                            _ => self,
+                        };
+                        let DomInner::Element {
+                            element, listeners, ..
+                        } = &target.inner
+                        else {
+                            unreachable!("must be an element");
+                        };
+                        let removed = listeners
+                            .borrow_mut()
+                            .as_mut()
+                            .and_then(|listeners| listeners.shift_remove(attr.name));
+                        // the closure could still be shared with the other elements,
+                        // so it is detached from this element instead of relying on it being dropped
+                        if let Some((listener, options)) = removed {
+                            DomNode::remove_event_listener(element, attr.name, &listener, options)?;
                         }
                     }
-                }
-            }
-
-            // This also removes the associated closures and event listeners to the node being replaced
-            // including the associated closures of the descendant of replaced node
-            // before it is actully replaced in the DOM
-            // TODO: make root node a Vec
-            PatchVariant::ReplaceNode { mut replacement } => {
-                let first_node = replacement.remove(0);
-
-                if target_element.is_fragment() {
-                    assert!(
-                        patch_path.is_empty(),
-                        "this should only happen to root node"
-                    );
-                    let mut mount_node = self.mount_node.borrow_mut();
-                    let mount_node = mount_node.as_mut().expect("must have a mount node");
-                    mount_node.append_children(vec![first_node.clone()]);
-                    mount_node.append_children(replacement);
-                } else {
-                    if patch_path.path.is_empty() {
-                        let mut mount_node = self.mount_node.borrow_mut();
-                        let mount_node = mount_node.as_mut().expect("must have a mount node");
-                        mount_node.replace_child(&target_element, first_node.clone());
-                    } else {
-                        target_parent.replace_child(&target_element, first_node.clone());
+                    DomAttrValue::Style(_) => {
+                        self.remove_dom_attr(attr)?;
                     }
-                    //insert the rest
-                    target_parent.insert_after(&first_node, replacement);
+                    DomAttrValue::Empty => (),
                 }
-                if patch_path.path.is_empty() {
-                    *self.root_node.borrow_mut() = Some(first_node);
-                }
-            }
-            PatchVariant::RemoveNode => {
-                target_parent.remove_children(&[&target_element]);
-            }
-            PatchVariant::ClearChildren => {
-                target_element.clear_children();
-            }
-            PatchVariant::MoveBeforeNode { for_moving } => {
-                target_parent.remove_children(&for_moving.iter().collect::<Vec<_>>());
-                target_parent.insert_before(&target_element, for_moving);
             }
+        }
+        Ok(())
+    }
+}
 
-            PatchVariant::MoveAfterNode { for_moving } => {
-                target_parent.remove_children(&for_moving.iter().collect::<Vec<_>>());
-                target_parent.insert_after(&target_element, for_moving);
-            }
+/// The browser DOM backend, which is the default [`Renderer`].
+/// The patches of the `Program` are applied with these same operations.
+impl<APP> Renderer<APP::MSG> for Program<APP>
+where
+    APP: Application + 'static,
+{
+    type Node = DomNode;
+    type Attribute = DomAttr;
+    type Error = JsValue;
+
+    fn create_node(&self, node: &vdom::Node<APP::MSG>) -> DomNode {
+        self.create_dom_node(node)
+    }
+
+    fn create_attribute(&self, attr: &Attribute<APP::MSG>) -> DomAttr {
+        self.convert_attr(attr)
+    }
+
+    fn child_node(&self, node: &DomNode, index: usize) -> Option<DomNode> {
+        node.find_node(&mut TreePath::new([index]))
+    }
+
+    fn insert_before(
+        &self,
+        parent: &DomNode,
+        target: &DomNode,
+        nodes: Vec<DomNode>,
+    ) -> Result<(), JsValue> {
+        parent.insert_before(target, nodes);
+        Ok(())
+    }
+
+    fn insert_after(
+        &self,
+        parent: &DomNode,
+        target: &DomNode,
+        nodes: Vec<DomNode>,
+    ) -> Result<(), JsValue> {
+        parent.insert_after(target, nodes);
+        Ok(())
+    }
+
+    fn append_children(&self, target: &DomNode, children: Vec<DomNode>) -> Result<(), JsValue> {
+        target.append_children(children);
+        Ok(())
+    }
+
+    fn clear_children(&self, target: &DomNode) -> Result<(), JsValue> {
+        target.clear_children();
+        Ok(())
+    }
+
+    // This also releases the node refs and the observers of the removed node and its descendants
+    fn remove_node(&self, parent: &DomNode, target: &DomNode) -> Result<(), JsValue> {
+        target.release_removed();
+        parent.remove_children(&[target]);
+        Ok(())
+    }
+
+    fn move_before(
+        &self,
+        parent: &DomNode,
+        target: &DomNode,
+        nodes: Vec<DomNode>,
+    ) -> Result<(), JsValue> {
+        parent.remove_children(&nodes.iter().collect::<Vec<_>>());
+        parent.insert_before(target, nodes);
+        Ok(())
+    }
+
+    fn move_after(
+        &self,
+        parent: &DomNode,
+        target: &DomNode,
+        nodes: Vec<DomNode>,
+    ) -> Result<(), JsValue> {
+        parent.remove_children(&nodes.iter().collect::<Vec<_>>());
+        parent.insert_after(target, nodes);
+        Ok(())
+    }
+
+    // This also removes the associated closures and event listeners to the node being replaced
+    // including the associated closures of the descendant of replaced node
+    // before it is actully replaced in the DOM
+    fn replace_node(
+        &self,
+        parent: &DomNode,
+        target: &DomNode,
+        mut replacement: Vec<DomNode>,
+    ) -> Result<(), JsValue> {
+        // the children of a fragment root are in the mount node
+        if target.is_fragment() {
+            parent.append_children(replacement);
+            return Ok(());
         }
+        let first_node = replacement.remove(0);
+        parent.replace_child(target, first_node.clone());
+        //insert the rest
+        parent.insert_after(&first_node, replacement);
         Ok(())
     }
+
+    fn set_attributes(&self, target: &DomNode, attrs: Vec<DomAttr>) -> Result<(), JsValue> {
+        self.set_node_attrs(target, attrs)
+    }
+
+    fn remove_attributes(&self, target: &DomNode, attrs: Vec<DomAttr>) -> Result<(), JsValue> {
+        self.remove_node_attrs(target, &attrs)
+    }
+}
+
+/// prevent the default action or stop the propagation of the event
+/// before it is passed to the listener, as specified in the `options`
+fn apply_event_options(event: &web_sys::Event, options: EventOptions) {
+    if options.prevent_default {
+        event.prevent_default();
+    }
+    if options.stop_propagation {
+        event.stop_propagation();
+    }
+    if options.stop_immediate_propagation {
+        event.stop_immediate_propagation();
+    }
 }
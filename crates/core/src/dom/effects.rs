@@ -157,6 +157,16 @@ impl<MSG, XMSG> From<Cmd<MSG>> for Effects<MSG, XMSG> {
     }
 }
 
+/// An empty Effects from `()`, the `Component` counterpart of `Cmd::from(())`
+impl<MSG, XMSG> From<()> for Effects<MSG, XMSG>
+where
+    MSG: 'static,
+{
+    fn from(_: ()) -> Self {
+        Effects::none()
+    }
+}
+
 impl<MSG> Effects<MSG, MSG>
 where
     MSG: 'static,
@@ -2,20 +2,24 @@
 //!
 //! [0]: https://developer.mozilla.org/en-US/docs/Web/Events
 use crate::dom::DomNode;
+use crate::dom::FileInfo;
 use crate::dom::{document, window, Event};
 use crate::vdom;
 use crate::vdom::ComponentEventCallback;
+pub use crate::vdom::EventOptions;
 use crate::vdom::{Attribute, AttributeValue, EventCallback};
-use wasm_bindgen::JsCast;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
 #[cfg(web_sys_unstable_apis)]
 pub use web_sys::ClipboardEvent;
 pub use web_sys::{
-    AnimationEvent, FocusEvent, HashChangeEvent, KeyboardEvent, MouseEvent, Selection, TouchEvent,
-    TransitionEvent,
+    AnimationEvent, DataTransfer, DragEvent, FocusEvent, HashChangeEvent, KeyboardEvent,
+    MouseEvent, Selection, TouchEvent, TransitionEvent, WheelEvent,
 };
 use web_sys::{
-    EventTarget, HtmlDetailsElement, HtmlElement, HtmlInputElement, HtmlSelectElement,
-    HtmlTextAreaElement,
+    EventTarget, HtmlDetailsElement, HtmlElement, HtmlInputElement, HtmlMediaElement,
+    HtmlSelectElement, HtmlTextAreaElement,
 };
 
 #[derive(Clone, Copy)]
@@ -93,6 +97,120 @@ where
     )
 }
 
+/// an event builder where the listener is attached with `options`
+/// # Example
+/// ```rust,ignore
+/// on_with_options(
+///     "touchmove",
+///     EventOptions {
+///         passive: true,
+///         ..Default::default()
+///     },
+///     |_| Msg::TouchMove,
+/// )
+/// ```
+pub fn on_with_options<F, MSG>(
+    event_name: &'static str,
+    options: EventOptions,
+    f: F,
+) -> Attribute<MSG>
+where
+    F: FnMut(Event) -> MSG + 'static,
+    MSG: 'static,
+{
+    vdom::attr(
+        event_name,
+        AttributeValue::EventListener(EventCallback::from(f).with_options(options)),
+    )
+}
+
+/// an event builder where the raw event is forwarded into the msg as an [`EventHandle`],
+/// so that `update` can decide from the state of the app whether to call `prevent_default`
+/// or `stop_propagation` on the event.
+///
+/// The msg of this listener is updated right away instead of being queued,
+/// since the browser carries on with the default action of the event as soon as the
+/// listeners return. Anything done on the [`EventHandle`] after that, such as in a `Cmd`,
+/// a spawned future or a later `update`, no longer has an effect.
+/// # Example
+/// ```rust,ignore
+/// a([href("/settings"), on_with_event("click", Msg::LinkClicked)], [text("settings")])
+///
+/// fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+///     match msg {
+///         Msg::LinkClicked(handle) => {
+///             if self.has_unsaved_changes {
+///                 handle.prevent_default();
+///             }
+///             Cmd::none()
+///         }
+///     }
+/// }
+/// ```
+pub fn on_with_event<F, MSG>(event_name: &'static str, mut f: F) -> Attribute<MSG>
+where
+    F: FnMut(EventHandle) -> MSG + 'static,
+    MSG: 'static,
+{
+    on_with_options(
+        event_name,
+        EventOptions {
+            dispatch_sync: true,
+            ..Default::default()
+        },
+        move |event: Event| {
+            let event = event.as_web().expect("must be a web event");
+            f(EventHandle { event })
+        },
+    )
+}
+
+/// The raw event of a listener created with [`on_with_event`], which is carried in the msg
+/// to `update`.
+///
+/// The browser only honors `prevent_default` and `stop_propagation` while the event is
+/// being dispatched, which is checked with [`EventHandle::is_dispatching`].
+#[derive(Debug, Clone)]
+pub struct EventHandle {
+    event: web_sys::Event,
+}
+
+impl EventHandle {
+    /// the underlying event
+    pub fn event(&self) -> &web_sys::Event {
+        &self.event
+    }
+
+    /// cast the underlying event into a specific event type such as `KeyboardEvent`
+    pub fn cast<T: JsCast>(&self) -> Option<&T> {
+        self.event.dyn_ref::<T>()
+    }
+
+    /// whether the event is still being dispatched, where calling `prevent_default`
+    /// and `stop_propagation` still has an effect
+    pub fn is_dispatching(&self) -> bool {
+        self.event.event_phase() != web_sys::Event::NONE
+    }
+
+    /// call `prevent_default` on the event,
+    /// returns false when the event is no longer being dispatched and so this has no effect
+    pub fn prevent_default(&self) -> bool {
+        self.is_dispatching() && {
+            self.event.prevent_default();
+            true
+        }
+    }
+
+    /// call `stop_propagation` on the event,
+    /// returns false when the event is no longer being dispatched and so this has no effect
+    pub fn stop_propagation(&self) -> bool {
+        self.is_dispatching() && {
+            self.event.stop_propagation();
+            true
+        }
+    }
+}
+
 /// on click event
 pub fn on_click<F, MSG>(mut f: F) -> Attribute<MSG>
 where
@@ -102,6 +220,131 @@ where
     on("click", move |event: Event| f(to_mouse_event(event)))
 }
 
+/// an event builder where the listener may produce no msg, such as to ignore the event
+/// in some cases without needing a no-op msg. When the listener returns `None`,
+/// nothing is dispatched and the app is not updated.
+///
+/// The [`EventOptions`] of the listener, such as `prevent_default`, are applied before the
+/// listener is called, so they still apply to the event when the listener returns `None`.
+/// Use [`on_opt_with_options`] to specify them.
+pub fn on_opt<F, MSG>(event_name: &'static str, f: F) -> Attribute<MSG>
+where
+    F: FnMut(Event) -> Option<MSG> + 'static,
+    MSG: 'static,
+{
+    on_opt_with_options(event_name, EventOptions::default(), f)
+}
+
+/// an event builder where the listener may produce no msg, see [`on_opt`],
+/// and the listener is attached with `options`
+pub fn on_opt_with_options<F, MSG>(
+    event_name: &'static str,
+    options: EventOptions,
+    f: F,
+) -> Attribute<MSG>
+where
+    F: FnMut(Event) -> Option<MSG> + 'static,
+    MSG: 'static,
+{
+    vdom::attr(
+        event_name,
+        AttributeValue::EventListener(EventCallback::from_optional(f).with_options(options)),
+    )
+}
+
+/// on click event where the listener may produce no msg, see [`on_opt`]
+/// # Example
+/// ```rust,ignore
+/// button([on_click_opt(|_| (!self.is_loading).then_some(Msg::Save))], [text("save")])
+/// ```
+pub fn on_click_opt<F, MSG>(mut f: F) -> Attribute<MSG>
+where
+    F: FnMut(MouseEvent) -> Option<MSG> + 'static,
+    MSG: 'static,
+{
+    on_opt("click", move |event: Event| f(to_mouse_event(event)))
+}
+
+/// on click event which always produces the same `msg`, ignoring the event
+/// # Example
+/// ```rust,ignore
+/// button([on_click_msg(Msg::Increment)], [text("+")])
+/// ```
+pub fn on_click_msg<MSG>(msg: MSG) -> Attribute<MSG>
+where
+    MSG: Clone + 'static,
+{
+    on("click", move |_| msg.clone())
+}
+
+/// on submit event which always produces the same `msg`, ignoring the event
+pub fn on_submit_msg<MSG>(msg: MSG) -> Attribute<MSG>
+where
+    MSG: Clone + 'static,
+{
+    on("submit", move |_| msg.clone())
+}
+
+/// on change event which always produces the same `msg`, ignoring the event
+pub fn on_change_msg<MSG>(msg: MSG) -> Attribute<MSG>
+where
+    MSG: Clone + 'static,
+{
+    on("change", move |_| msg.clone())
+}
+
+/// attach a callback to the wheel event, calling `prevent_default` on the event
+/// so that the page is not scrolled, such as for custom zoom and pan controls.
+pub fn on_wheel_prevent_default<F, MSG>(mut f: F) -> Attribute<MSG>
+where
+    F: FnMut(WheelInfo) -> MSG + 'static,
+    MSG: 'static,
+{
+    on_with_options(
+        "wheel",
+        EventOptions {
+            prevent_default: true,
+            ..Default::default()
+        },
+        move |event: Event| f(to_wheel_info(event)),
+    )
+}
+
+/// attach a callback to the input event of a `contenteditable` element,
+/// which is called with the text content of the element after each edit.
+///
+/// The content of the element should be left uncontrolled: set the initial content once,
+/// and store the text from the callback in the model without rendering it back into the element,
+/// since replacing the content on every render resets the cursor position.
+///
+/// The input events are also fired during an IME composition with the text which is not yet
+/// committed, use [`on_input`] and [`InputEvent::is_composing`] to tell them apart.
+/// # Example
+/// ```rust,ignore
+/// div([contenteditable(true), on_content_input(Msg::NoteChanged)], [text(&self.initial_note)])
+/// ```
+pub fn on_content_input<F, MSG>(mut f: F) -> Attribute<MSG>
+where
+    F: FnMut(String) -> MSG + 'static,
+    MSG: 'static,
+{
+    on("input", move |event: Event| {
+        let web_event = event.as_web().expect("must be a web event");
+        let target = web_event
+            .current_target()
+            .or_else(|| web_event.target())
+            .expect("must have a target");
+        let content = if let Some(element) = target.dyn_ref::<HtmlElement>() {
+            element.inner_text()
+        } else if let Some(node) = target.dyn_ref::<web_sys::Node>() {
+            node.text_content().unwrap_or_default()
+        } else {
+            String::new()
+        };
+        f(content)
+    })
+}
+
 /// attach callback to the scroll event
 pub fn on_scroll<F, MSG>(mut f: F) -> Attribute<MSG>
 where
@@ -124,6 +367,113 @@ where
     })
 }
 
+/// attach a callback to the dragover event.
+/// This calls `prevent_default` on the event, since an element only becomes a drop target
+/// when the default handling of dragover is prevented, otherwise the drop event will not fire.
+pub fn on_dragover<F, MSG>(mut f: F) -> Attribute<MSG>
+where
+    F: FnMut(DragEvent) -> MSG + 'static,
+    MSG: 'static,
+{
+    on("dragover", move |event: Event| {
+        let drag_event = to_drag_event(event);
+        drag_event.prevent_default();
+        f(drag_event)
+    })
+}
+
+/// attach a callback to the drop event.
+/// This calls `prevent_default` on the event to stop the browser from opening the dropped files.
+///
+/// Note: the drop event only fires when the default handling of the dragover event is prevented,
+/// use [`on_dragover`] on the same element.
+pub fn on_drop<F, MSG>(mut f: F) -> Attribute<MSG>
+where
+    F: FnMut(DropInfo) -> MSG + 'static,
+    MSG: 'static,
+{
+    on("drop", move |event: Event| {
+        let drag_event = to_drag_event(event);
+        drag_event.prevent_default();
+        f(DropInfo { event: drag_event })
+    })
+}
+
+/// The information of the drop event
+#[derive(Debug, Clone)]
+pub struct DropInfo {
+    /// the actual drag event
+    pub event: DragEvent,
+}
+
+impl DropInfo {
+    /// the data transfer which contains the dropped data
+    pub fn data_transfer(&self) -> Option<DataTransfer> {
+        self.event.data_transfer()
+    }
+
+    /// get the dropped data of this format, ie: `text/plain`, `text/uri-list`
+    pub fn get_data(&self, format: &str) -> Option<String> {
+        self.data_transfer()
+            .and_then(|data_transfer| data_transfer.get_data(format).ok())
+            .filter(|data| !data.is_empty())
+    }
+
+    /// the dropped plain text
+    pub fn text(&self) -> Option<String> {
+        self.get_data("text/plain")
+    }
+
+    /// the formats of the dropped data
+    pub fn types(&self) -> Vec<String> {
+        self.data_transfer()
+            .map(|data_transfer| {
+                data_transfer
+                    .types()
+                    .iter()
+                    .filter_map(|t| t.as_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// the dropped files
+    pub fn files(&self) -> Vec<FileInfo> {
+        self.data_transfer()
+            .and_then(|data_transfer| data_transfer.files())
+            .map(to_file_infos)
+            .unwrap_or_default()
+    }
+}
+
+fn to_file_infos(files: web_sys::FileList) -> Vec<FileInfo> {
+    (0..files.length())
+        .filter_map(|i| files.item(i))
+        .map(FileInfo::from)
+        .collect()
+}
+
+/// attach a callback to the change event of a file input,
+/// the callback receives all the selected files, which is empty when the selection is cleared.
+/// Use [`Cmd::read_file_as_text`](crate::dom::Cmd::read_file_as_text) or
+/// [`Cmd::read_file_as_bytes`](crate::dom::Cmd::read_file_as_bytes) to read the contents.
+pub fn on_file_change<F, MSG>(mut f: F) -> Attribute<MSG>
+where
+    F: FnMut(Vec<FileInfo>) -> MSG + 'static,
+    MSG: 'static,
+{
+    on("change", move |event: Event| {
+        let web_event = event.as_web().expect("must be a web event");
+        let target: EventTarget = web_event.target().expect("Unable to get event target");
+        let files = target
+            .dyn_ref::<HtmlInputElement>()
+            .and_then(|input| input.files())
+            .map(to_file_infos)
+            .unwrap_or_default();
+        f(files)
+    })
+}
+
 /// an event when a virtual Node is mounted the field node is the actual
 /// dom node where the virtual Node is created in the actual dom
 #[derive(Debug, Clone)]
@@ -177,6 +527,185 @@ where
     )
 }
 
+/// the name of the event dispatched to an element with a node ref,
+/// when the element is mounted or removed
+pub(crate) const NODE_REF_EVENT: &str = "noderef";
+
+/// create the node ref event, the detail is true when the element is mounted
+/// and false when the element is removed
+pub(crate) fn create_node_ref_event(is_mounted: bool) -> web_sys::Event {
+    let event_init = web_sys::CustomEventInit::new();
+    event_init.set_detail(&JsValue::from_bool(is_mounted));
+    web_sys::CustomEvent::new_with_event_init_dict(NODE_REF_EVENT, &event_init)
+        .expect("as event")
+        .unchecked_into()
+}
+
+/// A handle to the actual dom element of a virtual node, see [`node_ref`]
+pub type NodeRef = Rc<RefCell<Option<web_sys::Element>>>;
+
+/// store the actual `web_sys::Element` into `node_ref` when the element is created and
+/// mounted by the Program, this is useful for calling imperative apis such as `focus()`
+/// or getting the context of a canvas.
+/// The `node_ref` is set back to `None` when the element is removed during diffing.
+///
+/// Example:
+/// ```rust,ignore
+/// let canvas_ref: NodeRef = NodeRef::default();
+/// canvas(vec![node_ref(&canvas_ref)], vec![]);
+/// ```
+pub fn node_ref<MSG>(node_ref: &NodeRef) -> Attribute<MSG>
+where
+    MSG: 'static,
+{
+    let node_ref = Rc::clone(node_ref);
+    on_node_ref(move |element| {
+        *node_ref.borrow_mut() = element;
+    })
+}
+
+/// call `f` with the actual `web_sys::Element` when the element is created and mounted
+/// by the Program, and with `None` when the element is removed during diffing
+pub fn on_node_ref<F, MSG>(mut f: F) -> Attribute<MSG>
+where
+    F: FnMut(Option<web_sys::Element>) + 'static,
+    MSG: 'static,
+{
+    let cb = move |event: Event| {
+        let web_event = event.as_web().expect("must be a web event");
+        let event_target = web_event.target().expect("must have a target");
+        let is_mounted = web_event
+            .dyn_ref::<web_sys::CustomEvent>()
+            .and_then(|custom_event| custom_event.detail().as_bool())
+            .unwrap_or(false);
+        if is_mounted {
+            f(Some(event_target.unchecked_into()));
+        } else {
+            f(None);
+        }
+    };
+    vdom::attr(
+        NODE_REF_EVENT,
+        AttributeValue::ComponentEventListener(ComponentEventCallback::from(cb)),
+    )
+}
+
+/// the name of the event dispatched to an element with `on_resize` when its size changes
+pub(crate) const RESIZE_EVENT: &str = "elementresize";
+
+/// the new size of the content box of an element, see [`on_resize`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SizeInfo {
+    /// the width of the content box in pixels
+    pub width: f64,
+    /// the height of the content box in pixels
+    pub height: f64,
+}
+
+/// attach a callback which is called with the new size of the content box of the element
+/// whenever it changes, this is backed by a `ResizeObserver`.
+/// The observer is created when the element is created by the Program
+/// and is disconnected when the element is removed.
+pub fn on_resize<F, MSG>(f: F) -> Attribute<MSG>
+where
+    F: Fn(SizeInfo) -> MSG + 'static,
+    MSG: 'static,
+{
+    on(RESIZE_EVENT, move |event: Event| {
+        let web_event = event.as_web().expect("must be a web event");
+        let custom_event: &web_sys::CustomEvent = web_event.unchecked_ref();
+        let entry: web_sys::ResizeObserverEntry = custom_event.detail().unchecked_into();
+        let content_rect = entry.content_rect();
+        f(SizeInfo {
+            width: content_rect.width(),
+            height: content_rect.height(),
+        })
+    })
+}
+
+/// the name of the event dispatched to an element with `on_intersect` when its
+/// intersection with the viewport crosses one of its thresholds
+pub(crate) const INTERSECT_EVENT: &str = "elementintersect";
+
+/// the attribute which holds the thresholds of the `IntersectionObserver` of an element
+pub(crate) const INTERSECT_THRESHOLD: &str = "data-intersect-threshold";
+
+/// the intersection of an element with the viewport, see [`on_intersect`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntersectInfo {
+    /// true if the element is entering or is inside the viewport,
+    /// false if it is leaving the viewport
+    pub is_intersecting: bool,
+    /// the visible portion of the element, from `0.0` to `1.0`
+    pub intersection_ratio: f64,
+}
+
+/// attach a callback which is called when the element enters or leaves the viewport,
+/// this is backed by an `IntersectionObserver`.
+/// The observer is created when the element is created by the Program
+/// and is disconnected when the element is removed.
+///
+/// By default the callback is called as soon as 1 pixel of the element is visible,
+/// use [`intersect_threshold`] to be notified at other visible portions of the element.
+///
+/// # Examples
+/// ```rust
+/// use sauron::*;
+///
+/// enum Msg {
+///     LoadMore,
+///     Idle,
+/// }
+///
+/// // the sentinel at the end of a list, which loads more items once it is visible
+/// let sentinel: Node<Msg> = div(
+///     [on_intersect(|info| {
+///         if info.is_intersecting {
+///             Msg::LoadMore
+///         } else {
+///             Msg::Idle
+///         }
+///     })],
+///     [],
+/// );
+/// ```
+pub fn on_intersect<F, MSG>(f: F) -> Attribute<MSG>
+where
+    F: Fn(IntersectInfo) -> MSG + 'static,
+    MSG: 'static,
+{
+    on(INTERSECT_EVENT, move |event: Event| {
+        let web_event = event.as_web().expect("must be a web event");
+        let custom_event: &web_sys::CustomEvent = web_event.unchecked_ref();
+        let entry: web_sys::IntersectionObserverEntry = custom_event.detail().unchecked_into();
+        f(IntersectInfo {
+            is_intersecting: entry.is_intersecting(),
+            intersection_ratio: entry.intersection_ratio(),
+        })
+    })
+}
+
+/// set the visible portions of the element, from `0.0` to `1.0`,
+/// at which the [`on_intersect`] callback of the element is called.
+///
+/// # Examples
+/// ```rust
+/// use sauron::*;
+///
+/// let image: Node<f64> = img([
+///     intersect_threshold([0.0, 0.5, 1.0]),
+///     on_intersect(|info| info.intersection_ratio),
+/// ]);
+/// assert_eq!(
+///     image.render_to_string(),
+///     r#"<img data-intersect-threshold="0 0.5 1" />"#
+/// );
+/// ```
+pub fn intersect_threshold<MSG>(thresholds: impl IntoIterator<Item = f64>) -> Attribute<MSG> {
+    let thresholds: Vec<String> = thresholds.into_iter().map(|t| t.to_string()).collect();
+    vdom::attr(INTERSECT_THRESHOLD, thresholds.join(" "))
+}
+
 macro_rules! declare_events {
 
     ( $(
@@ -226,11 +755,26 @@ fn to_mouse_event(event: Event) -> MouseEvent {
     web_event.dyn_into().expect("Unable to cast to mouse event")
 }
 
+fn to_wheel_info(event: Event) -> WheelInfo {
+    let web_event = event.as_web().expect("must be a web_sys event");
+    let wheel_event: WheelEvent = web_event.dyn_into().expect("Unable to cast to wheel event");
+    WheelInfo::from(wheel_event)
+}
+
+fn to_drag_event(event: Event) -> DragEvent {
+    let web_event = event.as_web().expect("must be a web_sys event");
+    web_event.dyn_into().expect("Unable to cast to drag event")
+}
+
 fn to_focus_event(event: Event) -> FocusEvent {
     let web_event = event.as_web().expect("must be a web_sys event");
     web_event.dyn_into().expect("Unable to cast to focus event")
 }
 
+fn to_focus_info(event: Event) -> FocusInfo {
+    FocusInfo::from(to_focus_event(event))
+}
+
 fn to_keyboard_event(event: Event) -> KeyboardEvent {
     let web_event = event.as_web().expect("must be a web_sys event");
     web_event
@@ -238,18 +782,30 @@ fn to_keyboard_event(event: Event) -> KeyboardEvent {
         .expect("unable to cast to keyboard event")
 }
 
-fn to_animation_event(event: Event) -> AnimationEvent {
+fn to_animation_info(event: Event) -> AnimationInfo {
     let web_event = event.as_web().expect("must be a web_sys event");
-    web_event
+    let animation_event: AnimationEvent = web_event
         .dyn_into()
-        .expect("unable to cast to animation event")
+        .expect("unable to cast to animation event");
+    AnimationInfo::from(animation_event)
 }
 
-fn to_transition_event(event: Event) -> TransitionEvent {
+fn to_transition_info(event: Event) -> TransitionInfo {
     let web_event = event.as_web().expect("must be a web_sys event");
-    web_event
+    let transition_event: TransitionEvent = web_event
+        .dyn_into()
+        .expect("unable to cast to transition event");
+    TransitionInfo::from(transition_event)
+}
+
+fn to_media_info(event: Event) -> MediaInfo {
+    let web_event = event.as_web().expect("must be a web_sys event");
+    let media: HtmlMediaElement = web_event
+        .target()
+        .expect("must have a target")
         .dyn_into()
-        .expect("unable to cast to transition event")
+        .expect("must be an audio or video element");
+    MediaInfo::from(media)
 }
 
 fn to_touch_event(event: Event) -> TouchEvent {
@@ -271,6 +827,180 @@ fn to_hashchange_event(event: Event) -> HashChangeEvent {
         .expect("unable to cast to hashchange event")
 }
 
+/// The information of a focus or blur event, see [`on_focus`] and [`on_blur`]
+#[derive(Debug, Clone)]
+pub struct FocusInfo {
+    /// the actual focus event
+    pub event: FocusEvent,
+    /// the id of the related target, which is the element losing the focus on `focus`
+    /// and the element receiving the focus on `blur`.
+    /// None when there is no related target or it has no id
+    pub related_target_id: Option<String>,
+    /// the lowercase tag name of the related target
+    pub related_target_tag: Option<String>,
+}
+
+impl From<FocusEvent> for FocusInfo {
+    fn from(event: FocusEvent) -> Self {
+        let related_target: Option<web_sys::Element> = event
+            .related_target()
+            .and_then(|target| target.dyn_into().ok());
+        FocusInfo {
+            related_target_id: related_target
+                .as_ref()
+                .map(|element| element.id())
+                .filter(|id| !id.is_empty()),
+            related_target_tag: related_target.map(|element| element.tag_name().to_lowercase()),
+            event,
+        }
+    }
+}
+
+/// The information of an animation event, see [`on_animationend`]
+#[derive(Debug, Clone)]
+pub struct AnimationInfo {
+    /// the actual animation event
+    pub event: AnimationEvent,
+    /// the name of the css `@keyframes` of the animation
+    pub animation_name: String,
+    /// the seconds the animation has been running, excluding the time it was paused
+    pub elapsed_time: f32,
+    /// the pseudo element such as `::before` which runs the animation,
+    /// empty when the animation runs on the element itself
+    pub pseudo_element: String,
+}
+
+impl From<AnimationEvent> for AnimationInfo {
+    fn from(event: AnimationEvent) -> Self {
+        AnimationInfo {
+            animation_name: event.animation_name(),
+            elapsed_time: event.elapsed_time(),
+            pseudo_element: event.pseudo_element(),
+            event,
+        }
+    }
+}
+
+/// The information of a transition event, see [`on_transitionend`]
+#[derive(Debug, Clone)]
+pub struct TransitionInfo {
+    /// the actual transition event
+    pub event: TransitionEvent,
+    /// the css property of the transition such as `opacity`.
+    /// A transition of multiple properties fires an event for each of the properties
+    pub property_name: String,
+    /// the seconds the transition has been running
+    pub elapsed_time: f32,
+    /// the pseudo element such as `::before` which runs the transition,
+    /// empty when the transition runs on the element itself
+    pub pseudo_element: String,
+}
+
+impl From<TransitionEvent> for TransitionInfo {
+    fn from(event: TransitionEvent) -> Self {
+        TransitionInfo {
+            property_name: event.property_name(),
+            elapsed_time: event.elapsed_time(),
+            pseudo_element: event.pseudo_element(),
+            event,
+        }
+    }
+}
+
+/// The state of an audio or video element when a media event is fired,
+/// see [`on_play`] and [`on_timeupdate`]
+#[derive(Debug, Clone)]
+pub struct MediaInfo {
+    /// the audio or video element
+    pub media: HtmlMediaElement,
+    /// the playback position in seconds
+    pub current_time: f64,
+    /// the length of the media in seconds, `NaN` when the metadata is not yet loaded
+    /// and infinite for a live stream
+    pub duration: f64,
+    /// whether the playback is paused
+    pub paused: bool,
+    /// the volume from `0.0` which is silent to `1.0` which is the loudest
+    pub volume: f64,
+    /// whether the audio is muted, which is separate from the volume
+    pub muted: bool,
+}
+
+impl From<HtmlMediaElement> for MediaInfo {
+    fn from(media: HtmlMediaElement) -> Self {
+        MediaInfo {
+            current_time: media.current_time(),
+            duration: media.duration(),
+            paused: media.paused(),
+            volume: media.volume(),
+            muted: media.muted(),
+            media,
+        }
+    }
+}
+
+/// The unit of the deltas of a wheel event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WheelDeltaMode {
+    /// the deltas are in pixels
+    Pixel,
+    /// the deltas are in lines
+    Line,
+    /// the deltas are in pages
+    Page,
+}
+
+/// The information of a wheel event, see [`on_wheel`]
+#[derive(Debug, Clone)]
+pub struct WheelInfo {
+    /// the actual wheel event
+    pub event: WheelEvent,
+    /// the horizontal scroll amount
+    pub delta_x: f64,
+    /// the vertical scroll amount
+    pub delta_y: f64,
+    /// the scroll amount along the z-axis
+    pub delta_z: f64,
+    /// the unit of the deltas
+    pub delta_mode: WheelDeltaMode,
+    /// whether the ctrl key is pressed, which is the convention for pinch zooming
+    pub ctrl_key: bool,
+    /// whether the shift key is pressed
+    pub shift_key: bool,
+    /// whether the alt key is pressed
+    pub alt_key: bool,
+    /// whether the meta key is pressed
+    pub meta_key: bool,
+}
+
+impl WheelInfo {
+    /// call prevent default on the underlying event
+    pub fn prevent_default(&self) {
+        self.event.prevent_default()
+    }
+}
+
+impl From<WheelEvent> for WheelInfo {
+    fn from(event: WheelEvent) -> Self {
+        let delta_mode = match event.delta_mode() {
+            WheelEvent::DOM_DELTA_LINE => WheelDeltaMode::Line,
+            WheelEvent::DOM_DELTA_PAGE => WheelDeltaMode::Page,
+            _ => WheelDeltaMode::Pixel,
+        };
+        WheelInfo {
+            delta_x: event.delta_x(),
+            delta_y: event.delta_y(),
+            delta_z: event.delta_z(),
+            delta_mode,
+            ctrl_key: event.ctrl_key(),
+            shift_key: event.shift_key(),
+            alt_key: event.alt_key(),
+            meta_key: event.meta_key(),
+            event,
+        }
+    }
+}
+
 /// TODO: expand this much farther by getting the InputEvent data, data_transfer, event_type,
 /// is_composing events.
 /// a custom InputEvent to contain the input string value
@@ -325,6 +1055,15 @@ impl InputEvent {
         }
     }
 
+    /// returns true if the input happened in the middle of an IME composition,
+    /// such as when typing CJK characters, where the text is not committed yet
+    pub fn is_composing(&self) -> bool {
+        self.event
+            .dyn_ref::<web_sys::InputEvent>()
+            .map(|event| event.is_composing())
+            .unwrap_or(false)
+    }
+
     /// create a native web event
     pub fn create_web_event() -> web_sys::Event {
         web_sys::Event::new("input").expect("as event")
@@ -386,8 +1125,12 @@ fn to_selection(_event: Event) -> Option<Selection> {
 // Mouse events
 declare_html_events! {
     on_auxclick => auxclick => to_mouse_event => MouseEvent;
-    on_animationend => animationend => to_animation_event => AnimationEvent;
-    on_transitionend => transitionend => to_transition_event => TransitionEvent;
+    /// The animation events of the descendants bubble up to this element,
+    /// check the `animation_name` to react only to the expected animation.
+    on_animationend => animationend => to_animation_info => AnimationInfo;
+    /// This is fired for each of the properties of the transition,
+    /// check the `property_name` to react only once.
+    on_transitionend => transitionend => to_transition_info => TransitionInfo;
     on_contextmenu => contextmenu => to_mouse_event => MouseEvent;
     on_dblclick  => dblclick => to_mouse_event => MouseEvent;
     on_mousedown => mousedown => to_mouse_event => MouseEvent;
@@ -401,8 +1144,15 @@ declare_html_events! {
     on_pointerlockerror => pointerlockerror => to_mouse_event => MouseEvent;
     on_popstate => popstate => to_webevent => web_sys::Event;
     on_select => select => to_webevent => web_sys::Event;
-    on_wheel => wheel => to_mouse_event => MouseEvent;
+    /// Call `prevent_default` on the event to stop the page from scrolling,
+    /// or use [`on_wheel_prevent_default`].
+    on_wheel => wheel => to_wheel_info => WheelInfo;
     on_doubleclick => dblclick => to_mouse_event => MouseEvent;
+    on_drag => drag => to_drag_event => DragEvent;
+    on_dragstart => dragstart => to_drag_event => DragEvent;
+    on_dragend => dragend => to_drag_event => DragEvent;
+    on_dragenter => dragenter => to_drag_event => DragEvent;
+    on_dragleave => dragleave => to_drag_event => DragEvent;
     on_keydown => keydown => to_keyboard_event => KeyboardEvent;
     on_keypress => keypress => to_keyboard_event => KeyboardEvent;
     on_keyup => keyup => to_keyboard_event => KeyboardEvent;
@@ -410,8 +1160,12 @@ declare_html_events! {
     on_touchstart => touchstart => to_touch_event => TouchEvent;
     on_touchend => touchend => to_touch_event => TouchEvent;
     on_touchmove => touchmove => to_touch_event => TouchEvent;
-    on_focus => focus => to_focus_event => FocusEvent;
-    on_blur => blur => to_focus_event => FocusEvent;
+    /// The focus event doesn't bubble, the listener is attached directly to the element.
+    /// Delegating it to an ancestor needs a capturing listener or the `focusin` event.
+    on_focus => focus => to_focus_info => FocusInfo;
+    /// The blur event doesn't bubble, the listener is attached directly to the element.
+    /// Delegating it to an ancestor needs a capturing listener or the `focusout` event.
+    on_blur => blur => to_focus_info => FocusInfo;
     on_reset => reset => to_webevent => web_sys::Event;
     on_submit => submit => to_webevent => web_sys::Event;
     on_input => input => to_input_event => InputEvent;
@@ -424,5 +1178,16 @@ declare_html_events! {
     on_broadcast => broadcast => to_input_event => InputEvent;
     on_hashchange => hashchange => to_hashchange_event => HashChangeEvent;
     on_readystatechange => readystatechange => to_webevent => web_sys::Event;
+    // the media events of the audio and video elements, these don't bubble
+    on_play => play => to_media_info => MediaInfo;
+    on_pause => pause => to_media_info => MediaInfo;
+    on_ended => ended => to_media_info => MediaInfo;
+    /// This is fired several times a second while the media is playing,
+    /// keep the update cheap such as only storing the `current_time`,
+    /// or ignore the updates which are too close to the previous one.
+    on_timeupdate => timeupdate => to_media_info => MediaInfo;
+    on_volumechange => volumechange => to_media_info => MediaInfo;
+    /// The `duration` is known from this event onwards
+    on_loadedmetadata => loadedmetadata => to_media_info => MediaInfo;
     on_selectionchange => selectionchange => to_selection => Option<Selection>;
 }
@@ -0,0 +1,116 @@
+//! provides functions for reading the contents of the files selected by the user
+use crate::dom::Cmd;
+use futures::channel::oneshot;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{File, FileReader};
+
+/// A file selected from a file input or dropped into an element
+#[derive(Debug, Clone)]
+pub struct FileInfo {
+    /// the actual file
+    pub file: File,
+}
+
+impl From<File> for FileInfo {
+    fn from(file: File) -> Self {
+        Self { file }
+    }
+}
+
+impl FileInfo {
+    /// the name of the file, without the path
+    pub fn name(&self) -> String {
+        self.file.name()
+    }
+
+    /// the size of the file in bytes
+    pub fn size(&self) -> f64 {
+        self.file.size()
+    }
+
+    /// the mime type of the file, empty if it can not be determined
+    pub fn mime_type(&self) -> String {
+        self.file.type_()
+    }
+
+    /// the last modified time of the file, in milliseconds since the unix epoch
+    pub fn last_modified(&self) -> f64 {
+        self.file.last_modified()
+    }
+
+    /// read the contents of the file as text.
+    /// The error is the message of the read failure.
+    pub async fn read_as_text(&self) -> Result<String, String> {
+        let result = read_file(&self.file, |reader, file| reader.read_as_text(file)).await?;
+        result
+            .as_string()
+            .ok_or_else(|| "file content is not a text".to_string())
+    }
+
+    /// read the contents of the file as bytes.
+    /// The error is the message of the read failure.
+    pub async fn read_as_bytes(&self) -> Result<Vec<u8>, String> {
+        let result =
+            read_file(&self.file, |reader, file| reader.read_as_array_buffer(file)).await?;
+        Ok(js_sys::Uint8Array::new(&result).to_vec())
+    }
+}
+
+/// read the file using a FileReader, `read` starts the reading with the specified format
+async fn read_file(
+    file: &File,
+    read: impl FnOnce(&FileReader, &File) -> Result<(), JsValue>,
+) -> Result<JsValue, String> {
+    let reader = FileReader::new().map_err(error_message)?;
+    let (tx, rx) = oneshot::channel::<()>();
+    // the sender is shared with both of the onload and onerror callbacks
+    let tx = Rc::new(RefCell::new(Some(tx)));
+    let tx_error = Rc::clone(&tx);
+    let onload: Closure<dyn FnMut()> = Closure::new(move || {
+        if let Some(tx) = tx.borrow_mut().take() {
+            tx.send(()).ok();
+        }
+    });
+    let onerror: Closure<dyn FnMut()> = Closure::new(move || {
+        if let Some(tx) = tx_error.borrow_mut().take() {
+            tx.send(()).ok();
+        }
+    });
+    reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+    reader.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+    read(&reader, file).map_err(error_message)?;
+    rx.await
+        .map_err(|_| "file reading is cancelled".to_string())?;
+    if let Some(error) = reader.error() {
+        return Err(error.message());
+    }
+    reader.result().map_err(error_message)
+}
+
+fn error_message(err: JsValue) -> String {
+    err.as_string().unwrap_or_else(|| format!("{err:?}"))
+}
+
+impl<MSG> Cmd<MSG>
+where
+    MSG: 'static,
+{
+    /// read the contents of the file as text, then map the result into a MSG
+    pub fn read_file_as_text<F>(file: FileInfo, to_msg: F) -> Self
+    where
+        F: FnOnce(Result<String, String>) -> MSG + 'static,
+    {
+        Cmd::once(async move { to_msg(file.read_as_text().await) })
+    }
+
+    /// read the contents of the file as bytes, then map the result into a MSG
+    pub fn read_file_as_bytes<F>(file: FileInfo, to_msg: F) -> Self
+    where
+        F: FnOnce(Result<Vec<u8>, String>) -> MSG + 'static,
+    {
+        Cmd::once(async move { to_msg(file.read_as_bytes().await) })
+    }
+}
@@ -0,0 +1,213 @@
+//! provides functions for moving the focus between the focusable elements of a container,
+//! useful for building accessible menus and modals
+use crate::dom::{document, Cmd};
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, HtmlInputElement, HtmlTextAreaElement, KeyboardEvent};
+
+/// elements which can receive focus
+const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button, input, select, textarea, \
+    iframe, summary, audio[controls], video[controls], [tabindex], [contenteditable]";
+
+/// returns the focusable descendants of the element with the `container_id`, in tab order.
+/// Disabled, hidden elements and elements with a negative `tabindex` such as `tabindex="-1"`
+/// are excluded.
+pub fn focusable_elements(container_id: &str) -> Vec<HtmlElement> {
+    let Some(container) = document().get_element_by_id(container_id) else {
+        return vec![];
+    };
+    let Ok(node_list) = container.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return vec![];
+    };
+    let mut elements: Vec<HtmlElement> = (0..node_list.length())
+        .filter_map(|i| node_list.item(i))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .filter(is_focusable)
+        .collect();
+    // elements with positive tabindex comes first in ascending order,
+    // then the rest in document order
+    elements.sort_by_key(|element| match element.tab_index() {
+        tab_index if tab_index > 0 => tab_index,
+        _ => i32::MAX,
+    });
+    elements
+}
+
+fn is_focusable(element: &HtmlElement) -> bool {
+    let is_hidden_input = element.tag_name().eq_ignore_ascii_case("input")
+        && element
+            .get_attribute("type")
+            .map(|t| t.eq_ignore_ascii_case("hidden"))
+            .unwrap_or(false);
+    let is_not_editable = element
+        .get_attribute("contenteditable")
+        .map(|v| v.eq_ignore_ascii_case("false"))
+        .unwrap_or(false);
+    !element.has_attribute("disabled")
+        && !element.hidden()
+        && element.tab_index() >= 0
+        && !is_hidden_input
+        && !is_not_editable
+}
+
+/// move the focus to the next focusable element in the container,
+/// wrapping around to the first one.
+/// Returns false if there is no focusable element in the container.
+pub fn focus_next(container_id: &str) -> bool {
+    move_focus(container_id, true)
+}
+
+/// move the focus to the previous focusable element in the container,
+/// wrapping around to the last one.
+/// Returns false if there is no focusable element in the container.
+pub fn focus_previous(container_id: &str) -> bool {
+    move_focus(container_id, false)
+}
+
+fn move_focus(container_id: &str, forward: bool) -> bool {
+    let elements = focusable_elements(container_id);
+    if elements.is_empty() {
+        return false;
+    }
+    let active = document().active_element();
+    let current = active.and_then(|active| elements.iter().position(|e| **e == active));
+    let last = elements.len() - 1;
+    let target = match (current, forward) {
+        (Some(i), true) if i < last => i + 1,
+        (Some(i), false) if i > 0 => i - 1,
+        (_, true) => 0,
+        (_, false) => last,
+    };
+    elements[target].focus().is_ok()
+}
+
+/// keep the focus inside the container when the user press `Tab` or `Shift+Tab`,
+/// call this in the `on_keydown` of a modal.
+/// Returns true if the focus is moved.
+pub fn trap_focus(container_id: &str, event: &KeyboardEvent) -> bool {
+    if event.key() != "Tab" {
+        return false;
+    }
+    event.prevent_default();
+    move_focus(container_id, !event.shift_key())
+}
+
+/// The element which has the focus before the DOM is patched,
+/// used to restore the focus when the patches replaced or moved the element.
+///
+/// The focus is only restored when it is lost after patching, that is the active element
+/// becomes the body. The element to focus is the first one found of:
+///  - the previously focused element itself, if it is still in the document,
+///    since moving an element in the DOM takes the focus away from it.
+///  - the element in the container with the same `id`.
+///  - the element in the container with the same tag and `name` attribute,
+///    which is the common case for the form controls.
+///
+/// The selection range of the text inputs and textareas is restored as well.
+pub(crate) struct FocusSnapshot {
+    element: HtmlElement,
+    id: String,
+    tag: String,
+    name: Option<String>,
+    selection: Option<(u32, u32)>,
+}
+
+impl FocusSnapshot {
+    /// record the focused element if it is inside the `container`
+    pub(crate) fn take(container: &web_sys::Node) -> Option<Self> {
+        let active = document().active_element()?;
+        if !container.contains(Some(&active)) {
+            return None;
+        }
+        let element: HtmlElement = active.dyn_into().ok()?;
+        Some(Self {
+            id: element.id(),
+            tag: element.tag_name().to_lowercase(),
+            name: element.get_attribute("name"),
+            selection: selection_range(&element),
+            element,
+        })
+    }
+
+    /// restore the focus to the equivalent element in the `container`,
+    /// if the focus is lost after the patches are applied
+    pub(crate) fn restore(self, container: &web_sys::Node) {
+        let focus_is_lost = match document().active_element() {
+            Some(active) => document().body().is_some_and(|body| *body == active),
+            None => true,
+        };
+        if !focus_is_lost {
+            return;
+        }
+        let Some(element) = self.equivalent_element(container) else {
+            return;
+        };
+        if element.focus().is_err() {
+            return;
+        }
+        if let Some((start, end)) = self.selection {
+            if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+                input.set_selection_range(start, end).ok();
+            } else if let Some(textarea) = element.dyn_ref::<HtmlTextAreaElement>() {
+                textarea.set_selection_range(start, end).ok();
+            }
+        }
+    }
+
+    fn equivalent_element(&self, container: &web_sys::Node) -> Option<HtmlElement> {
+        if self.element.is_connected() {
+            return Some(self.element.clone());
+        }
+        let element = if !self.id.is_empty() {
+            document()
+                .get_element_by_id(&self.id)
+                .filter(|element| container.contains(Some(element)))
+        } else {
+            let container: &web_sys::Element = container.dyn_ref()?;
+            let selector = format!("{}[name=\"{}\"]", self.tag, self.name.as_ref()?);
+            container.query_selector(&selector).ok().flatten()
+        };
+        element.and_then(|element| element.dyn_into().ok())
+    }
+}
+
+/// the selection range of text inputs and textareas,
+/// the input types which has no selection such as checkboxes returns None
+fn selection_range(element: &HtmlElement) -> Option<(u32, u32)> {
+    if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+        Some((input.selection_start().ok()??, input.selection_end().ok()??))
+    } else if let Some(textarea) = element.dyn_ref::<HtmlTextAreaElement>() {
+        Some((
+            textarea.selection_start().ok()??,
+            textarea.selection_end().ok()??,
+        ))
+    } else {
+        None
+    }
+}
+
+impl<MSG> Cmd<MSG>
+where
+    MSG: 'static,
+{
+    /// move the focus to the next focusable element in the container,
+    /// this is executed when the Cmd is emitted, after the dom is updated,
+    /// then the `msg` is dispatched
+    pub fn focus_next(container_id: impl Into<String>, msg: MSG) -> Self {
+        let container_id = container_id.into();
+        Cmd::once(async move {
+            focus_next(&container_id);
+            msg
+        })
+    }
+
+    /// move the focus to the previous focusable element in the container,
+    /// this is executed when the Cmd is emitted, after the dom is updated,
+    /// then the `msg` is dispatched
+    pub fn focus_previous(container_id: impl Into<String>, msg: MSG) -> Self {
+        let container_id = container_id.into();
+        Cmd::once(async move {
+            focus_previous(&container_id);
+            msg
+        })
+    }
+}
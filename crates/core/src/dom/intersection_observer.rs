@@ -0,0 +1,59 @@
+use crate::dom::events::{INTERSECT_EVENT, INTERSECT_THRESHOLD};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+    CustomEvent, CustomEventInit, IntersectionObserver, IntersectionObserverEntry,
+    IntersectionObserverInit,
+};
+
+/// observes the intersection of an element with the viewport and dispatch it as an intersect
+/// event to the element, the observer is disconnected when this is dropped
+pub(crate) struct ElementIntersectionObserver {
+    observer: IntersectionObserver,
+    _closure: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+impl Drop for ElementIntersectionObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+impl ElementIntersectionObserver {
+    /// start observing the intersection of the `element` with the viewport,
+    /// using the thresholds set in its `intersect_threshold` attribute
+    pub(crate) fn new(element: &web_sys::Element) -> Result<Self, JsValue> {
+        let closure: Closure<dyn FnMut(js_sys::Array)> =
+            Closure::new(move |entries: js_sys::Array| {
+                for entry in entries.iter() {
+                    let entry: IntersectionObserverEntry = entry.unchecked_into();
+                    let event_init = CustomEventInit::new();
+                    event_init.set_detail(&entry);
+                    let event = CustomEvent::new_with_event_init_dict(INTERSECT_EVENT, &event_init)
+                        .expect("must create intersect event");
+                    entry
+                        .target()
+                        .dispatch_event(&event)
+                        .expect("must dispatch intersect event");
+                }
+            });
+        let thresholds: js_sys::Array = element
+            .get_attribute(INTERSECT_THRESHOLD)
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|threshold| threshold.parse::<f64>().ok())
+            .map(JsValue::from_f64)
+            .collect();
+        let options = IntersectionObserverInit::new();
+        if thresholds.length() > 0 {
+            options.set_threshold(&thresholds);
+        }
+        let observer =
+            IntersectionObserver::new_with_options(closure.as_ref().unchecked_ref(), &options)?;
+        observer.observe(element);
+        Ok(Self {
+            observer,
+            _closure: closure,
+        })
+    }
+}
@@ -0,0 +1,69 @@
+//! measure the elements of the view after they are rendered
+use crate::dom::{document, request_animation_frame, Cmd};
+use futures::channel::oneshot;
+use web_sys::DomRect;
+
+/// The bounding rectangle of an element relative to the viewport, in css pixels,
+/// see [`Cmd::measure`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RectInfo {
+    /// the distance of the left edge from the left of the viewport
+    pub x: f64,
+    /// the distance of the top edge from the top of the viewport
+    pub y: f64,
+    /// the width including the padding and the border
+    pub width: f64,
+    /// the height including the padding and the border
+    pub height: f64,
+}
+
+impl From<DomRect> for RectInfo {
+    fn from(rect: DomRect) -> Self {
+        RectInfo {
+            x: rect.x(),
+            y: rect.y(),
+            width: rect.width(),
+            height: rect.height(),
+        }
+    }
+}
+
+/// wait for the next animation frame, by then the patches of the current update are applied
+async fn next_animation_frame() {
+    let (tx, rx) = oneshot::channel();
+    let mut tx = Some(tx);
+    let _handle = request_animation_frame(move || {
+        if let Some(tx) = tx.take() {
+            tx.send(()).ok();
+        }
+    })
+    .expect("must request animation frame");
+    rx.await.ok();
+}
+
+impl<MSG> Cmd<MSG>
+where
+    MSG: 'static,
+{
+    /// measure the bounding rectangle of the element with `element_id` after the next render,
+    /// and dispatch the msg created by `to_msg`, such as for positioning a tooltip
+    /// next to its anchor.
+    ///
+    /// The msg is created with `None` when there is no element with the `element_id`.
+    pub fn measure<F>(element_id: impl Into<String>, to_msg: F) -> Self
+    where
+        F: FnOnce(Option<RectInfo>) -> MSG + 'static,
+    {
+        let element_id = element_id.into();
+        Cmd::once(async move {
+            next_animation_frame().await;
+            let rect = document()
+                .get_element_by_id(&element_id)
+                .map(|element| RectInfo::from(element.get_bounding_client_rect()));
+            if rect.is_none() {
+                log::warn!("unable to measure, there is no element with id: {element_id}");
+            }
+            to_msg(rect)
+        })
+    }
+}
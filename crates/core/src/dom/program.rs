@@ -1,3 +1,5 @@
+use crate::dom::context::Contexts;
+use crate::dom::focus::FocusSnapshot;
 use crate::dom::program::app_context::WeakContext;
 #[cfg(feature = "with-raf")]
 use crate::dom::request_animation_frame;
@@ -6,13 +8,15 @@ use crate::dom::request_idle_callback;
 use crate::dom::DomNode;
 use crate::dom::SkipDiff;
 use crate::dom::SkipPath;
-use crate::dom::{document, now, IdleDeadline, Measurements};
+use crate::dom::{current_url, document, now, IdleDeadline, Measurements};
 use crate::dom::{util::body, AnimationFrameHandle, Application, DomPatch, IdleCallbackHandle};
 use crate::html::{self, attributes::class, text};
 use crate::vdom;
-use crate::vdom::diff;
+use crate::vdom::diff_with_options;
+use crate::vdom::DiffOptions;
 use crate::vdom::diff_recursive;
 use crate::vdom::Patch;
+use crate::vdom::TreePath;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::VecDeque;
 use std::hash::{Hash, Hasher};
@@ -28,6 +32,17 @@ use web_sys;
 
 pub(crate) use app_context::AppContext;
 pub use mount_procedure::{MountAction, MountProcedure, MountTarget};
+#[cfg(feature = "test-fixtures")]
+pub use simulate_event::SimulatedEvent;
+use radio_group::sync_radio_groups;
+use render_lock::{RenderLock, SuspendGuard};
+use optimistic::OptimisticLog;
+pub(crate) use listener_pool::ListenerPool;
+use router::Router;
+use resource_registry::ResourceRegistry;
+use scroll_snapshot::ScrollSnapshot;
+pub use update_guard::DEFAULT_MAX_UPDATES_PER_FRAME;
+use update_guard::UpdateGuard;
 
 
 
@@ -40,7 +55,22 @@ thread_local! {
 }
 
 mod app_context;
+mod global_error_handler;
+mod hydration;
+mod listener_pool;
 mod mount_procedure;
+mod optimistic;
+mod radio_group;
+mod render_lock;
+mod router;
+mod resource_registry;
+mod scroll_snapshot;
+#[cfg(feature = "test-fixtures")]
+mod simulate_event;
+mod update_guard;
+
+/// called with the number of patches after the patches of a render are applied
+type AfterRenderCallback = Rc<dyn Fn(usize)>;
 
 /// Program handle the lifecycle of the APP
 pub struct Program<APP>
@@ -68,6 +98,36 @@ where
 
     /// keep track of the time when the dom is last updated
     pub(crate) last_update: Rc<RefCell<Option<f64>>>,
+
+    /// keep track of the number of updates in the current frame to detect runaway update loops
+    pub(crate) update_guard: Rc<RefCell<UpdateGuard>>,
+
+    /// whether the app's `stylesheet` and `style` are injected when mounted
+    pub(crate) inject_styles: Rc<RefCell<bool>>,
+
+    /// callbacks which are called with the number of patches after the patches are applied
+    pub(crate) after_render_callbacks: Rc<RefCell<Vec<AfterRenderCallback>>>,
+
+    /// the mount procedure of the app view which is deferred until the program is `ready`
+    pub(crate) deferred_mount: Rc<RefCell<Option<MountProcedure>>>,
+
+    /// queue the renders which are requested while rendering
+    pub(crate) render_lock: Rc<RefCell<RenderLock>>,
+
+    /// the options used when diffing the views of the app
+    pub(crate) diff_options: Rc<RefCell<DiffOptions>>,
+
+    /// the resources which are tied to the elements of the view
+    pub(crate) resources: Rc<RefCell<ResourceRegistry>>,
+
+    /// the listeners of the integrated routing, when the app has a route
+    pub(crate) router: Rc<RefCell<Option<Router>>>,
+
+    /// the state of the app and the msgs which are kept to roll back the optimistic updates
+    pub(crate) optimistic: Rc<RefCell<OptimisticLog<APP>>>,
+
+    /// the closures of the event listeners which are shared by the elements
+    pub(crate) listener_pool: Rc<RefCell<ListenerPool>>,
 }
 
 pub struct WeakProgram<APP>
@@ -81,6 +141,16 @@ where
     idle_callback_handles: Weak<RefCell<Vec<IdleCallbackHandle>>>,
     animation_frame_handles: Weak<RefCell<Vec<AnimationFrameHandle>>>,
     last_update: Weak<RefCell<Option<f64>>>,
+    update_guard: Weak<RefCell<UpdateGuard>>,
+    inject_styles: Weak<RefCell<bool>>,
+    after_render_callbacks: Weak<RefCell<Vec<AfterRenderCallback>>>,
+    deferred_mount: Weak<RefCell<Option<MountProcedure>>>,
+    render_lock: Weak<RefCell<RenderLock>>,
+    diff_options: Weak<RefCell<DiffOptions>>,
+    resources: Weak<RefCell<ResourceRegistry>>,
+    router: Weak<RefCell<Option<Router>>>,
+    optimistic: Weak<RefCell<OptimisticLog<APP>>>,
+    listener_pool: Weak<RefCell<ListenerPool>>,
 }
 
 impl<APP> WeakProgram<APP>
@@ -96,6 +166,16 @@ where
         let idle_callback_handles = self.idle_callback_handles.upgrade()?;
         let animation_frame_handles = self.animation_frame_handles.upgrade()?;
         let last_update = self.last_update.upgrade()?;
+        let update_guard = self.update_guard.upgrade()?;
+        let inject_styles = self.inject_styles.upgrade()?;
+        let after_render_callbacks = self.after_render_callbacks.upgrade()?;
+        let deferred_mount = self.deferred_mount.upgrade()?;
+        let render_lock = self.render_lock.upgrade()?;
+        let diff_options = self.diff_options.upgrade()?;
+        let resources = self.resources.upgrade()?;
+        let router = self.router.upgrade()?;
+        let optimistic = self.optimistic.upgrade()?;
+        let listener_pool = self.listener_pool.upgrade()?;
         Some(Program {
             app_context,
             root_node,
@@ -104,6 +184,16 @@ where
             idle_callback_handles,
             animation_frame_handles,
             last_update,
+            update_guard,
+            inject_styles,
+            after_render_callbacks,
+            deferred_mount,
+            render_lock,
+            diff_options,
+            resources,
+            router,
+            optimistic,
+            listener_pool,
         })
     }
 }
@@ -121,6 +211,16 @@ where
             idle_callback_handles: Weak::clone(&self.idle_callback_handles),
             animation_frame_handles: Weak::clone(&self.animation_frame_handles),
             last_update: Weak::clone(&self.last_update),
+            update_guard: Weak::clone(&self.update_guard),
+            inject_styles: Weak::clone(&self.inject_styles),
+            after_render_callbacks: Weak::clone(&self.after_render_callbacks),
+            deferred_mount: Weak::clone(&self.deferred_mount),
+            render_lock: Weak::clone(&self.render_lock),
+            diff_options: Weak::clone(&self.diff_options),
+            resources: Weak::clone(&self.resources),
+            router: Weak::clone(&self.router),
+            optimistic: Weak::clone(&self.optimistic),
+            listener_pool: Weak::clone(&self.listener_pool),
         }
     }
 }
@@ -139,6 +239,16 @@ where
             idle_callback_handles: Rc::downgrade(&self.idle_callback_handles),
             animation_frame_handles: Rc::downgrade(&self.animation_frame_handles),
             last_update: Rc::downgrade(&self.last_update),
+            update_guard: Rc::downgrade(&self.update_guard),
+            inject_styles: Rc::downgrade(&self.inject_styles),
+            after_render_callbacks: Rc::downgrade(&self.after_render_callbacks),
+            deferred_mount: Rc::downgrade(&self.deferred_mount),
+            render_lock: Rc::downgrade(&self.render_lock),
+            diff_options: Rc::downgrade(&self.diff_options),
+            resources: Rc::downgrade(&self.resources),
+            router: Rc::downgrade(&self.router),
+            optimistic: Rc::downgrade(&self.optimistic),
+            listener_pool: Rc::downgrade(&self.listener_pool),
         }
     }
 }
@@ -156,6 +266,16 @@ where
             idle_callback_handles: Rc::clone(&self.idle_callback_handles),
             animation_frame_handles: Rc::clone(&self.animation_frame_handles),
             last_update: Rc::clone(&self.last_update),
+            update_guard: Rc::clone(&self.update_guard),
+            inject_styles: Rc::clone(&self.inject_styles),
+            after_render_callbacks: Rc::clone(&self.after_render_callbacks),
+            deferred_mount: Rc::clone(&self.deferred_mount),
+            render_lock: Rc::clone(&self.render_lock),
+            diff_options: Rc::clone(&self.diff_options),
+            resources: Rc::clone(&self.resources),
+            router: Rc::clone(&self.router),
+            optimistic: Rc::clone(&self.optimistic),
+            listener_pool: Rc::clone(&self.listener_pool),
         }
     }
 }
@@ -194,6 +314,7 @@ where
                 current_vdom: Rc::new(RefCell::new(app_view)),
                 pending_msgs: Rc::new(RefCell::new(VecDeque::new())),
                 pending_dispatches: Rc::new(RefCell::new(VecDeque::new())),
+                contexts: Rc::new(RefCell::new(Contexts::default())),
             },
             root_node: Rc::new(RefCell::new(None)),
             mount_node: Rc::new(RefCell::new(None)),
@@ -201,9 +322,62 @@ where
             idle_callback_handles: Rc::new(RefCell::new(vec![])),
             animation_frame_handles: Rc::new(RefCell::new(vec![])),
             last_update: Rc::new(RefCell::new(None)),
+            update_guard: Rc::new(RefCell::new(UpdateGuard::default())),
+            inject_styles: Rc::new(RefCell::new(true)),
+            after_render_callbacks: Rc::new(RefCell::new(vec![])),
+            deferred_mount: Rc::new(RefCell::new(None)),
+            render_lock: Rc::new(RefCell::new(RenderLock::default())),
+            diff_options: Rc::new(RefCell::new(DiffOptions::default())),
+            resources: Rc::new(RefCell::new(ResourceRegistry::default())),
+            router: Rc::new(RefCell::new(None)),
+            optimistic: Rc::new(RefCell::new(OptimisticLog::default())),
+            listener_pool: Rc::new(RefCell::new(ListenerPool::default())),
+        }
+    }
+
+    /// set the maximum number of updates the program will do in a single frame.
+    /// When an app's update keeps on dispatching msgs in a loop, the exceeding updates
+    /// are deferred to the next frame and a warning is logged instead of freezing the page.
+    /// The default is [`DEFAULT_MAX_UPDATES_PER_FRAME`], `None` disables the guard.
+    pub fn with_max_updates_per_frame(self, max_updates: Option<usize>) -> Self {
+        self.update_guard.borrow_mut().max_updates_per_frame = max_updates;
+        self
+    }
+
+    /// set the options used when diffing the views of the app, such as the tolerance
+    /// when comparing the float values of attributes, see [`DiffOptions`]
+    pub fn with_diff_options(self, diff_options: DiffOptions) -> Self {
+        *self.diff_options.borrow_mut() = diff_options;
+        self
+    }
+
+    /// provide a context value of type `T` to the view of the app, which is read with
+    /// [`use_context`](crate::dom::use_context), replacing the previous value of the same type.
+    ///
+    /// The view is rendered again with the new value: before the program is mounted
+    /// the initial view is recreated, otherwise the DOM is patched right away.
+    pub fn provide<T: 'static>(&mut self, value: T) {
+        self.app_context.contexts.borrow_mut().insert(value);
+        if self.mount_node.borrow().is_none() {
+            let view = self.app_context.view();
+            self.app_context.set_current_dom(view);
+        } else {
+            self.update_dom().expect("must update dom");
         }
     }
 
+    /// set whether the app's `stylesheet` and `style` are injected when the program is mounted.
+    /// Disable this when the css is managed externally, such as by a bundler.
+    ///
+    /// The styles of a program are injected into the document `head`, which doesn't apply
+    /// to elements inside a shadow root, while stateful components inject their styles
+    /// into their own mount node. An identical style of the same app type is only injected once,
+    /// so mounting the app again doesn't produce duplicate style elements.
+    pub fn with_style_injection(self, inject_styles: bool) -> Self {
+        *self.inject_styles.borrow_mut() = inject_styles;
+        self
+    }
+
     /// executed after the program has been mounted
     fn after_mounted(&mut self) {
         // call the init of the component
@@ -214,15 +388,32 @@ where
         // may just return Cmd::none which doesn't trigger
         // dispatching / redraw
         init_cmd.emit(self.clone());
+        self.start_routing();
 
         // inject the app's dynamic style after the emitting the init function and it's effects
         self.inject_dynamic_style();
+        self.apply_document_metadata();
 
         // first dispatch call to ensure the template is patched with the
         // new app real view
         //self.dispatch_multiple([]);
     }
 
+    /// listen to the navigation of the user when the app has a route,
+    /// and update the app with the current url
+    fn start_routing(&mut self) {
+        if self.app_context.route().is_none() {
+            return;
+        }
+        let Some(mount_node) = self.mount_node.borrow().as_ref().map(|node| node.as_node()) else {
+            return;
+        };
+        *self.router.borrow_mut() = Some(Router::start(self, &mount_node));
+        if let Some(msg) = self.app_context.on_route_change(&current_url()) {
+            self.dispatch(msg);
+        }
+    }
+
     fn app_hash() -> u64 {
         let type_id = TypeId::of::<APP>();
         let mut hasher = DefaultHasher::new();
@@ -238,6 +429,52 @@ where
         }
     }
 
+    /// push the route of the app into the browser history when it changed
+    fn apply_route(&self) {
+        if self.router.borrow().is_none() {
+            return;
+        }
+        if let Some(route) = self.app_context.route() {
+            router::push_route(&route);
+        }
+    }
+
+    /// set the title and the meta tags of the document from the app
+    fn apply_document_metadata(&self) {
+        if let Some(title) = self.app_context.title() {
+            if document().title() != title {
+                document().set_title(&title);
+            }
+        }
+        let meta = self.app_context.meta();
+        if meta.is_empty() {
+            return;
+        }
+        let head = document().head().expect("must have a head");
+        for (name, content) in meta {
+            let selector = format!("meta[name=\"{name}\"]");
+            let meta_element = match head.query_selector(&selector).ok().flatten() {
+                Some(meta_element) => meta_element,
+                None => {
+                    let meta_element = document()
+                        .create_element("meta")
+                        .expect("must create meta element");
+                    meta_element
+                        .set_attribute("name", name)
+                        .expect("must set name");
+                    head.append_child(&meta_element)
+                        .expect("must append to head");
+                    meta_element
+                }
+            };
+            if meta_element.get_attribute("content").as_deref() != Some(&content) {
+                meta_element
+                    .set_attribute("content", &content)
+                    .expect("must set content");
+            }
+        }
+    }
+
     fn inject_dynamic_style(&mut self) {
         let dynamic_style = self.app_context.dynamic_style();
         if !dynamic_style.is_empty() {
@@ -329,6 +566,49 @@ where
         Self::append_to_mount(app, &body())
     }
 
+    /// Instantiate the app and mount it to the first element matching the css `selector`,
+    /// such as `"#app"`, with the mount `action` and `target`.
+    ///
+    /// Returns an error if the selector is invalid or no element matches it.
+    /// A warning is logged if more than one element matches the selector.
+    /// # Example
+    /// ```rust,ignore
+    /// # use sauron::prelude::*;
+    /// struct App{}
+    /// # impl Application<()> for App{
+    /// #     fn view(&self) -> Node<()>{
+    /// #         text("hello")
+    /// #     }
+    /// #     fn update(&mut self, _: ()) -> Cmd<Self, ()> {
+    /// #         Cmd::none()
+    /// #     }
+    /// # }
+    /// Program::mount_to_selector(App{}, "#app", MountAction::Append, MountTarget::MountNode)
+    ///     .expect("must mount");
+    /// ```
+    pub fn mount_to_selector(
+        app: APP,
+        selector: &str,
+        action: MountAction,
+        target: MountTarget,
+    ) -> Result<ManuallyDrop<Self>, JsValue> {
+        let matches = document().query_selector_all(selector)?;
+        let Some(mount_node) = matches.get(0) else {
+            return Err(JsValue::from_str(&format!(
+                "no element matches the selector: {selector}"
+            )));
+        };
+        if matches.length() > 1 {
+            log::warn!(
+                "{} elements match the selector: {selector}, mounting to the first one",
+                matches.length()
+            );
+        }
+        let mut program = Self::new(app);
+        program.mount(&mount_node, MountProcedure::new(action, target));
+        Ok(ManuallyDrop::new(program))
+    }
+
     /// executed right before the app is mounted to the dom
     pub fn pre_mount(&mut self) {
         self.inject_stylesheet();
@@ -340,17 +620,81 @@ where
     pub(crate) fn create_initial_view(&self) -> DomNode {
         let current_view = self.app_context.current_vdom();
         let real_view = current_view.unwrap_template_ref();
+        #[cfg(all(debug_assertions, feature = "check-duplicate-ids"))]
+        warn_duplicate_ids(real_view);
+        #[cfg(all(debug_assertions, feature = "check-namespaces"))]
+        warn_namespace_mismatches(real_view);
         self.create_dom_node(real_view)
     }
 
     /// each element and it's descendant in the vdom is created into
     /// an actual DOM node.
+    ///
+    /// The whole tree of the view is built while it is detached from the document,
+    /// and is attached to the mount node in a single operation, so the browser doesn't
+    /// recalculate the layout for each of the created nodes.
     pub fn mount(&mut self, mount_node: &web_sys::Node, mount_procedure: MountProcedure) {
         let mount_node = DomNode::from(mount_node.clone());
         *self.mount_node.borrow_mut() = Some(mount_node);
         self.pre_mount();
+        self.attach_initial_view(mount_procedure);
+        self.after_mounted();
+    }
 
-        let created_node = self.create_initial_view();
+    /// Mount the app like [`Program::mount`], except the first render is deferred until
+    /// [`Program::ready`] is called, such as when the initial data of the app has been fetched.
+    /// This avoids flashing an empty view on startup.
+    ///
+    /// The `init` of the app is executed right away, and the dispatched msgs update the app
+    /// as usual, but the DOM is not touched until the program is ready. The existing content
+    /// of the mount node stays visible in the meantime, so with [`MountAction::Replace`] a
+    /// server rendered page stays as is until it is replaced with the view of the ready app.
+    /// Note that the server rendered content has no event listeners until then.
+    /// # Example
+    /// ```rust,ignore
+    /// let mut program = Program::new(App::default());
+    /// program.mount_deferred(&mount_node, MountProcedure::replace());
+    /// spawn_local(async move {
+    ///     let data = fetch_initial_data().await;
+    ///     program.app_mut().set_data(data);
+    ///     program.ready();
+    /// });
+    /// ```
+    pub fn mount_deferred(&mut self, mount_node: &web_sys::Node, mount_procedure: MountProcedure) {
+        let mount_node = DomNode::from(mount_node.clone());
+        *self.mount_node.borrow_mut() = Some(mount_node);
+        *self.deferred_mount.borrow_mut() = Some(mount_procedure);
+        self.pre_mount();
+        let init_cmd = self.app_context.init_app();
+        init_cmd.emit(self.clone());
+        self.start_routing();
+    }
+
+    /// Render the app view into the DOM, for a program which is mounted with
+    /// [`Program::mount_deferred`]. The view is created from the app state at this point,
+    /// and the subsequent updates are patched into the DOM as usual.
+    ///
+    /// This does nothing if the program is not deferred or is already ready.
+    pub fn ready(&mut self) {
+        let Some(mount_procedure) = self.deferred_mount.borrow_mut().take() else {
+            return;
+        };
+        let view = self.app_context.view();
+        self.app_context.set_current_dom(view);
+        self.attach_initial_view(mount_procedure);
+        self.inject_dynamic_style();
+        self.apply_document_metadata();
+    }
+
+    /// returns true if the first render of the program is deferred until it is `ready`
+    pub fn is_deferred(&self) -> bool {
+        self.deferred_mount.borrow().is_some()
+    }
+
+    /// create the dom node of the current app view and attach it to the mount node.
+    /// The created node already contains all of its descendants, such that attaching it
+    /// is the only change to the live DOM
+    fn attach_initial_view(&mut self, mount_procedure: MountProcedure) {
 
         let mount_node: DomNode = match mount_procedure.target {
             MountTarget::MountNode => self
@@ -382,6 +726,13 @@ where
             }
         };
 
+        if let MountAction::Hydrate = mount_procedure.action {
+            let hydrated_node = self.hydrate_initial_view(&mount_node);
+            *self.root_node.borrow_mut() = Some(hydrated_node);
+            return;
+        }
+
+        let created_node = self.create_initial_view();
         match mount_procedure.action {
             MountAction::Append => {
                 mount_node.append_children(vec![created_node.clone()]);
@@ -393,9 +744,9 @@ where
             MountAction::Replace => {
                 mount_node.replace_node(created_node.clone());
             }
+            MountAction::Hydrate => unreachable!("hydrated above"),
         }
         *self.root_node.borrow_mut() = Some(created_node);
-        self.after_mounted();
     }
 
     #[cfg(feature = "with-ric")]
@@ -420,7 +771,14 @@ where
             return Ok(());
         }
         let mut did_complete = true;
-        while self.app_context.dispatch_pending_msg() {
+        loop {
+            // the msg is recorded before it is applied, so the optimistic updates can be rolled back
+            if let Some(msg) = self.app_context.pending_msgs.borrow().front() {
+                self.optimistic.borrow_mut().record(msg);
+            }
+            if !self.app_context.dispatch_pending_msg() {
+                break;
+            }
             // break only if a deadline is supplied
             if let Some(deadline) = &deadline {
                 if deadline.did_timeout() {
@@ -439,7 +797,28 @@ where
     }
 
     /// execute DOM changes in order to reflect the APP's view into the browser representation
+    ///
+    /// This is safe to call while the DOM is being updated, such as from an `on_mount` or an
+    /// `on_after_render` callback, in which case the update is done right after the current one.
     pub fn update_dom(&mut self) -> Result<(), JsValue> {
+        // the view is created once the program is ready
+        if self.is_deferred() {
+            return Ok(());
+        }
+        if !self.render_lock.borrow_mut().acquire() {
+            return Ok(());
+        }
+        let result = self.render();
+        let is_rerender_requested = self.render_lock.borrow_mut().release();
+        result?;
+        if is_rerender_requested {
+            self.update_dom()?;
+        }
+        Ok(())
+    }
+
+    /// create the view of the app and patch the DOM with the difference to the current vdom
+    fn render(&mut self) -> Result<(), JsValue> {
         let t1 = now();
         //#[cfg(all(feature = "with-measure", feature = "with-debug"))]
         if let Some(last_update) = self.last_update.borrow().as_ref() {
@@ -463,7 +842,11 @@ where
         UPDATE_CNT.with_borrow_mut(|c|*c += 1);
         log::info!("ratio(cancelled/update): {}/{}", CANCEL_CNT.with_borrow(|c|*c), UPDATE_CNT.with_borrow(|c|*c));
         // a new view is created due to the app update
-        let view = self.app_context.view();
+        let mut view = self.app_context.view();
+        #[cfg(all(debug_assertions, feature = "check-duplicate-ids"))]
+        warn_duplicate_ids(&view);
+        #[cfg(all(debug_assertions, feature = "check-namespaces"))]
+        warn_namespace_mismatches(&view);
         let t2 = now();
 
         let node_count = view.node_count();
@@ -496,8 +879,14 @@ where
 
         // update the last DOM node tree with this new view
         self.queue_dom_patches(dom_patches).expect("must not error");
+        // the values which changed within the float tolerance are not patched
+        self.diff_options
+            .borrow()
+            .keep_skipped_values(&self.app_context.current_vdom(), &mut view);
         // set the current dom
         self.app_context.set_current_dom(view);
+        self.apply_document_metadata();
+        self.apply_route();
         let t3 = now();
 
         let strong_count = self.app_context.strong_count();
@@ -549,19 +938,22 @@ where
         new_vdom: &'a vdom::Node<APP::MSG>,
         skip_diff: &SkipDiff,
     ) -> Vec<Patch<'a, APP::MSG>> {
-        use crate::vdom::TreePath;
         assert!(!old_vdom.is_template(), "old vdom should not be a template");
         assert!(!new_vdom.is_template(), "new vdom should not be a template");
         diff_recursive(
             old_vdom,
             new_vdom,
-            &SkipPath::new(TreePath::root(), skip_diff.clone()),
+            &SkipPath::new(
+                TreePath::root(),
+                skip_diff.clone(),
+                *self.diff_options.borrow(),
+            ),
         )
     }
 
     fn create_dom_patch(&self, new_vdom: &vdom::Node<APP::MSG>) -> Vec<DomPatch> {
         let current_vdom = self.app_context.current_vdom();
-        let patches = diff(&current_vdom, new_vdom);
+        let patches = diff_with_options(&current_vdom, new_vdom, &self.diff_options.borrow());
 
         #[cfg(all(feature = "with-debug", feature = "log-patches"))]
         {
@@ -593,12 +985,39 @@ where
 
     /// apply the pending patches into the DOM
     fn apply_pending_patches(&mut self) -> Result<(), JsValue> {
+        let mount_node = self.mount_node.borrow().as_ref().map(|node| node.as_node());
         if self.pending_patches.borrow().is_empty() {
+            // the view may be unchanged while the user has checked another radio button
+            if let Some(mount_node) = &mount_node {
+                sync_radio_groups(mount_node);
+            }
             return Ok(());
         }
         let dom_patches: Vec<DomPatch> = self.pending_patches.borrow_mut().drain(..).collect();
+        let total_patches = dom_patches.len();
+        let focus = mount_node.as_ref().and_then(FocusSnapshot::take);
+        let scroll = mount_node.as_ref().and_then(ScrollSnapshot::take);
         self.apply_dom_patches(dom_patches)?;
+        if let (Some(scroll), Some(mount_node)) = (scroll, &mount_node) {
+            scroll.restore(mount_node);
+        }
+        if let Some(mount_node) = &mount_node {
+            sync_radio_groups(mount_node);
+        }
+        if let (Some(focus), Some(mount_node)) = (focus, &mount_node) {
+            focus.restore(mount_node);
+        }
+        let detached = self
+            .resources
+            .borrow_mut()
+            .take_detached(mount_node.as_ref());
+        drop(detached);
 
+        // clone the callbacks, so a callback can register another callback
+        let callbacks = self.after_render_callbacks.borrow().clone();
+        for callback in callbacks {
+            callback(total_patches);
+        }
         Ok(())
     }
 
@@ -659,6 +1078,27 @@ where
     /// - The view is reconstructed with the new state of the app.
     /// - The dom is updated with the newly reconstructed view.
     fn dispatch_inner(&mut self, deadline: Option<IdleDeadline>) {
+        // the msgs of an unmounted program are dropped
+        if self.render_lock.borrow().is_stopped() {
+            self.app_context.pending_msgs.borrow_mut().clear();
+            self.app_context.pending_dispatches.borrow_mut().clear();
+            return;
+        }
+        // the msgs are dispatched once the transaction is done
+        if self.render_lock.borrow().is_suspended() {
+            return;
+        }
+        let remaining = self.update_guard.borrow_mut().record_update(now());
+        if let Some(remaining) = remaining {
+            let program = Program::downgrade(self);
+            wasm_bindgen_futures::spawn_local(async move {
+                crate::dom::delay(remaining.ceil() as i32).await;
+                if let Some(mut program) = program.upgrade() {
+                    program.dispatch_inner(None);
+                }
+            });
+            return;
+        }
         self.dispatch_pending_msgs(deadline)
             .expect("must dispatch msgs");
         // ensure that all pending msgs are all dispatched already
@@ -710,12 +1150,25 @@ where
         cmd.emit(self.clone());
     }
 
-    /// Inject a style to the global document
+    /// Inject a style to the global document,
+    /// unless the same style with the same `class_names` is already injected
     fn inject_style(&mut self, class_names: String, style: &str) {
+        if !*self.inject_styles.borrow() {
+            return;
+        }
+        let head = document().head().expect("must have a head");
+        let selector = format!("style[class=\"{class_names}\"]");
+        if let Ok(injected) = head.query_selector_all(&selector) {
+            let is_injected = (0..injected.length())
+                .filter_map(|i| injected.item(i))
+                .any(|node| node.text_content().as_deref() == Some(style));
+            if is_injected {
+                return;
+            }
+        }
         let style_node = html::tags::style([class(class_names)], [text(style)]);
         let created_node = self.create_dom_node(&style_node);
 
-        let head = document().head().expect("must have a head");
         let head_node: web_sys::Node = head.unchecked_into();
         let dom_head = DomNode::from(head_node);
         dom_head.append_children(vec![created_node]);
@@ -723,6 +1176,9 @@ where
 
     /// inject style element to the mount node
     pub fn inject_style_to_mount(&mut self, style: &str) {
+        if !*self.inject_styles.borrow() || style.is_empty() {
+            return;
+        }
         let style_node = html::tags::style([], [text(style)]);
         let created_node = self.create_dom_node(&style_node);
 
@@ -739,10 +1195,264 @@ where
         self.dispatch_inner_with_priority_ric();
     }
 
+    /// register a callback which is called with the number of applied patches,
+    /// each time the DOM is patched to reflect the app's view.
+    ///
+    /// This is useful for integrating libraries which need to re-scan the DOM,
+    /// such as syntax highlighters. Unlike `on_mount` which is per element, this is called
+    /// once per render and only when there were patches applied.
+    /// It is called after all the patches of the render are applied,
+    /// which is after the `on_mount` of the newly created elements.
+    ///
+    /// With the `with-measure` feature, the number of patches is also passed to
+    /// [`Application::measurements`], which is called once the patches are queued. Without the `with-raf` feature the patches are
+    /// applied right away, so this is called before `measurements` of the same render.
+    /// With `with-raf` the patches are applied in the next animation frame,
+    /// so this is called after `measurements`.
+    pub fn on_after_render(&self, f: impl Fn(usize) + 'static) {
+        self.after_render_callbacks.borrow_mut().push(Rc::new(f));
+    }
+
+    /// take a snapshot of the current view which is reflected in the DOM,
+    /// such as for sending the state of the UI to a logging backend when diagnosing an issue.
+    ///
+    /// The event listeners are necessarily omitted, see [`vdom::Node::to_serializable`].
+    /// Enable the `serde` feature to serialize the snapshot.
+    pub fn snapshot(&self) -> vdom::SerializableNode {
+        self.app_context.current_vdom().to_serializable()
+    }
+
     /// dispatch a single msg
     pub fn dispatch(&mut self, msg: APP::MSG) {
         self.dispatch_multiple([msg])
     }
+
+    /// update the app with the `msg` and patch the DOM right away, instead of queueing it
+    /// for the next idle or animation frame.
+    /// This is used for the listeners of [`on_with_event`](crate::dom::events::on_with_event),
+    /// so that `update` is called while the event is still being dispatched.
+    pub(crate) fn dispatch_sync(&mut self, msg: APP::MSG) {
+        self.app_context.push_msgs([msg]);
+        self.dispatch_inner(None);
+    }
+
+    /// Tie a `resource` such as a timer handle, an observer or a debounced callback to the
+    /// `element`, so it is dropped once the element is removed from the view,
+    /// or when the program is [unmounted](Program::unmount).
+    ///
+    /// The resource is kept as long as the element is in the document after each render,
+    /// so an element which is moved by the diff, such as a reordered keyed element,
+    /// keeps its resources.
+    /// # Example
+    /// ```rust,ignore
+    /// div([on_mount(|mount| Msg::StartPolling(mount.target_node.as_element()))], [])
+    ///
+    /// // in update, with a handle to the program
+    /// let handle = request_timeout_callback(poll, 1000)?;
+    /// program.register_resource(&element, handle);
+    /// ```
+    pub fn register_resource(&self, element: &web_sys::Element, resource: impl std::any::Any) {
+        self.resources
+            .borrow_mut()
+            .register(element.clone(), Box::new(resource));
+    }
+
+    /// the number of resources which are registered with [`Program::register_resource`]
+    /// and are not yet released
+    pub fn resource_count(&self) -> usize {
+        self.resources.borrow().len()
+    }
+
+    /// Remove the view of the app from the DOM, and release the resources which are tied
+    /// to its elements, such as the observers and the registered resources.
+    ///
+    /// The program is not rendered again after this. The queued updates are cancelled,
+    /// and the msgs which are dispatched afterwards, such as from a `Cmd` which is still
+    /// running, are dropped.
+    ///
+    /// The styles which are injected by the program are kept.
+    pub fn unmount(&mut self) {
+        self.render_lock.borrow_mut().stop();
+        self.pending_patches.borrow_mut().clear();
+        // dropping the handles cancels the queued idle callbacks and animation frames
+        self.idle_callback_handles.borrow_mut().clear();
+        self.animation_frame_handles.borrow_mut().clear();
+        self.app_context.pending_msgs.borrow_mut().clear();
+        self.app_context.pending_dispatches.borrow_mut().clear();
+        self.router.borrow_mut().take();
+        if let Some(root_node) = self.root_node.borrow_mut().take() {
+            root_node.release_removed();
+            root_node.detach();
+        }
+        self.mount_node.borrow_mut().take();
+        let resources = self.resources.borrow_mut().take_all();
+        drop(resources);
+    }
+
+    /// re-run the app's `view` and patch the DOM with the difference, without dispatching any msg.
+    ///
+    /// This is a manual escape hatch for when the view depends on external state which changed
+    /// outside of `update`, such as a global store observed elsewhere.
+    /// Normal apps should drive the rendering by dispatching msgs instead.
+    pub fn redraw(&self) {
+        let mut program = self.clone();
+        program.update_dom().expect("must update dom");
+    }
+
+    /// make several changes to the app with `f` which has a direct mutable access to the app,
+    /// and update the DOM just once after all the changes are done,
+    /// such as for a multi-step change to the state of the app which is driven from javascript.
+    ///
+    /// Unlike [`Program::dispatch_multiple`] this doesn't go through the app's `update`,
+    /// so there are no `Cmd` to be emitted from the changes.
+    ///
+    /// The rendering is suspended while `f` is running, and the msgs which are dispatched
+    /// in the meantime, such as from the event listeners, are queued and dispatched after
+    /// the DOM is updated. The rendering is resumed even when `f` panics.
+    ///
+    /// The app is borrowed mutably for the duration of `f`, so this returns an error
+    /// when the app is already borrowed, such as when this is called from within the app's
+    /// `update` or `view`. Likewise, calling [`Program::app`], [`Program::app_mut`] or
+    /// another `transaction` inside `f` panics since the app is already borrowed.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// program.transaction(|app| {
+    ///     app.items.clear();
+    ///     app.items.extend(loaded_items);
+    ///     app.selected = None;
+    /// })?;
+    /// ```
+    pub fn transaction<R>(&mut self, f: impl FnOnce(&mut APP) -> R) -> Result<R, JsValue> {
+        let result = {
+            let mut app = self.app_context.app.try_borrow_mut().map_err(|_| {
+                JsValue::from_str("the app is already borrowed, a transaction can not start")
+            })?;
+            let _suspended = SuspendGuard::new(&self.render_lock);
+            f(&mut app)
+        };
+        self.update_dom()?;
+        if self.app_context.has_pending_msgs() {
+            self.dispatch_inner(None);
+        }
+        Ok(result)
+    }
+
+    /// dispatch a `CustomEvent` with the `name` from the element where the app is mounted,
+    /// so the page which hosts the app can listen to it,
+    /// such as a custom element notifying the `change` of its value.
+    ///
+    /// The `detail` is passed as is to the `detail` of the event, which can be any js value
+    /// such as a string or an object created with `serde_wasm_bindgen::to_value`.
+    /// The event bubbles, so it can be listened to on an ancestor of the mount element,
+    /// and is composed, so it crosses the shadow root into the page when the app is mounted
+    /// in a shadow root, in which case it is dispatched from the host element of the shadow root.
+    /// The event is cancelable, this returns false if a listener called `prevent_default` on it.
+    pub fn emit_event(&self, name: &str, detail: JsValue) -> Result<bool, JsValue> {
+        let mount_node = self
+            .mount_node
+            .borrow()
+            .as_ref()
+            .map(|node| node.as_node())
+            .ok_or_else(|| JsValue::from_str("the program is not mounted"))?;
+        let target: web_sys::EventTarget = match mount_node.dyn_into::<web_sys::ShadowRoot>() {
+            Ok(shadow_root) => shadow_root.host().into(),
+            Err(mount_node) => mount_node.into(),
+        };
+        let event_init = web_sys::CustomEventInit::new();
+        event_init.set_bubbles(true);
+        event_init.set_composed(true);
+        event_init.set_cancelable(true);
+        event_init.set_detail(&detail);
+        let event = web_sys::CustomEvent::new_with_event_init_dict(name, &event_init)?;
+        target.dispatch_event(&event)
+    }
+
+    /// returns the DOM element which was created for the element with the [`key`](crate::html::attributes::key)
+    /// in the current view, such as for scrolling a list item into view.
+    ///
+    /// The element is looked up in the current vdom and resolved to the DOM node at the same path,
+    /// the vdom and the DOM are kept in sync by the patches, so there is no separate map of keys to keep up to date.
+    /// The patches which are still waiting for the next animation frame are applied first,
+    /// since the current vdom already contains the changes of these patches.
+    /// If more than one element use the same key, the first one is returned and a warning is logged.
+    /// Returns `None` if there is no element with the key, or if the app is not mounted yet.
+    pub fn element_for_key(&self, key: impl Into<vdom::Value>) -> Option<web_sys::Element> {
+        let key = key.into();
+        if !self.pending_patches.borrow().is_empty() {
+            self.clone()
+                .apply_pending_patches()
+                .expect("must apply pending patches");
+        }
+        let mut path = {
+            let current_vdom = self.app_context.current_vdom();
+            let mut paths = vec![];
+            find_key_paths(&current_vdom, &TreePath::root(), &key, &mut paths);
+            if paths.len() > 1 {
+                log::warn!(
+                    "duplicate key: {key:?} is used by {} elements, the first one is returned",
+                    paths.len()
+                );
+            }
+            paths.into_iter().next()?
+        };
+        let root_node = self.root_node.borrow();
+        root_node
+            .as_ref()?
+            .find_node(&mut path)
+            .and_then(|node| node.as_node().dyn_into::<web_sys::Element>().ok())
+    }
+
+    /// replace the node at the `path` of child indexes with `new_node`, in both the current
+    /// vdom and the DOM, without diffing the rest of the view.
+    ///
+    /// This is a manual fast path for apps which know exactly which part of the view changed.
+    /// The next `view` of the app should produce the same node at that path,
+    /// otherwise it is reverted on the next update.
+    /// Returns an error if there is no node at the path.
+    pub fn patch_at(
+        &mut self,
+        path: impl Into<TreePath>,
+        new_node: vdom::Node<APP::MSG>,
+    ) -> Result<(), JsValue> {
+        let path = path.into();
+        // the vdom is replaced first, so the DOM is left untouched when there is no node at the path
+        let Some(old_node) = self
+            .app_context
+            .current_vdom
+            .borrow_mut()
+            .replace_node_at_path(path.clone(), new_node)
+        else {
+            return Err(JsValue::from_str(&format!(
+                "there is no node at path: {:?}",
+                path.path
+            )));
+        };
+        let dom_patches = {
+            let current_vdom = self.app_context.current_vdom();
+            let new_node = path
+                .find_node_by_path(&current_vdom)
+                .expect("must have the replaced node");
+            let patch = Patch::replace_node(old_node.tag(), path.clone(), [new_node]);
+            let root_node = self.root_node.borrow();
+            let root_node = root_node.as_ref().expect("must have a root node");
+            self.convert_patches(root_node, &[patch])
+        };
+        let dom_patches = match dom_patches {
+            Ok(dom_patches) => dom_patches,
+            Err(e) => {
+                // put back the old node, so the vdom still matches the DOM
+                self.app_context
+                    .current_vdom
+                    .borrow_mut()
+                    .replace_node_at_path(path, old_node);
+                return Err(e);
+            }
+        };
+        self.pending_patches.borrow_mut().extend(dom_patches);
+        self.apply_pending_patches()?;
+        Ok(())
+    }
 }
 
 impl<APP> Program<APP>
@@ -755,15 +1465,49 @@ where
     #[cfg(feature = "test-fixtures")]
     pub fn update_dom_with_vdom(
         &mut self,
-        new_vdom: vdom::Node<APP::MSG>,
+        mut new_vdom: vdom::Node<APP::MSG>,
     ) -> Result<usize, JsValue> {
         let dom_patches = self.create_dom_patch(&new_vdom);
         let total_patches = dom_patches.len();
         self.pending_patches.borrow_mut().extend(dom_patches);
 
         self.apply_pending_patches().expect("raf");
+        self.diff_options
+            .borrow()
+            .keep_skipped_values(&self.app_context.current_vdom(), &mut new_vdom);
 
         self.app_context.set_current_dom(new_vdom);
         Ok(total_patches)
     }
 }
+
+/// collect the paths of the elements which `key` attribute is `key`, in document order
+fn find_key_paths<MSG>(
+    node: &vdom::Node<MSG>,
+    path: &TreePath,
+    key: &vdom::Value,
+    paths: &mut Vec<TreePath>,
+) {
+    if node.first_value(vdom::KEY) == Some(key) {
+        paths.push(path.clone());
+    }
+    for (idx, child) in node.children().iter().enumerate() {
+        find_key_paths(child, &path.traverse(idx), key, paths);
+    }
+}
+
+/// log a warning for each id which is used by more than one element in the view
+#[cfg(all(debug_assertions, feature = "check-duplicate-ids"))]
+fn warn_duplicate_ids<MSG>(view: &vdom::Node<MSG>) {
+    for id in view.duplicate_ids() {
+        log::warn!("duplicate id: `{id}` is used by more than one element in the view");
+    }
+}
+
+/// log a warning for each element which is created in a namespace that doesn't match its tag
+#[cfg(all(debug_assertions, feature = "check-namespaces"))]
+fn warn_namespace_mismatches<MSG>(view: &vdom::Node<MSG>) {
+    for mismatch in view.namespace_mismatches() {
+        log::warn!("namespace mismatch: {mismatch}");
+    }
+}
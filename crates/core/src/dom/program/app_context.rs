@@ -1,3 +1,4 @@
+use crate::dom::context::Contexts;
 #[cfg(feature = "with-measure")]
 use crate::dom::Measurements;
 use crate::dom::{Application, Dispatch};
@@ -30,6 +31,9 @@ where
 
     /// pending cmds that hasn't been emited yet
     pub(crate) pending_dispatches: Rc<RefCell<VecDeque<Dispatch<APP>>>>,
+
+    /// the context values which are available to the view of the app
+    pub(crate) contexts: Rc<RefCell<Contexts>>,
 }
 
 pub(crate) struct WeakContext<APP>
@@ -40,6 +44,7 @@ where
     pub(crate) current_vdom: Weak<RefCell<vdom::Node<APP::MSG>>>,
     pub(crate) pending_msgs: Weak<RefCell<VecDeque<APP::MSG>>>,
     pub(crate) pending_dispatches: Weak<RefCell<VecDeque<Dispatch<APP>>>>,
+    pub(crate) contexts: Weak<RefCell<Contexts>>,
 }
 
 impl<APP> WeakContext<APP>
@@ -51,11 +56,13 @@ where
         let current_vdom = self.current_vdom.upgrade()?;
         let pending_msgs = self.pending_msgs.upgrade()?;
         let pending_dispatches = self.pending_dispatches.upgrade()?;
+        let contexts = self.contexts.upgrade()?;
         Some(AppContext {
             app,
             current_vdom,
             pending_msgs,
             pending_dispatches,
+            contexts,
         })
     }
 }
@@ -70,6 +77,7 @@ where
             current_vdom: Weak::clone(&self.current_vdom),
             pending_msgs: Weak::clone(&self.pending_msgs),
             pending_dispatches: Weak::clone(&self.pending_dispatches),
+            contexts: Weak::clone(&self.contexts),
         }
     }
 }
@@ -84,6 +92,7 @@ where
             current_vdom: Rc::downgrade(&this.current_vdom),
             pending_msgs: Rc::downgrade(&this.pending_msgs),
             pending_dispatches: Rc::downgrade(&this.pending_dispatches),
+            contexts: Rc::downgrade(&this.contexts),
         }
     }
     pub fn strong_count(&self) -> usize {
@@ -104,6 +113,7 @@ where
             current_vdom: Rc::clone(&self.current_vdom),
             pending_msgs: Rc::clone(&self.pending_msgs),
             pending_dispatches: Rc::clone(&self.pending_dispatches),
+            contexts: Rc::clone(&self.contexts),
         }
     }
 }
@@ -117,12 +127,29 @@ where
     }
 
     pub fn view(&self) -> vdom::Node<APP::MSG> {
-        self.app.borrow().view()
+        let contexts = self.contexts.borrow().clone();
+        contexts.scope(|| self.app.borrow().view())
     }
     pub fn dynamic_style(&self) -> String {
         self.app.borrow().style().join("")
     }
 
+    pub fn title(&self) -> Option<String> {
+        self.app.borrow().title()
+    }
+
+    pub fn meta(&self) -> Vec<(&'static str, String)> {
+        self.app.borrow().meta()
+    }
+
+    pub fn route(&self) -> Option<String> {
+        self.app.borrow().route()
+    }
+
+    pub fn on_route_change(&self, url: &str) -> Option<APP::MSG> {
+        self.app.borrow().on_route_change(url)
+    }
+
     pub fn static_style(&self) -> String {
         APP::stylesheet().join("")
     }
@@ -0,0 +1,98 @@
+use crate::dom::{document, Application, DomNode, Program};
+use crate::vdom::Node;
+use std::cell::RefCell;
+use std::rc::Weak;
+use std::sync::Once;
+use wasm_bindgen::JsCast;
+
+/// the handler which is called with the message of a panic
+type ErrorHandler = Box<dyn Fn(&str)>;
+
+thread_local! {
+    /// the handler which is called with the panic message,
+    /// the panic hook is global, so this is the handler of the last program which set it
+    static GLOBAL_ERROR_HANDLER: RefCell<Option<ErrorHandler>> = RefCell::new(None);
+}
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+/// chain a panic hook which calls the global error handler after the previous hook,
+/// such that the panic is still logged by a hook like `console_error_panic_hook`
+fn install_panic_hook() {
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            previous_hook(info);
+            let message = info.to_string();
+            // the handler is not called again if it panics itself
+            let _ = GLOBAL_ERROR_HANDLER.try_with(|handler| {
+                if let Ok(handler) = handler.try_borrow_mut() {
+                    if let Some(handler) = handler.as_ref() {
+                        handler(&message);
+                    }
+                }
+            });
+        }));
+    });
+}
+
+/// replace the root node of the app with the fallback html,
+/// or the content of the document body if the root node is not an attached element
+fn show_fallback(root_node: &Weak<RefCell<Option<DomNode>>>, html: &str) {
+    let root_element = root_node
+        .upgrade()
+        .and_then(|root_node| {
+            root_node
+                .try_borrow()
+                .ok()
+                .and_then(|root_node| root_node.as_ref().map(DomNode::as_node))
+        })
+        .and_then(|root_node| root_node.dyn_into::<web_sys::Element>().ok())
+        .filter(|root_element| root_element.parent_node().is_some());
+    match root_element {
+        Some(root_element) => root_element.set_outer_html(html),
+        None => {
+            if let Some(body) = document().body() {
+                body.set_inner_html(html);
+            }
+        }
+    }
+}
+
+impl<APP> Program<APP>
+where
+    APP: Application,
+{
+    /// Set a handler which is called with the message of a panic anywhere in the wasm module,
+    /// such as in the app's `update` or `view`, and returns a fallback view which replaces
+    /// the view of the app, so an error screen is shown instead of a page which stopped working.
+    ///
+    /// A panic in wasm generally aborts the execution, so the app can not recover from it.
+    /// The fallback view is rendered as static html, so it has no event listeners,
+    /// use plain links such as for reloading the page.
+    ///
+    /// The panic hook is global, so this is called for the panics of the other programs as well
+    /// and the handler set last is the one which is used. The panic hook which was set before,
+    /// such as `console_error_panic_hook`, is still called before the handler.
+    /// # Example
+    /// ```rust,ignore
+    /// let program = Program::new(App::default()).with_global_error_handler(|message| {
+    ///     div([class("crashed")], [
+    ///         p([], [text!("Something went wrong: {message}")]),
+    ///         a([href("")], [text("Reload the page")]),
+    ///     ])
+    /// });
+    /// ```
+    pub fn with_global_error_handler(self, handler: impl Fn(&str) -> Node<()> + 'static) -> Self {
+        let root_node = std::rc::Rc::downgrade(&self.root_node);
+        let show_error = move |message: &str| {
+            let fallback = handler(message);
+            show_fallback(&root_node, &fallback.render_to_string());
+        };
+        GLOBAL_ERROR_HANDLER.with(|global_handler| {
+            *global_handler.borrow_mut() = Some(Box::new(show_error));
+        });
+        install_panic_hook();
+        self
+    }
+}
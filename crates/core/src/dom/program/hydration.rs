@@ -0,0 +1,395 @@
+//! Hydrate the server rendered DOM with the view of the app, which reuses the elements
+//! of the server rendered page instead of creating them again.
+//!
+//! The server rendered DOM is expected to be the result of rendering the same view
+//! with `render_to_string`. Where it differs, such as when the server and the client
+//! render different data, the DOM is patched to match the view of the app.
+//! In debug builds, the differences are logged as a hydration mismatch report,
+//! so the divergence between the server and the client can be fixed.
+use crate::dom::{dom_node::DomInner, Application, DomNode, Program};
+use crate::vdom::{self, Leaf, TreePath, PORTAL_TARGET};
+use std::{cell::RefCell, fmt, rc::Rc};
+use wasm_bindgen::JsCast;
+
+/// A difference between the server rendered DOM and the view of the app, which is found
+/// when the app is hydrated.
+///
+/// It is displayed like [`diff_pretty`](crate::vdom::diff_pretty), where the server rendered
+/// DOM is the old tree and the view of the app is the new one:
+/// ```text
+/// hydration mismatch at [0, 1]:
+/// - <div>
+/// + <li>
+/// ```
+/// The path is the [`TreePath`] of the node from the root node of the app.
+/// The node or the attribute which is only in the server rendered DOM is prefixed with `"- "`,
+/// and the one which is only in the view of the app is prefixed with `"+ "`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct HydrationMismatch {
+    /// the path of the node where the mismatch is found
+    pub(crate) path: TreePath,
+    /// what is in the server rendered DOM, such as `<div>` or `class="active"`,
+    /// `None` when the view of the app has a node or an attribute which is not in the DOM
+    pub(crate) found: Option<String>,
+    /// what is in the view of the app, `None` when the DOM has a node or an attribute
+    /// which is not in the view of the app
+    pub(crate) expected: Option<String>,
+}
+
+impl fmt::Display for HydrationMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "hydration mismatch at {:?}:", self.path.path)?;
+        if let Some(found) = &self.found {
+            write!(f, "\n- {found}")?;
+        }
+        if let Some(expected) = &self.expected {
+            write!(f, "\n+ {expected}")?;
+        }
+        Ok(())
+    }
+}
+
+/// returns true if the `node` corresponds to a single node in the server rendered DOM,
+/// the others such as the fragments and the components are created instead of hydrated.
+fn is_hydratable<MSG>(node: &vdom::Node<MSG>) -> bool {
+    match node {
+        vdom::Node::Element(elm) => elm.first_value(PORTAL_TARGET).is_none(),
+        vdom::Node::Leaf(Leaf::Text(_) | Leaf::PreText(_) | Leaf::Comment(_)) => true,
+        vdom::Node::Leaf(Leaf::Shared(node)) => is_hydratable(node),
+        vdom::Node::Leaf(_) => false,
+    }
+}
+
+/// returns true if the `dom` node is the same kind of node as the `node`,
+/// which for an element is having the same tag and namespace
+fn is_same_kind<MSG>(node: &vdom::Node<MSG>, dom: &web_sys::Node) -> bool {
+    if !is_hydratable(node) {
+        return false;
+    }
+    match node {
+        vdom::Node::Element(elm) => {
+            let Some(element) = dom.dyn_ref::<web_sys::Element>() else {
+                return false;
+            };
+            let is_same_namespace = elm
+                .namespace()
+                .is_none_or(|namespace| element.namespace_uri().as_deref() == Some(namespace));
+            element.local_name() == *elm.tag() && is_same_namespace
+        }
+        vdom::Node::Leaf(Leaf::Text(_) | Leaf::PreText(_)) => {
+            dom.node_type() == web_sys::Node::TEXT_NODE
+        }
+        vdom::Node::Leaf(Leaf::Comment(_)) => dom.node_type() == web_sys::Node::COMMENT_NODE,
+        vdom::Node::Leaf(Leaf::Shared(node)) => is_same_kind(node, dom),
+        vdom::Node::Leaf(_) => false,
+    }
+}
+
+/// describe the `node` the way it is rendered, the elements are described by their opening tag
+fn describe<MSG>(node: &vdom::Node<MSG>) -> String {
+    match node {
+        vdom::Node::Element(elm) => format!("<{}>", elm.tag()),
+        vdom::Node::Leaf(Leaf::Shared(node)) => describe(node),
+        vdom::Node::Leaf(_) => node.render_to_string(),
+    }
+}
+
+/// describe the `dom` node the same way as [`describe`]
+fn describe_dom(dom: &web_sys::Node) -> String {
+    match dom.node_type() {
+        web_sys::Node::ELEMENT_NODE => {
+            format!("<{}>", dom.unchecked_ref::<web_sys::Element>().local_name())
+        }
+        web_sys::Node::COMMENT_NODE => format!("<!--{}-->", dom.text_content().unwrap_or_default()),
+        _ => dom.text_content().unwrap_or_default(),
+    }
+}
+
+/// the rendered attributes of the element `elm` by their name,
+/// the event listeners and the attributes which are not rendered are left out
+fn expected_attributes<MSG>(elm: &vdom::Element<MSG>) -> Vec<(&'static str, String)> {
+    vdom::Attribute::merge_attributes_of_same_name(elm.attributes().iter())
+        .iter()
+        .map(|attr| (*attr.name(), attr.render_to_string()))
+        .filter(|(_, rendered)| !rendered.is_empty())
+        .collect()
+}
+
+/// the attributes of the DOM `element` by their local name, rendered the same way as the
+/// attributes of the view
+fn found_attributes(element: &web_sys::Element) -> Vec<(String, String)> {
+    let attributes = element.attributes();
+    (0..attributes.length())
+        .filter_map(|i| attributes.item(i))
+        .map(|attr| {
+            let value = attr.value();
+            let rendered = if value.is_empty() {
+                attr.name()
+            } else {
+                let escaped = value.replace('&', "&amp;").replace('"', "&quot;");
+                format!("{}=\"{escaped}\"", attr.name())
+            };
+            (attr.local_name(), rendered)
+        })
+        .collect()
+}
+
+/// find the mismatches of the server rendered `dom` node and its descendants
+/// to the `node` in the view of the app, which is at `path`
+pub(crate) fn hydration_mismatches<MSG>(
+    node: &vdom::Node<MSG>,
+    dom: &web_sys::Node,
+    path: &TreePath,
+    mismatches: &mut Vec<HydrationMismatch>,
+) {
+    let mismatch = |found: Option<String>, expected: Option<String>| HydrationMismatch {
+        path: path.clone(),
+        found,
+        expected,
+    };
+    if !is_same_kind(node, dom) {
+        if is_hydratable(node) {
+            mismatches.push(mismatch(Some(describe_dom(dom)), Some(describe(node))));
+        }
+        return;
+    }
+    let elm = match node {
+        vdom::Node::Element(elm) => elm,
+        vdom::Node::Leaf(Leaf::Shared(node)) => {
+            return hydration_mismatches(node, dom, path, mismatches);
+        }
+        vdom::Node::Leaf(_) => {
+            let (found, expected) = (describe_dom(dom), describe(node));
+            if found != expected {
+                mismatches.push(mismatch(Some(found), Some(expected)));
+            }
+            return;
+        }
+    };
+
+    let expected_attrs = expected_attributes(elm);
+    let found_attrs = found_attributes(dom.unchecked_ref());
+    for (name, found) in found_attrs.iter() {
+        match expected_attrs
+            .iter()
+            .find(|(expected_name, _)| expected_name == name)
+        {
+            Some((_, expected)) if expected == found => (),
+            Some((_, expected)) => {
+                mismatches.push(mismatch(Some(found.clone()), Some(expected.clone())))
+            }
+            None => mismatches.push(mismatch(Some(found.clone()), None)),
+        }
+    }
+    for (name, expected) in expected_attrs.iter() {
+        if !found_attrs.iter().any(|(found_name, _)| found_name == name) {
+            mismatches.push(mismatch(None, Some(expected.clone())));
+        }
+    }
+
+    // the children which are not hydrated are created anew, so they are not compared
+    if !elm.children().iter().all(is_hydratable) {
+        return;
+    }
+    let child_nodes = dom.child_nodes();
+    let child_count = elm.children().len().max(child_nodes.length() as usize);
+    for index in 0..child_count {
+        let child_path = path.traverse(index);
+        match (elm.children().get(index), child_nodes.get(index as u32)) {
+            (Some(child), Some(dom_child)) => {
+                hydration_mismatches(child, &dom_child, &child_path, mismatches)
+            }
+            (Some(child), None) => mismatches.push(HydrationMismatch {
+                path: child_path,
+                found: None,
+                expected: Some(describe(child)),
+            }),
+            (None, Some(dom_child)) => mismatches.push(HydrationMismatch {
+                path: child_path,
+                found: Some(describe_dom(&dom_child)),
+                expected: None,
+            }),
+            (None, None) => unreachable!("the index is less than the number of children"),
+        }
+    }
+}
+
+impl<APP> Program<APP>
+where
+    APP: Application + 'static,
+{
+    /// hydrate the first node in the `mount_node` which is rendered by the server,
+    /// with the current view of the app
+    pub(crate) fn hydrate_initial_view(&self, mount_node: &DomNode) -> DomNode {
+        let current_view = self.app_context.current_vdom();
+        let real_view = current_view.unwrap_template_ref();
+        // the whitespace around the server rendered root node is not part of the view
+        let child_nodes = mount_node.as_node().child_nodes();
+        let server_root = (0..child_nodes.length())
+            .filter_map(|i| child_nodes.get(i))
+            .find(|child| {
+                child.node_type() != web_sys::Node::TEXT_NODE
+                    || !child.text_content().unwrap_or_default().trim().is_empty()
+            });
+
+        let Some(server_root) = server_root else {
+            if cfg!(debug_assertions) {
+                log::warn!(
+                    "{}",
+                    HydrationMismatch {
+                        path: TreePath::root(),
+                        found: None,
+                        expected: Some(describe(real_view)),
+                    }
+                );
+            }
+            let created_node = self.create_dom_node(real_view);
+            mount_node.append_children(vec![created_node.clone()]);
+            return created_node;
+        };
+
+        if cfg!(debug_assertions) {
+            let mut mismatches = vec![];
+            hydration_mismatches(real_view, &server_root, &TreePath::root(), &mut mismatches);
+            if !mismatches.is_empty() {
+                let report: Vec<String> = mismatches.iter().map(ToString::to_string).collect();
+                log::warn!(
+                    "The server rendered DOM doesn't match the view of the app, \
+                    it is patched to match the view:\n{}",
+                    report.join("\n")
+                );
+            }
+        }
+        self.hydrate_dom_node(real_view, server_root)
+    }
+
+    /// reuse the server rendered `dom` node for the `node` in the view of the app,
+    /// patching its attributes and children to match the `node` and attaching its event listeners.
+    /// The `dom` node is replaced with a newly created one when it is a different kind of node.
+    fn hydrate_dom_node(&self, node: &vdom::Node<APP::MSG>, dom: web_sys::Node) -> DomNode {
+        if !is_same_kind(node, &dom) {
+            let created_node = self.create_dom_node(node);
+            if let Some(parent) = dom.parent_node() {
+                parent
+                    .replace_child(&created_node.as_node(), &dom)
+                    .expect("must replace the server rendered node");
+            }
+            created_node.dispatch_mount_event();
+            return created_node;
+        }
+        let elm = match node {
+            vdom::Node::Element(elm) => elm,
+            vdom::Node::Leaf(Leaf::Shared(node)) => return self.hydrate_dom_node(node, dom),
+            vdom::Node::Leaf(Leaf::Comment(comment)) => {
+                let comment_node: web_sys::Comment = dom.unchecked_into();
+                if comment_node.data() != *comment {
+                    comment_node.set_data(comment);
+                }
+                return DomNode {
+                    inner: DomInner::Comment(comment_node),
+                };
+            }
+            vdom::Node::Leaf(Leaf::Text(txt) | Leaf::PreText(txt)) => {
+                let text_node: web_sys::Text = dom.unchecked_into();
+                if text_node.data() != *txt {
+                    text_node.set_data(txt);
+                }
+                return DomNode {
+                    inner: DomInner::Text(text_node),
+                };
+            }
+            vdom::Node::Leaf(_) => unreachable!("only the hydratable nodes are the same kind"),
+        };
+
+        let element: web_sys::Element = dom.unchecked_into();
+        // the attributes which are not in the view are removed
+        let attr_names: Vec<&str> = elm.attributes().iter().map(|attr| *attr.name()).collect();
+        let attributes = element.attributes();
+        let stale_attrs: Vec<String> = (0..attributes.length())
+            .filter_map(|i| attributes.item(i))
+            .filter(|attr| !attr_names.contains(&attr.local_name().as_str()))
+            .map(|attr| attr.name())
+            .collect();
+        for name in stale_attrs {
+            element
+                .remove_attribute(&name)
+                .expect("must remove attribute");
+        }
+
+        let children = elm.children();
+        let child_nodes = element.child_nodes();
+        let server_children: Vec<web_sys::Node> = (0..child_nodes.length())
+            .filter_map(|i| child_nodes.get(i))
+            .collect();
+        let (hydrated_children, created_children) = if children.iter().all(is_hydratable) {
+            for extra in server_children.iter().skip(children.len()) {
+                element.remove_child(extra).expect("must remove child");
+            }
+            let hydrated: Vec<DomNode> = children
+                .iter()
+                .zip(server_children)
+                .map(|(child, dom_child)| self.hydrate_dom_node(child, dom_child))
+                .collect();
+            let created: Vec<DomNode> = children
+                .iter()
+                .skip(hydrated.len())
+                .map(|child| self.create_dom_node(child))
+                .collect();
+            (hydrated, created)
+        } else {
+            element.set_text_content(None);
+            let created: Vec<DomNode> = children
+                .iter()
+                .map(|child| self.create_dom_node(child))
+                .collect();
+            (vec![], created)
+        };
+
+        let dom_node = DomNode {
+            inner: DomInner::Element {
+                element,
+                listeners: Rc::new(RefCell::new(None)),
+                children: Rc::new(RefCell::new(hydrated_children)),
+                has_mount_callback: elm.has_mount_callback(),
+                inline_styles: Rc::new(RefCell::new(vec![])),
+            },
+        };
+        let attrs = vdom::Attribute::merge_attributes_of_same_name(elm.attributes().iter());
+        let dom_attrs = attrs.iter().map(|a| self.convert_attr(a)).collect();
+        self.set_node_attrs(&dom_node, dom_attrs)
+            .expect("set dom attrs");
+        dom_node.append_children(created_children);
+        if elm.tag() == &"select" {
+            dom_node.sync_select_value();
+        }
+        dom_node.dispatch_mount_event();
+        dom_node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mismatch_report_format() {
+        let replaced = HydrationMismatch {
+            path: TreePath::new([0, 1]),
+            found: Some("<div>".to_string()),
+            expected: Some("<li>".to_string()),
+        };
+        assert_eq!(
+            replaced.to_string(),
+            "hydration mismatch at [0, 1]:\n- <div>\n+ <li>"
+        );
+        let missing = HydrationMismatch {
+            path: TreePath::root(),
+            found: None,
+            expected: Some(r#"class="active""#.to_string()),
+        };
+        assert_eq!(
+            missing.to_string(),
+            "hydration mismatch at []:\n+ class=\"active\""
+        );
+    }
+}
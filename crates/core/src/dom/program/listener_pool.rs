@@ -0,0 +1,101 @@
+//! The closures of the event listeners which are shared by the elements, so that a list
+//! of items with the same callback doesn't allocate a JS closure for each of its items.
+use crate::dom::dom_node::{EventClosure, WeakEventClosure};
+#[cfg(feature = "test-fixtures")]
+use crate::dom::{Application, Program};
+use crate::vdom::EventOptions;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+/// the closures are looked up by the event name, the pool id of the callback and its options
+type PoolKey = (&'static str, u64, EventOptions);
+
+/// the number of entries before the closures which are no longer attached are pruned
+const INITIAL_PRUNE_AT: usize = 64;
+
+/// Keep the closures of the event listeners which callbacks have a stable id,
+/// see [`Callback::with_id`](crate::vdom::Callback::with_id).
+///
+/// The pool only holds weak references, the closures are owned by the elements
+/// they are attached to and are dropped together with the last of them.
+pub(crate) struct ListenerPool {
+    closures: HashMap<PoolKey, WeakEventClosure>,
+    /// the total number of closures which are created for the event listeners
+    #[cfg(feature = "test-fixtures")]
+    created: usize,
+    /// the number of entries which triggers pruning the dropped closures
+    prune_at: usize,
+}
+
+impl Default for ListenerPool {
+    fn default() -> Self {
+        Self {
+            closures: HashMap::new(),
+            #[cfg(feature = "test-fixtures")]
+            created: 0,
+            prune_at: INITIAL_PRUNE_AT,
+        }
+    }
+}
+
+impl ListenerPool {
+    /// return the closure of the listener for the `event_name` with the `pool_id` of its callback,
+    /// creating it with `create` if there is none which is still attached to an element.
+    /// The listeners without an id always get a new closure.
+    pub(crate) fn get_or_create(
+        &mut self,
+        event_name: &'static str,
+        pool_id: Option<u64>,
+        options: EventOptions,
+        create: impl FnOnce() -> EventClosure,
+    ) -> EventClosure {
+        let Some(pool_id) = pool_id else {
+            return self.create(create);
+        };
+        let key = (event_name, pool_id, options);
+        if let Some(closure) = self.closures.get(&key).and_then(Weak::upgrade) {
+            return closure;
+        }
+        let closure = self.create(create);
+        self.closures.insert(key, Rc::downgrade(&closure));
+        self.prune();
+        closure
+    }
+
+    fn create(&mut self, create: impl FnOnce() -> EventClosure) -> EventClosure {
+        #[cfg(feature = "test-fixtures")]
+        {
+            self.created += 1;
+        }
+        create()
+    }
+
+    /// remove the entries of the closures which are dropped, the threshold is doubled
+    /// when most of the entries are still alive so this stays amortized
+    fn prune(&mut self) {
+        if self.closures.len() < self.prune_at {
+            return;
+        }
+        self.closures
+            .retain(|_, closure| closure.strong_count() > 0);
+        self.prune_at = (self.closures.len() * 2).max(INITIAL_PRUNE_AT);
+    }
+
+    /// the total number of closures which are created for the event listeners
+    #[cfg(feature = "test-fixtures")]
+    pub(crate) fn created(&self) -> usize {
+        self.created
+    }
+}
+
+#[cfg(feature = "test-fixtures")]
+impl<APP> Program<APP>
+where
+    APP: Application,
+{
+    /// the total number of closures which are created for the event listeners of the view,
+    /// the listeners which callbacks have the same id share a single closure
+    pub fn listener_closure_count(&self) -> usize {
+        self.listener_pool.borrow().created()
+    }
+}
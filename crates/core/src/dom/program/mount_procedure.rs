@@ -7,6 +7,30 @@ pub enum MountAction {
     ClearAppend,
     /// replace the target mount node with the APP's root node
     Replace,
+    /// reuse the server rendered root node in the target mount node as the APP's root node,
+    /// attaching the event listeners to the existing elements.
+    ///
+    /// The server rendered DOM is expected to be the `render_to_string` of the same view.
+    /// Where it differs, the DOM is patched to match the APP's view. In debug builds,
+    /// the differences are logged as a report with an entry for each of them, such as:
+    /// ```text
+    /// hydration mismatch at [0, 1]:
+    /// - <div>
+    /// + <li>
+    /// hydration mismatch at [0, 2, 0]:
+    /// - 3 items
+    /// + 4 items
+    /// hydration mismatch at [0]:
+    /// + class="active"
+    /// ```
+    /// Each entry has the tree path of the node from the APP's root node, followed by what is
+    /// in the server rendered DOM prefixed with `"- "`, and what is in the APP's view prefixed
+    /// with `"+ "`, either of which is left out for a node or an attribute which is missing
+    /// on one side, the same way as [`diff_pretty`](crate::vdom::diff_pretty).
+    ///
+    /// The fragments, the components and the portals are created instead of hydrated,
+    /// which replaces the server rendered children of their parent element.
+    Hydrate,
 }
 
 /// specify whether to attach the Node in shadow_root
@@ -48,6 +72,11 @@ impl MountProcedure {
         Self::new(MountAction::Replace, MountTarget::MountNode)
     }
 
+    /// hydrate the server rendered content of the mount node, see [`MountAction::Hydrate`]
+    pub fn hydrate() -> Self {
+        Self::new(MountAction::Hydrate, MountTarget::MountNode)
+    }
+
     /// append to the mount node but on it's shadow
     pub fn append_to_shadow() -> Self {
         Self::new(MountAction::Append, MountTarget::ShadowRoot)
@@ -0,0 +1,300 @@
+//! Optimistic updates of the app, which are applied right away and rolled back
+//! if they are rejected later on, such as by the server.
+use crate::dom::{Application, Dispatch, Program};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+
+/// applies a msg to the app again, the cmd is dropped since it was already emitted
+type Replay<APP> = Rc<dyn Fn(&mut APP)>;
+
+/// records the msg which is applied to the app while there are pending optimistic updates
+type Recorder<APP> = Rc<dyn Fn(&<APP as Application>::MSG) -> Replay<APP>>;
+
+/// Keep the state of the app from before the oldest pending optimistic update,
+/// and all the msgs which are applied to the app since then, so that an optimistic update
+/// can be rolled back by applying the other msgs again on that state.
+pub(crate) struct OptimisticLog<APP>
+where
+    APP: Application,
+{
+    /// the state of the app before the oldest pending optimistic update
+    base: Option<APP>,
+    /// the msgs applied to the app since the base, in the order they are applied,
+    /// with the id of the optimistic update the msg is for
+    msgs: Vec<(Option<u64>, Replay<APP>)>,
+    /// the ids of the optimistic updates which are not yet committed nor rolled back
+    pending: Vec<u64>,
+    /// records the msgs which are dispatched while there are pending optimistic updates
+    recorder: Option<Recorder<APP>>,
+}
+
+impl<APP> Default for OptimisticLog<APP>
+where
+    APP: Application,
+{
+    fn default() -> Self {
+        Self {
+            base: None,
+            msgs: vec![],
+            pending: vec![],
+            recorder: None,
+        }
+    }
+}
+
+/// the id of an optimistic update, which is hashed so any hashable value can be used
+pub(crate) fn optimistic_id(id: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    id.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<APP> OptimisticLog<APP>
+where
+    APP: Application,
+{
+    /// whether the optimistic update `id` is pending
+    pub(crate) fn is_pending(&self, id: u64) -> bool {
+        self.pending.contains(&id)
+    }
+
+    /// record the `msg` which is about to be applied to the app,
+    /// this does nothing if there is no pending optimistic update
+    pub(crate) fn record(&mut self, msg: &APP::MSG) {
+        if let Some(recorder) = &self.recorder {
+            self.msgs.push((None, recorder(msg)));
+        }
+    }
+
+    /// commit the optimistic update `id`, its msg is kept as if it was dispatched normally.
+    /// Returns false if there is no such pending optimistic update
+    pub(crate) fn commit(&mut self, id: u64) -> bool {
+        if !self.remove_pending(id) {
+            return false;
+        }
+        for (msg_id, _) in self.msgs.iter_mut() {
+            if *msg_id == Some(id) {
+                *msg_id = None;
+            }
+        }
+        self.release_if_settled();
+        true
+    }
+
+    fn remove_pending(&mut self, id: u64) -> bool {
+        let len = self.pending.len();
+        self.pending.retain(|pending| *pending != id);
+        self.pending.len() != len
+    }
+
+    /// drop the snapshot and the recorded msgs once there are no more pending optimistic updates
+    fn release_if_settled(&mut self) {
+        if self.pending.is_empty() {
+            *self = Self::default();
+        }
+    }
+}
+
+impl<APP> OptimisticLog<APP>
+where
+    APP: Application + Clone,
+    APP::MSG: Clone,
+{
+    /// start the optimistic update `id` of the `msg`, which is about to be applied to the `app`
+    pub(crate) fn begin(&mut self, id: u64, app: &APP, msg: &APP::MSG) {
+        if self.base.is_none() {
+            self.base = Some(app.clone());
+            self.recorder = Some(Rc::new(|msg: &APP::MSG| -> Replay<APP> {
+                let msg = msg.clone();
+                Rc::new(move |app: &mut APP| {
+                    let _ = app.update(msg.clone());
+                })
+            }));
+        }
+        self.pending.push(id);
+        if let Some(recorder) = &self.recorder {
+            self.msgs.push((Some(id), recorder(msg)));
+        }
+    }
+
+    /// roll back the optimistic update `id`, returns the state of the app without it,
+    /// or None if there is no such pending optimistic update
+    pub(crate) fn rollback(&mut self, id: u64) -> Option<APP> {
+        if !self.remove_pending(id) {
+            return None;
+        }
+        self.msgs.retain(|(msg_id, _)| *msg_id != Some(id));
+        let mut app = self.base.clone().expect("must have a base state");
+        for (_, replay) in self.msgs.iter() {
+            replay(&mut app);
+        }
+        self.release_if_settled();
+        Some(app)
+    }
+}
+
+impl<APP> Program<APP>
+where
+    APP: Application + Clone + 'static,
+    APP::MSG: Clone,
+{
+    /// update the app with the `msg` right away as an optimistic update tagged with `id`,
+    /// such as showing a comment before the server has saved it.
+    /// Call [`commit`](Self::commit) with the same `id` once it is confirmed,
+    /// or [`rollback`](Self::rollback) to undo it if it is rejected.
+    ///
+    /// The `id` can be any hashable value, such as the id of the request to the server.
+    ///
+    /// # Memory
+    /// A clone of the app is kept from the first optimistic update, together with a clone
+    /// of every msg which is dispatched after it, until all the optimistic updates are either
+    /// committed or rolled back. Settle the optimistic updates promptly, since the msgs
+    /// keep on accumulating as long as one of them is pending.
+    ///
+    /// # Concurrent optimistic updates
+    /// The optimistic updates compose, rolling back one of them restores the clone of the app
+    /// and updates it again with all the other msgs in the order they were dispatched,
+    /// including the other pending optimistic updates and the msgs which are dispatched normally.
+    /// So `update` should only depend on the state and the msg, the cmds of the msgs
+    /// are not emitted again.
+    /// The changes which are made to the app directly, such as with [`transaction`](Self::transaction)
+    /// or [`app_mut`](Self::app_mut), are not recorded and are undone by a rollback.
+    ///
+    /// Returns an error if there is already a pending optimistic update with the same `id`,
+    /// or if the app is borrowed.
+    pub fn optimistic(&mut self, id: impl Hash, msg: APP::MSG) -> Result<(), JsValue> {
+        let id = optimistic_id(id);
+        if self.optimistic.borrow().is_pending(id) {
+            return Err(JsValue::from_str(
+                "there is already a pending optimistic update with the same id",
+            ));
+        }
+        let cmd = {
+            let mut app = self.app_context.app.try_borrow_mut().map_err(|_| {
+                JsValue::from_str("the app is already borrowed, an optimistic update can not start")
+            })?;
+            self.optimistic.borrow_mut().begin(id, &app, &msg);
+            app.update(msg)
+        };
+        self.app_context
+            .pending_dispatches
+            .borrow_mut()
+            .push_back(Dispatch::from(cmd));
+        self.dispatch_inner(None);
+        Ok(())
+    }
+
+    /// confirm the optimistic update `id`, which is kept as if its msg was dispatched normally.
+    /// Returns false if there is no pending optimistic update with the `id`
+    pub fn commit(&self, id: impl Hash) -> bool {
+        self.optimistic.borrow_mut().commit(optimistic_id(id))
+    }
+
+    /// undo the optimistic update `id`, the app is restored as if its msg was never dispatched
+    /// while the msgs which are dispatched after it are kept, then the DOM is updated.
+    /// See [`optimistic`](Self::optimistic) for how it composes with the other optimistic updates.
+    ///
+    /// Returns an error if there is no pending optimistic update with the `id`,
+    /// or if the app is borrowed.
+    pub fn rollback(&mut self, id: impl Hash) -> Result<(), JsValue> {
+        {
+            let mut app = self.app_context.app.try_borrow_mut().map_err(|_| {
+                JsValue::from_str("the app is already borrowed, it can not be rolled back")
+            })?;
+            let Some(restored) = self.optimistic.borrow_mut().rollback(optimistic_id(id)) else {
+                return Err(JsValue::from_str(
+                    "there is no pending optimistic update with the id",
+                ));
+            };
+            *app = restored;
+        }
+        self.update_dom()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::Cmd;
+    use crate::html::text;
+    use crate::vdom::Node;
+
+    #[derive(Clone)]
+    struct Likes(Vec<&'static str>);
+
+    impl Application for Likes {
+        type MSG = &'static str;
+
+        fn update(&mut self, msg: &'static str) -> Cmd<&'static str> {
+            self.0.push(msg);
+            Cmd::none()
+        }
+
+        fn view(&self) -> Node<&'static str> {
+            text(self.0.join(","))
+        }
+    }
+
+    fn apply(log: &mut OptimisticLog<Likes>, app: &mut Likes, msg: &'static str) {
+        log.record(&msg);
+        let _ = app.update(msg);
+    }
+
+    fn apply_optimistic(
+        log: &mut OptimisticLog<Likes>,
+        app: &mut Likes,
+        id: u64,
+        msg: &'static str,
+    ) {
+        log.begin(id, app, &msg);
+        let _ = app.update(msg);
+    }
+
+    #[test]
+    fn rollback_keeps_the_other_msgs() {
+        let mut log = OptimisticLog::default();
+        let mut app = Likes(vec!["a"]);
+        apply_optimistic(&mut log, &mut app, 1, "b");
+        apply(&mut log, &mut app, "c");
+        apply_optimistic(&mut log, &mut app, 2, "d");
+        apply(&mut log, &mut app, "e");
+        assert_eq!(app.0, ["a", "b", "c", "d", "e"]);
+
+        let app = log.rollback(1).expect("must roll back");
+        assert_eq!(app.0, ["a", "c", "d", "e"]);
+        assert!(log.is_pending(2));
+        assert!(log.rollback(1).is_none());
+
+        let app = log.rollback(2).expect("must roll back");
+        assert_eq!(app.0, ["a", "c", "e"]);
+        assert!(log.base.is_none());
+        assert!(log.msgs.is_empty());
+    }
+
+    #[test]
+    fn committed_msgs_are_kept_on_rollback() {
+        let mut log = OptimisticLog::default();
+        let mut app = Likes(vec![]);
+        apply_optimistic(&mut log, &mut app, 1, "a");
+        apply_optimistic(&mut log, &mut app, 2, "b");
+        assert!(log.commit(1));
+        assert!(!log.commit(1));
+        let app = log.rollback(2).expect("must roll back");
+        assert_eq!(app.0, ["a"]);
+        assert!(log.recorder.is_none());
+    }
+
+    #[test]
+    fn msgs_are_not_recorded_without_a_pending_update() {
+        let mut log = OptimisticLog::default();
+        let mut app = Likes(vec![]);
+        apply(&mut log, &mut app, "a");
+        assert!(log.msgs.is_empty());
+        apply_optimistic(&mut log, &mut app, 1, "b");
+        assert!(log.commit(1));
+        apply(&mut log, &mut app, "c");
+        assert!(log.msgs.is_empty());
+    }
+}
@@ -0,0 +1,70 @@
+//! Keep the `checked` property of the radio buttons in sync with the view.
+//!
+//! Radio buttons which share the same `name` form a group where only one of them is checked.
+//! Checking one radio button by the user unchecks the others in the group, but this only
+//! changes the `checked` property, not the `checked` attribute which is what the view
+//! describes. Since the view of the radio buttons may not have changed, there is nothing to
+//! patch and the diffing alone leaves the group showing the choice of the user instead of
+//! the state of the app.
+use std::collections::HashSet;
+use wasm_bindgen::JsCast;
+use web_sys::{DocumentFragment, Element, HtmlInputElement, NodeList};
+
+/// set the `checked` property of the radio buttons in the `container` from their `checked`
+/// attribute, in the groups which the view controls.
+///
+/// A group is controlled when one of its radio buttons is checked in the view, the other
+/// groups are left as the user has checked them, such as a group which the app only reads
+/// when the form is submitted.
+/// In a controlled group, the radio buttons which are not checked in the view are cleared
+/// and the one which is checked in the view is set, so the group ends up with the radio button
+/// of the view, even when the browser has unchecked it in favor of the one clicked by the user.
+/// A `checked="false"` attribute is treated as not checked.
+pub(crate) fn sync_radio_groups(container: &web_sys::Node) {
+    let Some(radios) = radio_buttons(container) else {
+        return;
+    };
+    let radios: Vec<HtmlInputElement> = (0..radios.length())
+        .filter_map(|i| radios.item(i))
+        .filter_map(|node| node.dyn_into::<HtmlInputElement>().ok())
+        .collect();
+    let controlled_groups: HashSet<String> = radios
+        .iter()
+        .filter(|radio| is_checked_in_view(radio))
+        .map(|radio| radio.name())
+        .collect();
+    // a radio button without a name is a group of its own
+    let is_controlled = |radio: &HtmlInputElement| {
+        let name = radio.name();
+        if name.is_empty() {
+            is_checked_in_view(radio)
+        } else {
+            controlled_groups.contains(&name)
+        }
+    };
+    for radio in radios.iter().filter(|radio| is_controlled(radio)) {
+        let checked = is_checked_in_view(radio);
+        if radio.checked() != checked {
+            radio.set_checked(checked);
+        }
+    }
+}
+
+/// whether the radio button has a `checked` attribute which is not `"false"`
+fn is_checked_in_view(radio: &HtmlInputElement) -> bool {
+    radio
+        .get_attribute("checked")
+        .is_some_and(|value| value != "false")
+}
+
+/// the radio buttons in the container which can be an element or a shadow root
+fn radio_buttons(container: &web_sys::Node) -> Option<NodeList> {
+    const SELECTOR: &str = "input[type=radio]";
+    if let Some(element) = container.dyn_ref::<Element>() {
+        element.query_selector_all(SELECTOR).ok()
+    } else if let Some(fragment) = container.dyn_ref::<DocumentFragment>() {
+        fragment.query_selector_all(SELECTOR).ok()
+    } else {
+        None
+    }
+}
@@ -0,0 +1,144 @@
+use std::{cell::RefCell, rc::Rc};
+
+/// Keep track of whether the program is rendering, so a render which is requested while
+/// rendering, such as from a callback which is called while the patches are applied,
+/// is queued after the current render instead of diffing against the view
+/// which is not yet set as the current vdom.
+///
+/// The rendering can also be suspended, such as in a [`Program::transaction`](crate::dom::Program::transaction),
+/// in which case the renders are skipped until it is resumed.
+///
+/// Once the program is unmounted the rendering is stopped for good.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RenderLock {
+    /// whether a render is in progress
+    is_rendering: bool,
+    /// whether the rendering is suspended
+    is_suspended: bool,
+    /// whether another render is requested while rendering
+    is_rerender_requested: bool,
+    /// whether the rendering is stopped since the program is unmounted
+    is_stopped: bool,
+}
+
+impl RenderLock {
+    /// acquire the lock for rendering, returns false if a render is already in progress,
+    /// in which case another render is requested after it.
+    pub(crate) fn acquire(&mut self) -> bool {
+        if self.is_stopped {
+            false
+        } else if self.is_rendering || self.is_suspended {
+            self.is_rerender_requested = true;
+            false
+        } else {
+            self.is_rendering = true;
+            true
+        }
+    }
+
+    /// release the lock after rendering, returns true if another render has been requested
+    /// while rendering.
+    pub(crate) fn release(&mut self) -> bool {
+        self.is_rendering = false;
+        std::mem::take(&mut self.is_rerender_requested)
+    }
+
+    /// skip the renders until the rendering is resumed
+    pub(crate) fn suspend(&mut self) {
+        self.is_suspended = true;
+    }
+
+    /// resume the rendering, the renders which are requested while suspended are dropped
+    /// since the caller renders right after.
+    pub(crate) fn resume(&mut self) {
+        self.is_suspended = false;
+        if !self.is_rendering {
+            self.is_rerender_requested = false;
+        }
+    }
+
+    /// whether the rendering is suspended
+    pub(crate) fn is_suspended(&self) -> bool {
+        self.is_suspended
+    }
+
+    /// stop the rendering for good, such as when the program is unmounted
+    pub(crate) fn stop(&mut self) {
+        self.is_stopped = true;
+        self.is_rerender_requested = false;
+    }
+
+    /// whether the rendering is stopped
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.is_stopped
+    }
+}
+
+/// Suspend the rendering until this guard is dropped,
+/// so the rendering is resumed even when the caller panics.
+pub(crate) struct SuspendGuard(Rc<RefCell<RenderLock>>);
+
+impl SuspendGuard {
+    pub(crate) fn new(lock: &Rc<RefCell<RenderLock>>) -> Self {
+        lock.borrow_mut().suspend();
+        Self(Rc::clone(lock))
+    }
+}
+
+impl Drop for SuspendGuard {
+    fn drop(&mut self) {
+        self.0.borrow_mut().resume();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_requested_while_rendering_is_queued() {
+        let mut lock = RenderLock::default();
+        assert!(lock.acquire());
+        assert!(!lock.acquire());
+        assert!(!lock.acquire());
+        assert!(lock.release());
+        assert!(lock.acquire());
+        assert!(!lock.release());
+    }
+
+    #[test]
+    fn render_is_skipped_while_suspended() {
+        let mut lock = RenderLock::default();
+        lock.suspend();
+        assert!(lock.is_suspended());
+        assert!(!lock.acquire());
+        lock.resume();
+        assert!(!lock.is_suspended());
+        assert!(lock.acquire());
+        assert!(!lock.release());
+    }
+
+    #[test]
+    fn render_is_skipped_once_stopped() {
+        let mut lock = RenderLock::default();
+        lock.stop();
+        assert!(lock.is_stopped());
+        assert!(!lock.acquire());
+        assert!(!lock.release());
+        lock.resume();
+        assert!(!lock.acquire());
+    }
+
+    #[test]
+    fn rendering_is_resumed_when_the_suspending_code_panics() {
+        let lock = Rc::new(RefCell::new(RenderLock::default()));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _guard = SuspendGuard::new(&lock);
+            assert!(lock.borrow().is_suspended());
+            panic!("panic while suspended");
+        }));
+        assert!(result.is_err());
+        assert!(!lock.borrow().is_suspended());
+        assert!(lock.borrow_mut().acquire());
+    }
+}
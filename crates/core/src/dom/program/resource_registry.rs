@@ -0,0 +1,167 @@
+use crate::dom::events::{INTERSECT_EVENT, INTERSECT_THRESHOLD, RESIZE_EVENT};
+use crate::dom::intersection_observer::ElementIntersectionObserver;
+use crate::dom::resize_observer::ElementResizeObserver;
+use crate::dom::{Application, Program};
+use std::any::Any;
+use wasm_bindgen::JsValue;
+
+/// The resources such as timer handles and observers which are tied to an element,
+/// and are dropped once the element is removed from the view or the program is unmounted.
+///
+/// The resources are keyed by the element itself instead of its path in the view,
+/// so an element which is moved by the diff, such as a reordered keyed element,
+/// keeps its resources. Only the elements which are no longer in the document after
+/// all the patches of a render are applied have their resources released.
+///
+/// A resource can also be put in a named slot of the element, such as the observer of
+/// `on_resize`, which there is at most one of for each element.
+#[derive(Default)]
+pub(crate) struct ResourceRegistry {
+    resources: Vec<(web_sys::Element, Option<&'static str>, Box<dyn Any>)>,
+}
+
+impl ResourceRegistry {
+    /// tie the `resource` to the `element`
+    pub(crate) fn register(&mut self, element: web_sys::Element, resource: Box<dyn Any>) {
+        self.resources.push((element, None, resource));
+    }
+
+    /// put the `resource` in the `slot` of the `element`, returns the resource which it replaces
+    pub(crate) fn set_slot(
+        &mut self,
+        element: &web_sys::Element,
+        slot: &'static str,
+        resource: Box<dyn Any>,
+    ) -> Option<Box<dyn Any>> {
+        let replaced = self.take_slot(element, slot);
+        self.resources.push((element.clone(), Some(slot), resource));
+        replaced
+    }
+
+    /// take out the resource in the `slot` of the `element`
+    pub(crate) fn take_slot(
+        &mut self,
+        element: &web_sys::Element,
+        slot: &'static str,
+    ) -> Option<Box<dyn Any>> {
+        let index = self
+            .resources
+            .iter()
+            .position(|(e, s, _)| e == element && *s == Some(slot))?;
+        Some(self.resources.swap_remove(index).2)
+    }
+
+    /// whether there is a resource in the `slot` of the `element`
+    pub(crate) fn has_slot(&self, element: &web_sys::Element, slot: &'static str) -> bool {
+        self.resources
+            .iter()
+            .any(|(e, s, _)| e == element && *s == Some(slot))
+    }
+
+    /// take out the resources of the elements which are no longer in the document,
+    /// nor inside the `mount_node` when the program is mounted to a detached node.
+    ///
+    /// The resources are returned instead of dropped here, so they are dropped after the
+    /// registry is no longer borrowed, in case dropping a resource calls into the program.
+    pub(crate) fn take_detached(
+        &mut self,
+        mount_node: Option<&web_sys::Node>,
+    ) -> Vec<Box<dyn Any>> {
+        let is_attached = |element: &web_sys::Element| {
+            element.is_connected() || mount_node.is_some_and(|node| node.contains(Some(element)))
+        };
+        let (attached, detached): (Vec<_>, Vec<_>) = self
+            .resources
+            .drain(..)
+            .partition(|(element, _, _)| is_attached(element));
+        self.resources = attached;
+        detached
+            .into_iter()
+            .map(|(_, _, resource)| resource)
+            .collect()
+    }
+
+    /// take out all of the resources, such as when the program is unmounted
+    pub(crate) fn take_all(&mut self) -> Vec<Box<dyn Any>> {
+        self.resources
+            .drain(..)
+            .map(|(_, _, resource)| resource)
+            .collect()
+    }
+
+    /// the number of resources which are registered with [`register`](Self::register),
+    /// the resources in the slots such as the observers are not counted
+    pub(crate) fn len(&self) -> usize {
+        self.resources
+            .iter()
+            .filter(|(_, slot, _)| slot.is_none())
+            .count()
+    }
+}
+
+impl<APP> Program<APP>
+where
+    APP: Application,
+{
+    /// start the observers of the `element` which attributes `attrs` are just set,
+    /// the attributes are given with whether they have an event listener.
+    ///
+    /// The observers are kept in the resource registry, so they are disconnected
+    /// once the element is removed from the view.
+    pub(crate) fn observe_set_attrs(
+        &self,
+        element: &web_sys::Element,
+        attrs: impl IntoIterator<Item = (&'static str, bool)>,
+    ) -> Result<(), JsValue> {
+        for (name, has_listeners) in attrs {
+            let has_observer = |slot| self.resources.borrow().has_slot(element, slot);
+            if name == RESIZE_EVENT && has_listeners && !has_observer(RESIZE_EVENT) {
+                let observer = ElementResizeObserver::new(element)?;
+                self.set_resource_slot(element, RESIZE_EVENT, Box::new(observer));
+            }
+            // the observer reads the thresholds from the element,
+            // so it is created again when the thresholds are changed
+            if (name == INTERSECT_EVENT && has_listeners && !has_observer(INTERSECT_EVENT))
+                || (name == INTERSECT_THRESHOLD && has_observer(INTERSECT_EVENT))
+            {
+                let observer = ElementIntersectionObserver::new(element)?;
+                self.set_resource_slot(element, INTERSECT_EVENT, Box::new(observer));
+            }
+        }
+        Ok(())
+    }
+
+    /// stop the observers of the `element` which attributes `attr_names` are just removed
+    pub(crate) fn observe_removed_attrs(
+        &self,
+        element: &web_sys::Element,
+        attr_names: impl IntoIterator<Item = &'static str>,
+    ) -> Result<(), JsValue> {
+        for name in attr_names {
+            if name == RESIZE_EVENT || name == INTERSECT_EVENT {
+                let removed = self.resources.borrow_mut().take_slot(element, name);
+                drop(removed);
+            }
+            if name == INTERSECT_THRESHOLD
+                && self.resources.borrow().has_slot(element, INTERSECT_EVENT)
+            {
+                let observer = ElementIntersectionObserver::new(element)?;
+                self.set_resource_slot(element, INTERSECT_EVENT, Box::new(observer));
+            }
+        }
+        Ok(())
+    }
+
+    fn set_resource_slot(
+        &self,
+        element: &web_sys::Element,
+        slot: &'static str,
+        resource: Box<dyn Any>,
+    ) {
+        let replaced = self
+            .resources
+            .borrow_mut()
+            .set_slot(element, slot, resource);
+        drop(replaced);
+    }
+}
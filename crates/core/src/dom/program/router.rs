@@ -0,0 +1,145 @@
+//! The integrated routing of the apps which declare their [`Application::route`].
+use crate::dom::{current_url, document, dom_node::intern, window, Application, Program};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{EventTarget, HtmlAnchorElement, MouseEvent};
+
+/// The listeners for the clicks on the internal links in the mount node
+/// and for the back and forward buttons of the browser,
+/// the listeners are removed when this is dropped.
+pub(crate) struct Router {
+    mount_node: EventTarget,
+    click_closure: Closure<dyn FnMut(web_sys::Event)>,
+    popstate_closure: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+impl Drop for Router {
+    fn drop(&mut self) {
+        self.mount_node
+            .remove_event_listener_with_callback(
+                intern("click"),
+                self.click_closure.as_ref().unchecked_ref(),
+            )
+            .expect("must remove click listener");
+        window()
+            .remove_event_listener_with_callback(
+                intern("popstate"),
+                self.popstate_closure.as_ref().unchecked_ref(),
+            )
+            .expect("must remove popstate listener");
+    }
+}
+
+impl Router {
+    /// listen to the navigation of the user and dispatch the msg of
+    /// [`Application::on_route_change`] to the `program`
+    pub(crate) fn start<APP>(program: &Program<APP>, mount_node: &web_sys::Node) -> Self
+    where
+        APP: Application,
+    {
+        let weak_program = Program::downgrade(program);
+        let click_closure: Closure<dyn FnMut(web_sys::Event)> =
+            Closure::new(move |event: web_sys::Event| {
+                let Some(url) = internal_link_url(&event) else {
+                    return;
+                };
+                let Some(mut program) = weak_program.upgrade() else {
+                    return;
+                };
+                // the browser loads the url as usual when the app doesn't handle it
+                if let Some(msg) = program.app_context.on_route_change(&url) {
+                    event.prevent_default();
+                    program.dispatch(msg);
+                }
+            });
+        let weak_program = Program::downgrade(program);
+        let popstate_closure: Closure<dyn FnMut(web_sys::Event)> =
+            Closure::new(move |_event: web_sys::Event| {
+                let Some(mut program) = weak_program.upgrade() else {
+                    return;
+                };
+                if let Some(msg) = program.app_context.on_route_change(&current_url()) {
+                    program.dispatch(msg);
+                }
+            });
+        let mount_node: EventTarget = mount_node.clone().into();
+        mount_node
+            .add_event_listener_with_callback(
+                intern("click"),
+                click_closure.as_ref().unchecked_ref(),
+            )
+            .expect("must add click listener");
+        window()
+            .add_event_listener_with_callback(
+                intern("popstate"),
+                popstate_closure.as_ref().unchecked_ref(),
+            )
+            .expect("must add popstate listener");
+        Self {
+            mount_node,
+            click_closure,
+            popstate_closure,
+        }
+    }
+}
+
+/// push the `route` into the browser history when it is not the current url
+pub(crate) fn push_route(route: &str) {
+    if route == current_url() {
+        return;
+    }
+    if let Err(e) = window()
+        .history()
+        .and_then(|history| history.push_state_with_url(&JsValue::NULL, "", Some(route)))
+    {
+        log::error!("unable to push the route: {route}, {e:?}");
+    }
+}
+
+/// the url of the link which is clicked, if it is an internal link which is navigated
+/// in the same page.
+///
+/// The clicks which are left to the browser are:
+///  - the clicks with a modifier key or other than the main button, which opens a new tab
+///  - the links with a `target` other than `_self`, a `download` or a `rel="external"`
+///  - the links to another origin
+///  - the links to a hash in the current page
+fn internal_link_url(event: &web_sys::Event) -> Option<String> {
+    if event.default_prevented() {
+        return None;
+    }
+    let mouse_event: &MouseEvent = event.dyn_ref()?;
+    if mouse_event.button() != 0
+        || mouse_event.ctrl_key()
+        || mouse_event.meta_key()
+        || mouse_event.shift_key()
+        || mouse_event.alt_key()
+    {
+        return None;
+    }
+    let target: web_sys::Element = event.target()?.dyn_into().ok()?;
+    let anchor: HtmlAnchorElement = target.closest("a[href]").ok()??.dyn_into().ok()?;
+    let is_other_target = anchor
+        .get_attribute("target")
+        .is_some_and(|target| !target.is_empty() && target != "_self");
+    let is_external = anchor
+        .get_attribute("rel")
+        .is_some_and(|rel| rel.split_whitespace().any(|rel| rel == "external"));
+    if is_other_target || is_external || anchor.has_attribute("download") {
+        return None;
+    }
+    let location = document().location()?;
+    if anchor.origin() != location.origin().ok()? {
+        return None;
+    }
+    let is_same_page = anchor.pathname() == location.pathname().ok()?
+        && anchor.search() == location.search().ok()?;
+    if is_same_page && !anchor.hash().is_empty() {
+        return None;
+    }
+    Some(format!(
+        "{}{}{}",
+        anchor.pathname(),
+        anchor.search(),
+        anchor.hash()
+    ))
+}
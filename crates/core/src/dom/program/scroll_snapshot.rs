@@ -0,0 +1,109 @@
+//! Preserve the scroll position of the containers which are marked with
+//! [`preserve_scroll`](crate::html::attributes::preserve_scroll) across the patches.
+use crate::html::attributes::PRESERVE_SCROLL;
+use wasm_bindgen::JsCast;
+use web_sys::{DocumentFragment, Element, NodeList};
+
+/// The scroll positions of the marked containers before the DOM is patched,
+/// used to restore them when the patches replaced the containers or their content.
+///
+/// The container after patching is the first one found of:
+///  - the container itself, if it is still in the document.
+///  - the marked container with the same `id`.
+///  - the marked container at the same position among the marked containers,
+///    in document order.
+///
+/// A container which was scrolled to the bottom is kept at the bottom,
+/// so the new entries of a chat or log pane stays in view.
+pub(crate) struct ScrollSnapshot {
+    positions: Vec<ScrollPosition>,
+}
+
+struct ScrollPosition {
+    element: Element,
+    id: String,
+    scroll_top: i32,
+    scroll_left: i32,
+    is_at_bottom: bool,
+}
+
+impl ScrollSnapshot {
+    /// record the scroll position of the marked containers in the `container`
+    pub(crate) fn take(container: &web_sys::Node) -> Option<Self> {
+        let elements = marked_elements(container);
+        if elements.is_empty() {
+            return None;
+        }
+        let positions = elements
+            .into_iter()
+            .map(|element| ScrollPosition {
+                id: element.id(),
+                scroll_top: element.scroll_top(),
+                scroll_left: element.scroll_left(),
+                is_at_bottom: is_at_bottom(&element),
+                element,
+            })
+            .collect();
+        Some(Self { positions })
+    }
+
+    /// restore the scroll position of the equivalent containers in the `container`
+    pub(crate) fn restore(self, container: &web_sys::Node) {
+        let elements = marked_elements(container);
+        for (index, position) in self.positions.into_iter().enumerate() {
+            let Some(element) = position.equivalent_element(index, &elements) else {
+                continue;
+            };
+            if position.is_at_bottom {
+                element.set_scroll_top(element.scroll_height());
+            } else if element.scroll_top() != position.scroll_top {
+                element.set_scroll_top(position.scroll_top);
+            }
+            if element.scroll_left() != position.scroll_left {
+                element.set_scroll_left(position.scroll_left);
+            }
+        }
+    }
+}
+
+impl ScrollPosition {
+    fn equivalent_element<'a>(
+        &'a self,
+        index: usize,
+        elements: &'a [Element],
+    ) -> Option<&'a Element> {
+        if self.element.is_connected() {
+            return Some(&self.element);
+        }
+        if !self.id.is_empty() {
+            return elements.iter().find(|element| element.id() == self.id);
+        }
+        elements.get(index)
+    }
+}
+
+/// whether the element is scrolled to the bottom, allowing a pixel for the rounding
+/// of the fractional scroll positions
+fn is_at_bottom(element: &Element) -> bool {
+    let max_scroll_top = element.scroll_height() - element.client_height();
+    max_scroll_top > 0 && element.scroll_top() >= max_scroll_top - 1
+}
+
+/// the marked containers in the container, which can be an element or a shadow root
+fn marked_elements(container: &web_sys::Node) -> Vec<Element> {
+    let selector = format!("[{PRESERVE_SCROLL}]");
+    let node_list: Option<NodeList> = if let Some(element) = container.dyn_ref::<Element>() {
+        element.query_selector_all(&selector).ok()
+    } else if let Some(fragment) = container.dyn_ref::<DocumentFragment>() {
+        fragment.query_selector_all(&selector).ok()
+    } else {
+        None
+    };
+    let Some(node_list) = node_list else {
+        return vec![];
+    };
+    (0..node_list.length())
+        .filter_map(|i| node_list.item(i))
+        .filter_map(|node| node.dyn_into::<Element>().ok())
+        .collect()
+}
@@ -0,0 +1,96 @@
+use crate::dom::{document, Application, Program};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+    EventInit, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement, InputEventInit,
+    KeyboardEventInit, MouseEventInit,
+};
+
+/// An event which is simulated on an element, see [`Program::simulate_event`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulatedEvent {
+    /// a `click` of the main mouse button
+    Click,
+    /// an `input` event, after setting the value of the input, textarea or select to this text
+    Input(String),
+    /// a `keydown` event of this key, such as `"Enter"` or `"a"`
+    KeyDown(String),
+    /// a plain event with this name, such as `"submit"` or `"focus"`
+    Other(String),
+}
+
+impl<APP> Program<APP>
+where
+    APP: Application,
+{
+    /// Simulate the `event` on the first element matching the css `selector` inside the mount
+    /// node of this program, for testing the behavior of the app.
+    ///
+    /// The event bubbles up just like a real event, so the event listeners of the element and of
+    /// its ancestors are called, and their msgs are dispatched to the app.
+    /// The update of the app and the DOM happens asynchronously after the dispatch,
+    /// so wait for it, such as with `delay(0).await`, before checking the DOM.
+    ///
+    /// Returns an error if the selector is invalid or no element matches it.
+    pub fn simulate_event(&self, selector: &str, event: SimulatedEvent) -> Result<(), JsValue> {
+        let mount_node = self
+            .mount_node
+            .borrow()
+            .as_ref()
+            .map(|mount_node| mount_node.as_node());
+        let element = match mount_node {
+            Some(mount_node) => {
+                if let Some(mount_element) = mount_node.dyn_ref::<web_sys::Element>() {
+                    mount_element.query_selector(selector)?
+                } else if let Some(fragment) = mount_node.dyn_ref::<web_sys::DocumentFragment>() {
+                    fragment.query_selector(selector)?
+                } else {
+                    document().query_selector(selector)?
+                }
+            }
+            None => document().query_selector(selector)?,
+        };
+        let Some(element) = element else {
+            return Err(JsValue::from_str(&format!(
+                "no element matches the selector: {selector}"
+            )));
+        };
+        let web_event: web_sys::Event = match event {
+            SimulatedEvent::Click => {
+                let event_init = MouseEventInit::new();
+                event_init.set_bubbles(true);
+                event_init.set_cancelable(true);
+                web_sys::MouseEvent::new_with_mouse_event_init_dict("click", &event_init)?.into()
+            }
+            SimulatedEvent::Input(value) => {
+                if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+                    input.set_value(&value);
+                } else if let Some(textarea) = element.dyn_ref::<HtmlTextAreaElement>() {
+                    textarea.set_value(&value);
+                } else if let Some(select) = element.dyn_ref::<HtmlSelectElement>() {
+                    select.set_value(&value);
+                }
+                let event_init = InputEventInit::new();
+                event_init.set_bubbles(true);
+                event_init.set_data(Some(&value));
+                event_init.set_input_type("insertText");
+                web_sys::InputEvent::new_with_event_init_dict("input", &event_init)?.into()
+            }
+            SimulatedEvent::KeyDown(key) => {
+                let event_init = KeyboardEventInit::new();
+                event_init.set_bubbles(true);
+                event_init.set_cancelable(true);
+                event_init.set_key(&key);
+                web_sys::KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &event_init)?
+                    .into()
+            }
+            SimulatedEvent::Other(event_name) => {
+                let event_init = EventInit::new();
+                event_init.set_bubbles(true);
+                event_init.set_cancelable(true);
+                web_sys::Event::new_with_event_init_dict(&event_name, &event_init)?
+            }
+        };
+        element.dispatch_event(&web_event)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+/// the duration of 1 frame in ms, at 60 frames per second
+pub(crate) const FRAME_TIME: f64 = 1000.0 / 60.0;
+
+/// the default maximum number of updates the program will do in a single frame
+pub const DEFAULT_MAX_UPDATES_PER_FRAME: usize = 100;
+
+/// Keep track of the number of updates done in the current frame
+/// to detect runaway update loops, such as an app's update which keeps on dispatching msgs.
+#[derive(Debug, Clone)]
+pub(crate) struct UpdateGuard {
+    /// the maximum number of updates in a single frame,
+    /// None means no limit
+    pub(crate) max_updates_per_frame: Option<usize>,
+    /// the time the current frame started
+    frame_start: f64,
+    /// the number of updates done in the current frame
+    updates_in_frame: usize,
+    /// whether a warning has been logged for the current frame
+    warned: bool,
+}
+
+impl Default for UpdateGuard {
+    fn default() -> Self {
+        Self {
+            max_updates_per_frame: Some(DEFAULT_MAX_UPDATES_PER_FRAME),
+            frame_start: 0.0,
+            updates_in_frame: 0,
+            warned: false,
+        }
+    }
+}
+
+impl UpdateGuard {
+    /// record an update at time `now`,
+    /// returns the remaining time of the current frame in ms
+    /// if the maximum number of updates in this frame has been exceeded.
+    pub(crate) fn record_update(&mut self, now: f64) -> Option<f64> {
+        let max_updates = self.max_updates_per_frame?;
+        let elapsed = now - self.frame_start;
+        if elapsed >= FRAME_TIME {
+            self.frame_start = now;
+            self.updates_in_frame = 0;
+            self.warned = false;
+        }
+        self.updates_in_frame += 1;
+        if self.updates_in_frame > max_updates {
+            if !self.warned {
+                log::warn!(
+                    "More than {max_updates} updates in a single frame, \
+                    the app's update could be dispatching msgs in a loop. \
+                    Deferring the next updates to the next frame."
+                );
+                self.warned = true;
+            }
+            Some((FRAME_TIME - (now - self.frame_start)).max(0.0))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defer_updates_exceeding_the_limit_in_a_frame() {
+        let mut guard = UpdateGuard {
+            max_updates_per_frame: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(guard.record_update(100.0), None);
+        assert_eq!(guard.record_update(101.0), None);
+        let remaining = guard.record_update(102.0).expect("must be deferred");
+        assert!((remaining - (FRAME_TIME - 2.0)).abs() < f64::EPSILON);
+        // a new frame resets the count
+        assert_eq!(guard.record_update(100.0 + FRAME_TIME), None);
+    }
+
+    #[test]
+    fn no_limit() {
+        let mut guard = UpdateGuard {
+            max_updates_per_frame: None,
+            ..Default::default()
+        };
+        assert!((0..1000).all(|i| guard.record_update(i as f64 * 0.001).is_none()));
+    }
+}
@@ -0,0 +1,43 @@
+use crate::dom::events::RESIZE_EVENT;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CustomEvent, CustomEventInit, ResizeObserver, ResizeObserverEntry};
+
+/// observes the size changes of an element and dispatch it as a resize event to the element,
+/// the observer is disconnected when this is dropped
+pub(crate) struct ElementResizeObserver {
+    observer: ResizeObserver,
+    _closure: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+impl Drop for ElementResizeObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+impl ElementResizeObserver {
+    /// start observing the size changes of the `element`
+    pub(crate) fn new(element: &web_sys::Element) -> Result<Self, JsValue> {
+        let closure: Closure<dyn FnMut(js_sys::Array)> =
+            Closure::new(move |entries: js_sys::Array| {
+                for entry in entries.iter() {
+                    let entry: ResizeObserverEntry = entry.unchecked_into();
+                    let event_init = CustomEventInit::new();
+                    event_init.set_detail(&entry);
+                    let event = CustomEvent::new_with_event_init_dict(RESIZE_EVENT, &event_init)
+                        .expect("must create resize event");
+                    entry
+                        .target()
+                        .dispatch_event(&event)
+                        .expect("must dispatch resize event");
+                }
+            });
+        let observer = ResizeObserver::new(closure.as_ref().unchecked_ref())?;
+        observer.observe(element);
+        Ok(Self {
+            observer,
+            _closure: closure,
+        })
+    }
+}
@@ -73,6 +73,17 @@ pub fn get_location_hash() -> String {
     window().location().hash().expect("must have a hash")
 }
 
+/// return the url of the current location relative to the origin,
+/// which is the path followed by the query string and the hash fragment, ie: `/users/1?tab=posts#top`.
+/// Use this to read the initial url when the app starts.
+pub fn current_url() -> String {
+    let location = window().location();
+    let path = location.pathname().expect("must have a pathname");
+    let search = location.search().expect("must have a search");
+    let hash = location.hash().expect("must have a hash");
+    format!("{path}{search}{hash}")
+}
+
 /// return the size of the browser at this moment
 pub fn get_window_size() -> (i32, i32) {
     let window = dom::window();
@@ -175,6 +175,17 @@ impl Window {
         }))
     }
 
+    /// Create a recurring Cmd which is triggered when the user navigates the history,
+    /// such as with the back and forward buttons, with the new url as in [`util::current_url`].
+    /// This is not triggered by [`Cmd::push_url`] and [`Cmd::replace_url`].
+    pub fn on_url_change<F, MSG>(mut cb: F) -> Cmd<MSG>
+    where
+        F: FnMut(String) -> MSG + 'static,
+        MSG: 'static,
+    {
+        Self::on_popstate(move |_event| cb(util::current_url()))
+    }
+
     ///
     pub fn on_popstate<F, MSG>(mut cb: F) -> Cmd<MSG>
     where
@@ -198,3 +209,36 @@ impl Window {
         Cmd::recurring(rx, closure_cb)
     }
 }
+
+impl<MSG> Cmd<MSG>
+where
+    MSG: 'static,
+{
+    /// push the `url` into the browser history, changing the url in the address bar
+    /// without reloading the page, then dispatch the `msg`.
+    /// The `url` can be relative to the current url, and may contain a query string
+    /// and a hash fragment.
+    /// The url is pushed when the Cmd is emitted, so dropping the Cmd has no effect.
+    pub fn push_url(url: impl Into<String>, msg: MSG) -> Self {
+        let url = url.into();
+        Cmd::once(async move {
+            if let Err(e) = util::history().push_state_with_url(&JsValue::NULL, "", Some(&url)) {
+                log::error!("unable to push url: {url}, {e:?}");
+            }
+            msg
+        })
+    }
+
+    /// replace the current entry of the browser history with the `url`,
+    /// such that going back doesn't return to the current url, then dispatch the `msg`.
+    /// The url is replaced when the Cmd is emitted, so dropping the Cmd has no effect.
+    pub fn replace_url(url: impl Into<String>, msg: MSG) -> Self {
+        let url = url.into();
+        Cmd::once(async move {
+            if let Err(e) = util::history().replace_state_with_url(&JsValue::NULL, "", Some(&url)) {
+                log::error!("unable to replace url: {url}, {e:?}");
+            }
+            msg
+        })
+    }
+}
@@ -2,7 +2,7 @@
 use crate::vdom;
 use crate::vdom::Leaf;
 pub use crate::vdom::{element, element_ns};
-use crate::vdom::{Attribute, Node};
+use crate::vdom::{Attribute, IntoChildren, Node};
 use std::borrow::Cow;
 pub use tags::{commons::*, self_closing::*, *};
 
@@ -12,9 +12,13 @@ pub mod attributes;
 pub mod lookup;
 pub mod tags;
 pub mod units;
+#[cfg(feature = "with-dom")]
+mod virtual_list;
 
 #[cfg(feature = "with-dom")]
 pub use crate::dom::events;
+#[cfg(feature = "with-dom")]
+pub use virtual_list::{virtual_list, VirtualListConfig, DEFAULT_OVERSCAN};
 
 /// A help function which render the view when the condition is met, otherwise
 /// just display a `span(vec![], vec![])`
@@ -61,6 +65,9 @@ where
 /// Creates an html element with the element tag name and namespace
 /// This is specifically used for creating svg element where a namespace is needed, otherwise the
 /// browser will not render it correctly.
+///
+/// A `self_closing` element is a void element, such as `img` and `input`, which can not have
+/// children, so the `children` of it are dropped, with a warning in debug builds.
 /// # Examples
 /// ```rust
 /// use sauron::{*,html::html_element};
@@ -73,9 +80,18 @@ pub fn html_element<MSG>(
     namespace: Option<&'static str>,
     tag: &'static str,
     attrs: impl IntoIterator<Item = Attribute<MSG>>,
-    children: impl IntoIterator<Item = Node<MSG>>,
+    children: impl IntoChildren<MSG>,
     self_closing: bool,
 ) -> Node<MSG> {
+    let children = children.into_children();
+    if self_closing && !children.is_empty() {
+        #[cfg(debug_assertions)]
+        log::warn!(
+            "<{tag}> is a void element which can not have children, the {} children are dropped",
+            children.len()
+        );
+        return element_ns(namespace, tag, attrs, Vec::<Node<MSG>>::new(), self_closing);
+    }
     // we do a correction to children where text node siblings are next to each other by inserting
     // a comment separator in between them, to prevent the browser from merging the 2 text node
     // together
@@ -93,6 +109,249 @@ pub fn html_element<MSG>(
     element_ns(namespace, tag, attrs, corrected_children, self_closing)
 }
 
+/// Creates a portal, the children of which are mounted by the Program into the element matching
+/// the `target_selector` such as `"body"`, instead of where the portal is in the view.
+/// The portal is still diffed as part of the view, so the event listeners and messages works as
+/// usual. This is useful for modals and tooltips which needs to escape the overflow and
+/// stacking context of their parent.
+///
+/// The children is wrapped in a `div` which is appended to the target element,
+/// while a comment placeholder takes its place in the view.
+/// When the portal is removed from the view, the wrapper is also removed from the target element.
+/// If there is no element matching the `target_selector`, the portal is mounted in place.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{*, html::portal};
+///
+/// let modal: Node<()> = portal("body", [div([class("modal")], [text("Hello")])]);
+/// ```
+pub fn portal<MSG>(
+    target_selector: impl ToString,
+    children: impl IntoIterator<Item = Node<MSG>>,
+) -> Node<MSG> {
+    html_element(
+        None,
+        "div",
+        [vdom::portal_target(target_selector.to_string())],
+        children,
+        false,
+    )
+}
+
+/// Creates a `table` with a `thead` row of the `headers` and a `tbody` row for each of the `rows`.
+///
+/// A header which is not a `th` element is wrapped in a `th`,
+/// and a cell which is not a `td` or `th` element is wrapped in a `td`,
+/// so the cells can be plain nodes or the `td` elements with their own attributes and events.
+/// The `thead` is omitted when there are no headers.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::data_table, *};
+///
+/// let people = [("Alice", 32), ("Bob", 27)];
+/// let node: Node<()> = data_table(
+///     [class("people")],
+///     [text("Name"), th([class("num")], [text("Age")])],
+///     people.iter().map(|(name, age)| vec![text(name), td([class("num")], [text(age)])]),
+/// );
+/// assert_eq!(
+///     node.render_to_string(),
+///     concat!(
+///         r#"<table class="people">"#,
+///         r#"<thead><tr><th>Name</th><th class="num">Age</th></tr></thead>"#,
+///         r#"<tbody>"#,
+///         r#"<tr><td>Alice</td><td class="num">32</td></tr>"#,
+///         r#"<tr><td>Bob</td><td class="num">27</td></tr>"#,
+///         r#"</tbody></table>"#
+///     )
+/// );
+/// ```
+pub fn data_table<MSG, R>(
+    attrs: impl IntoIterator<Item = Attribute<MSG>>,
+    headers: impl IntoIterator<Item = Node<MSG>>,
+    rows: impl IntoIterator<Item = R>,
+) -> Node<MSG>
+where
+    R: IntoIterator<Item = Node<MSG>>,
+{
+    let wrap_cell = |cell: Node<MSG>| match cell.tag() {
+        Some(&"td") | Some(&"th") => cell,
+        _ => cell.wrap("td", []),
+    };
+    let headers: Vec<Node<MSG>> = headers
+        .into_iter()
+        .map(|header| match header.tag() {
+            Some(&"th") => header,
+            _ => header.wrap("th", []),
+        })
+        .collect();
+    let thead = if headers.is_empty() {
+        None
+    } else {
+        Some(thead([], [tr([], headers)]))
+    };
+    let tbody = tbody(
+        [],
+        rows.into_iter()
+            .map(|row| tr([], row.into_iter().map(wrap_cell))),
+    );
+    table(attrs, thead.into_iter().chain([tbody]))
+}
+
+/// Creates a `ul` with each of the `items` wrapped in an `li`,
+/// an item which is already an `li` element is used as is.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::ul_of, *};
+///
+/// let fruits = ["apple", "banana"];
+/// let node: Node<()> = ul_of([class("fruits")], fruits.iter().map(text));
+/// assert_eq!(
+///     node.render_to_string(),
+///     r#"<ul class="fruits"><li>apple</li><li>banana</li></ul>"#
+/// );
+/// ```
+pub fn ul_of<MSG>(
+    attrs: impl IntoIterator<Item = Attribute<MSG>>,
+    items: impl IntoIterator<Item = Node<MSG>>,
+) -> Node<MSG> {
+    ul(attrs, list_items(items))
+}
+
+/// Creates an `ol` with each of the `items` wrapped in an `li`,
+/// an item which is already an `li` element is used as is.
+pub fn ol_of<MSG>(
+    attrs: impl IntoIterator<Item = Attribute<MSG>>,
+    items: impl IntoIterator<Item = Node<MSG>>,
+) -> Node<MSG> {
+    ol(attrs, list_items(items))
+}
+
+/// Creates a `ul` with each of the `items` wrapped in an `li` with the attributes of the item,
+/// such as a `key` so the items are diffed as keyed nodes when they are reordered.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::ul_of_with_attrs, *};
+///
+/// let todos = [(1, "write"), (2, "test")];
+/// let node: Node<()> = ul_of_with_attrs(
+///     [],
+///     todos.iter().map(|(id, todo)| ([key(*id)], text(todo))),
+/// );
+/// assert_eq!(
+///     node.render_to_string(),
+///     r#"<ul><li key="1">write</li><li key="2">test</li></ul>"#
+/// );
+/// ```
+pub fn ul_of_with_attrs<MSG, A>(
+    attrs: impl IntoIterator<Item = Attribute<MSG>>,
+    items: impl IntoIterator<Item = (A, Node<MSG>)>,
+) -> Node<MSG>
+where
+    A: IntoIterator<Item = Attribute<MSG>>,
+{
+    ul(attrs, list_items_with_attrs(items))
+}
+
+/// Creates an `ol` with each of the `items` wrapped in an `li` with the attributes of the item,
+/// see [`ul_of_with_attrs`]
+pub fn ol_of_with_attrs<MSG, A>(
+    attrs: impl IntoIterator<Item = Attribute<MSG>>,
+    items: impl IntoIterator<Item = (A, Node<MSG>)>,
+) -> Node<MSG>
+where
+    A: IntoIterator<Item = Attribute<MSG>>,
+{
+    ol(attrs, list_items_with_attrs(items))
+}
+
+/// wrap the items which are not an `li` element in an `li`
+fn list_items<MSG>(items: impl IntoIterator<Item = Node<MSG>>) -> Vec<Node<MSG>> {
+    items
+        .into_iter()
+        .map(|item| match item.tag() {
+            Some(&"li") => item,
+            _ => item.wrap("li", []),
+        })
+        .collect()
+}
+
+/// wrap the items in an `li` with their attributes,
+/// the attributes are added to the item which is already an `li` element
+fn list_items_with_attrs<MSG, A>(items: impl IntoIterator<Item = (A, Node<MSG>)>) -> Vec<Node<MSG>>
+where
+    A: IntoIterator<Item = Attribute<MSG>>,
+{
+    items
+        .into_iter()
+        .map(|(attrs, item)| match item.tag() {
+            Some(&"li") => item.with_attributes(attrs),
+            _ => item.wrap("li", attrs),
+        })
+        .collect()
+}
+
+/// Attach each key to its child node, returning the children ready to be passed to an element.
+///
+/// The keys allows the diff to match the old and new children which have the same key,
+/// so that a reordered child is moved in the DOM instead of being recreated.
+/// The keys should be stable ids such as the id of the item in the model, and not its index.
+/// A child which is not an element can not have a key, and is kept as is.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::keyed_children, *};
+///
+/// let todos = [(7, "write tests"), (3, "fix bugs")];
+/// let node: Node<()> = ul(
+///     [],
+///     keyed_children(todos.iter().map(|(id, title)| (*id, li([], [text(title)])))),
+/// );
+/// assert_eq!(
+///     node.render_to_string(),
+///     r#"<ul><li key="7">write tests</li><li key="3">fix bugs</li></ul>"#
+/// );
+/// ```
+pub fn keyed_children<MSG, K>(pairs: impl IntoIterator<Item = (K, Node<MSG>)>) -> Vec<Node<MSG>>
+where
+    K: Into<vdom::Value>,
+{
+    let children: Vec<Node<MSG>> = pairs
+        .into_iter()
+        .map(|(k, child)| {
+            if child.is_element() {
+                child.with_attributes([attributes::key(k)])
+            } else {
+                #[cfg(debug_assertions)]
+                log::warn!("a key can only be attached to an element, the child is kept unkeyed");
+                child
+            }
+        })
+        .collect();
+    #[cfg(debug_assertions)]
+    warn_duplicate_keys(&children);
+    children
+}
+
+/// log a warning for each key which is used by more than one of the children,
+/// since the diff can only match one of them
+#[cfg(debug_assertions)]
+fn warn_duplicate_keys<MSG>(children: &[Node<MSG>]) {
+    let keys: Vec<&vdom::Value> = children
+        .iter()
+        .filter_map(|child| child.first_value(vdom::KEY))
+        .collect();
+    for (i, key) in keys.iter().enumerate() {
+        if keys[..i].contains(key) {
+            log::warn!("duplicate key: {key:?} is used by more than one of the keyed children");
+        }
+    }
+}
+
 /// creates a text node using a formatter
 /// # Examples
 /// ```rust
@@ -120,6 +379,28 @@ pub fn text<MSG>(s: impl ToString) -> Node<MSG> {
     Node::Leaf(Leaf::Text(Cow::from(s.to_string())))
 }
 
+/// Create a preformatted text node, the whitespace of which is kept as is regardless of
+/// the parent element, such as for code snippets and log output inside a `<div>` or `<td>`.
+///
+/// Unlike a [`text`] node, it is not affected by the `normalize_whitespace` serialize option
+/// nor [`Node::trim_whitespace_text`], and no new line or indentation is added around it in
+/// the pretty html. Otherwise it is the same as a [`text`] node, including the escaping:
+/// the content is set as the text of the DOM node and is never parsed as html.
+/// The browser still displays the whitespace according to the `white-space` css of the parent.
+/// # Example
+/// ```rust
+/// use sauron::{*, html::pre_text};
+///
+/// let node: Node<()> = div([], [span([], [text("log:")]), pre_text("started\n  done")]);
+/// assert_eq!(
+///     node.render_to_string_pretty(),
+///     "<div>\n  <span>log:</span>started\n  done</div>"
+/// );
+/// ```
+pub fn pre_text<MSG>(s: impl ToString) -> Node<MSG> {
+    Node::Leaf(Leaf::PreText(Cow::from(s.to_string())))
+}
+
 /// create a comment node
 /// # Example
 /// ```rust
@@ -157,6 +438,43 @@ pub fn symbol<MSG>(s: &str) -> Node<MSG> {
     Node::Leaf(Leaf::Symbol(s.into()))
 }
 
+/// Create a text node which already contains encoded html entities such as
+/// `&nbsp;`, `&copy;` or `&#8212;`, the entities are emitted verbatim instead of
+/// being displayed literally.
+///
+/// Only the entities are passed through: `<`, `>` and quotes are escaped and an `&`
+/// which doesn't start a complete entity is encoded as `&amp;`, so the content can not
+/// introduce markup. Since the node is inserted as html, use it for static or trusted
+/// strings only and prefer [`text`] for user input. Use `sauron::parse_html`
+/// when actual markup needs to be rendered.
+/// # Example
+/// ```rust
+/// use sauron::{*, html::entity_text};
+///
+/// let node: Node<()> = entity_text("&copy; 2024&nbsp;Sauron & co <b>");
+/// assert_eq!(node.render_to_string(), "&copy; 2024&nbsp;Sauron &amp; co &lt;b&gt;");
+/// ```
+pub fn entity_text<MSG>(s: &str) -> Node<MSG> {
+    let mut encoded = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(pos) = rest.find('&') {
+        encoded.push_str(&escape_html_text(&rest[..pos]));
+        rest = &rest[pos..];
+        match vdom::entity_len(rest) {
+            Some(len) => {
+                encoded.push_str(&rest[..len]);
+                rest = &rest[len..];
+            }
+            None => {
+                encoded.push_str("&amp;");
+                rest = &rest[1..];
+            }
+        }
+    }
+    encoded.push_str(&escape_html_text(rest));
+    Node::Leaf(Leaf::Symbol(encoded.into()))
+}
+
 fn escape_html_text(s: &str) -> String {
     s.chars()
         .map(|ch| match ch {
@@ -168,3 +486,40 @@ fn escape_html_text(s: &str) -> String {
         })
         .collect()
 }
+
+/// prefix the class names in the css selector with the namespace, the same way
+/// `Component::prefix_class` does, so `.item:hover > .icon` becomes
+/// `.{namespace}__item:hover > .{namespace}__icon`.
+/// The selector is returned as is when there is no namespace.
+/// This is used in `jss_ns!` to scope the styles of a component.
+pub fn scope_selector(namespace: Option<&str>, selector: &str) -> String {
+    let Some(namespace) = namespace else {
+        return selector.to_string();
+    };
+    let mut scoped = String::with_capacity(selector.len());
+    let mut chars = selector.chars().peekable();
+    // class names in attribute selectors and quoted text are not prefixed, ie: `[href$=".pdf"]`
+    let mut in_brackets = false;
+    let mut quote: Option<char> = None;
+    while let Some(ch) = chars.next() {
+        scoped.push(ch);
+        match ch {
+            '"' | '\'' if quote == Some(ch) => quote = None,
+            '"' | '\'' if quote.is_none() => quote = Some(ch),
+            '[' if quote.is_none() => in_brackets = true,
+            ']' if quote.is_none() => in_brackets = false,
+            '.' if quote.is_none() && !in_brackets => {
+                let is_class_name = chars
+                    .peek()
+                    .map(|c| c.is_alphabetic() || *c == '_' || *c == '-')
+                    .unwrap_or(false);
+                if is_class_name {
+                    scoped.push_str(namespace);
+                    scoped.push_str("__");
+                }
+            }
+            _ => (),
+        }
+    }
+    scoped
+}
@@ -10,13 +10,35 @@ pub use crate::vdom::EventCallback;
 pub use crate::vdom::Style;
 pub use crate::vdom::{key, replace, skip, skip_criteria};
 pub use crate::{dom::Event, vdom::Attribute};
+pub use aria::{
+    aria_busy, aria_checked, aria_disabled, aria_expanded, aria_hidden, aria_invalid, aria_modal,
+    aria_pressed, aria_readonly, aria_required, aria_selected,
+};
 pub use attribute_macros::commons::*;
 pub use attribute_macros::*;
+#[cfg(feature = "chrono")]
+pub use datetime::{datetime_attr, DatetimeValue};
+pub use form::{autocomplete, inputmode, spellcheck, AutocompleteToken, InputMode};
+pub use memo::{attr_lazy, attr_memo};
+pub use srcset::{sizes_entries, srcset_entries, SrcsetDescriptor};
 
+mod aria;
 #[macro_use]
 mod attribute_macros;
+#[cfg(feature = "chrono")]
+mod datetime;
+mod form;
+mod memo;
+mod srcset;
 
 /// A helper function which creates a style attribute by assembling the tuples into a string for the style value.
+///
+/// The styles are kept as a list of declarations, which the `Program` updates one by one in the
+/// inline style of the element, such that changing a single style only sets that property.
+/// The other properties of the inline style, such as the ones set by javascript, are left as is.
+/// In contrast, a plain string value such as `attr("style", "display:flex")` replaces the whole
+/// inline style, so use either one of them for the `style` of an element but not both.
+///
 /// # Example
 /// ```rust
 /// use sauron::{*, html::attributes::styles};
@@ -130,7 +152,7 @@ pub fn classes<MSG>(class_list: impl IntoIterator<Item = impl Into<Value>>) -> A
 ///
 /// let is_checked = true;
 /// let html: Node<()> =
-///     input(vec![r#type("checkbox")], vec![]).with_attributes(attrs_flag(vec![(
+///     input(vec![r#type("checkbox")]).with_attributes(attrs_flag(vec![(
 ///                             "checked",
 ///                             "checked",
 ///                             is_checked,
@@ -148,6 +170,29 @@ pub fn attrs_flag<MSG>(
     })
 }
 
+/// Create the attributes in the `namespace` from pairs of attribute name and value,
+/// such as the `xlink` attributes of svg elements
+/// # Examples
+/// ```rust
+/// use sauron::{*, html::attributes::attrs_ns};
+///
+/// let attributes: Vec<Attribute<()>> = attrs_ns(
+///     Some("http://www.w3.org/1999/xlink"),
+///     [("href", "#icon"), ("title", "icon")],
+/// )
+/// .into_iter()
+/// .collect();
+/// assert_eq!(attributes.len(), 2);
+/// ```
+pub fn attrs_ns<MSG>(
+    namespace: Option<vdom::Namespace>,
+    pairs: impl IntoIterator<Item = (vdom::AttributeName, impl Into<Value>)>,
+) -> impl IntoIterator<Item = Attribute<MSG>> {
+    pairs
+        .into_iter()
+        .map(move |(name, value)| vdom::attr_ns(namespace, name, value.into()))
+}
+
 /// Set the attribute of this element if value is Some, empty attribute otherwise
 /// # Examples
 /// ```rust
@@ -175,12 +220,17 @@ pub fn maybe_attr<MSG>(
 }
 
 /// set the checked value, used checkbox and radio buttons
+///
+/// Radio buttons with the same `name` form a group where only one of them is checked.
+/// After each update the program sets the `checked` property of the radio buttons from the
+/// view, so the group shows the radio button which is checked in the view
+/// and not the one the user clicked, until the app updates its state accordingly.
 /// # Examples
 /// ```rust
 /// use sauron::*;
 ///
 /// let html: Node<()> =
-///     input(vec![r#type("checkbox"), checked(true)], vec![]);
+///     input(vec![r#type("checkbox"), checked(true)]);
 /// ```
 pub fn checked<MSG>(is_checked: bool) -> Attribute<MSG> {
     if is_checked {
@@ -203,7 +253,7 @@ pub fn checked<MSG>(is_checked: bool) -> Attribute<MSG> {
 /// use sauron::{*, html::*, html::attributes::*};
 ///
 /// let html: Node<()> =
-///     input(vec![r#type("checkbox"), disabled(true)], vec![]);
+///     input(vec![r#type("checkbox"), disabled(true)]);
 /// ```
 pub fn disabled<MSG>(is_disabled: bool) -> Attribute<MSG> {
     if is_disabled {
@@ -234,6 +284,36 @@ pub fn focus<MSG>(is_focus: bool) -> Attribute<MSG> {
     attr("focus", is_focus)
 }
 
+/// html attributes which have a typed helper function instead of being declared in the list
+/// of the html attributes, these are still looked up as standard html attributes
+#[cfg(feature = "with-lookup")]
+pub const HTML_ATTRS_TYPED: &[&str] = &["autocomplete", "inputmode", "spellcheck", "tabindex"];
+
+/// set the [tabindex](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/tabindex)
+/// of an element, which is how it takes part in the keyboard navigation.
+///
+/// - a negative value, such as `-1`, makes the element focusable but not reachable with the `Tab` key
+/// - `0` makes the element reachable with the `Tab` key in the order it appears in the document
+/// - a positive value puts the element ahead of the rest of the document in the `Tab` order,
+///   which is confusing for keyboard and screen reader users, a warning is logged in debug builds
+///
+/// # Examples
+/// ```rust
+/// use sauron::*;
+///
+/// let view: Node<()> = div([attr("role", "button"), tabindex(0)], [text("save")]);
+/// assert_eq!(view.render_to_string(), r#"<div role="button" tabindex="0">save</div>"#);
+/// ```
+pub fn tabindex<MSG>(index: i32) -> Attribute<MSG> {
+    #[cfg(debug_assertions)]
+    if index > 0 {
+        log::warn!(
+            "tabindex={index} changes the keyboard navigation order, use 0 or reorder the elements instead"
+        );
+    }
+    attr("tabindex", index)
+}
+
 /// a utility function to convert simple value into attribute
 /// # Examples
 /// ```rust
@@ -241,12 +321,95 @@ pub fn focus<MSG>(is_focus: bool) -> Attribute<MSG> {
 ///
 /// let data_id: Attribute<()> = attr("data-id", 42);
 /// ```
-pub fn attr<MSG>(att: &'static str, v: impl Into<Value>) -> Attribute<MSG> {
-    vdom::attr(att, AttributeValue::from(v.into()))
+pub fn attr<MSG>(att: &'static str, v: impl Into<AttributeValue<MSG>>) -> Attribute<MSG> {
+    vdom::attr(att, v)
+}
+
+/// an attribute which is meaningful by its mere presence, such as `required`,
+/// `autofocus` and `novalidate`.
+/// It is set to an empty string in the DOM, and is rendered as just its name when it is
+/// a [boolean attribute](https://html.spec.whatwg.org/multipage/indices.html#attributes-3)
+/// of html, the other attributes are rendered with an empty value such as `alt=""`.
+///
+/// Unlike the boolean attributes such as `disabled(bool)` this is unconditionally present,
+/// leave it out of the view to remove the attribute from the element.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::attributes::presence_attr, *};
+///
+/// let view: Node<()> = input([r#type("email"), presence_attr("required")]);
+/// assert_eq!(view.render_to_string(), r#"<input type="email" required/>"#);
+/// ```
+pub fn presence_attr<MSG>(name: &'static str) -> Attribute<MSG> {
+    vdom::attr(name, "")
+}
+
+/// an attribute with the `value` encoded as json, useful for passing a configuration
+/// to the web components which read json from their attributes, such as `data-config`.
+///
+/// The value is set as is in the DOM, and when rendered as a string the `"` and `&`
+/// in the json are escaped as `&quot;` and `&amp;` which the browser decodes back when parsing.
+/// The whole json is compared when diffing and is set again when it changes,
+/// so keep the large or frequently changing data out of the attributes.
+///
+/// # Panics
+/// Panics if the value can not be encoded as json, such as a map with non-string keys.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::attributes::json_attr, *};
+///
+/// #[derive(serde::Serialize)]
+/// struct Config {
+///     theme: &'static str,
+///     page_size: usize,
+/// }
+///
+/// let view: Node<()> = div([json_attr("data-config", &Config { theme: "dark", page_size: 20 })], []);
+/// assert_eq!(
+///     view.render_to_string(),
+///     r#"<div data-config="{&quot;theme&quot;:&quot;dark&quot;,&quot;page_size&quot;:20}"></div>"#
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub fn json_attr<MSG>(name: &'static str, value: &impl serde::Serialize) -> Attribute<MSG> {
+    let json = serde_json::to_string(value)
+        .unwrap_or_else(|e| panic!("unable to encode the value of `{name}` as json: {e}"));
+    vdom::attr(name, json)
+}
+
+/// the attribute which marks the scrollable containers to preserve the scroll position of,
+/// see [`preserve_scroll`]
+pub(crate) const PRESERVE_SCROLL: &str = "data-preserve-scroll";
+
+/// mark a scrollable container to keep its scroll position when the view is patched,
+/// such as the chat and log panes which content is replaced frequently.
+///
+/// The scroll positions of the marked containers are recorded before the patches are applied
+/// and are restored afterwards. When a container is replaced, the new container is matched
+/// by its `id`, otherwise by its position among the marked containers in document order,
+/// so give the containers an `id` when they can be added or removed.
+/// A container which was scrolled to the bottom is kept at the bottom,
+/// so the new entries appended to it stays in view.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::attributes::preserve_scroll, *};
+///
+/// let view: Node<()> = ul([id("messages"), preserve_scroll()], [li([], [text("hi")])]);
+/// assert_eq!(
+///     view.render_to_string(),
+///     r#"<ul id="messages" data-preserve-scroll=""><li>hi</li></ul>"#
+/// );
+/// ```
+pub fn preserve_scroll<MSG>() -> Attribute<MSG> {
+    presence_attr(PRESERVE_SCROLL)
 }
 
 /// a utility function to return create an empty attr, useful for cases where branch expression
 /// need to return an attribute which otherwise it can not produce
+/// Note: this is not an attribute without a value, see [`presence_attr`] for that.
 /// example:
 /// ```rust
 /// use sauron::*;
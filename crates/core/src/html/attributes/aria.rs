@@ -0,0 +1,80 @@
+//! [ARIA](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes) state attributes,
+//! which mirror the state of the app such as whether a menu is expanded.
+//!
+//! These are not boolean html attributes, the assistive technologies read the value
+//! so it is always rendered as `"true"` or `"false"`, and `"mixed"` for the tri-state ones,
+//! instead of dropping the attribute when it is false.
+use crate::vdom::{attr, Attribute, AttributeName};
+
+/// the value of an ARIA state which is either true or false
+fn aria_bool<MSG>(name: AttributeName, value: bool) -> Attribute<MSG> {
+    attr(name, if value { "true" } else { "false" })
+}
+
+/// the value of an ARIA state which is true, false or mixed when it is `None`
+fn aria_tristate<MSG>(name: AttributeName, value: Option<bool>) -> Attribute<MSG> {
+    match value {
+        Some(value) => aria_bool(name, value),
+        None => attr(name, "mixed"),
+    }
+}
+
+macro_rules! declare_aria_bool {
+    ( $( $(#[$attr:meta])* $name:ident => $attribute:tt; )* ) => {
+        $(
+            $(#[$attr])*
+            pub fn $name<MSG>(value: bool) -> Attribute<MSG> {
+                aria_bool($attribute, value)
+            }
+        )*
+    };
+}
+
+declare_aria_bool! {
+    /// `aria-expanded`, whether the element which it controls such as a menu or an accordion panel is shown
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sauron::{html::attributes::aria_expanded, *};
+    ///
+    /// let view: Node<()> = button([aria_expanded(false)], [text("menu")]);
+    /// assert_eq!(view.render_to_string(), r#"<button aria-expanded="false">menu</button>"#);
+    /// ```
+    aria_expanded => "aria-expanded";
+    /// `aria-selected`, whether the element such as a tab or an option is selected
+    aria_selected => "aria-selected";
+    /// `aria-hidden`, whether the element is hidden from the assistive technologies
+    aria_hidden => "aria-hidden";
+    /// `aria-disabled`, whether the element is shown but can not be interacted with
+    aria_disabled => "aria-disabled";
+    /// `aria-busy`, whether the element is being updated, such as while loading its content
+    aria_busy => "aria-busy";
+    /// `aria-modal`, whether the element is a modal dialog
+    aria_modal => "aria-modal";
+    /// `aria-required`, whether the field must be filled in before the form is submitted
+    aria_required => "aria-required";
+    /// `aria-readonly`, whether the value of the element can not be changed
+    aria_readonly => "aria-readonly";
+    /// `aria-invalid`, whether the value of the field is invalid
+    aria_invalid => "aria-invalid";
+}
+
+/// `aria-pressed`, whether a toggle button is pressed, `None` is a partially pressed button
+/// which is rendered as `"mixed"`
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::attributes::aria_pressed, *};
+///
+/// let view: Node<()> = button([aria_pressed(None)], [text("bold")]);
+/// assert_eq!(view.render_to_string(), r#"<button aria-pressed="mixed">bold</button>"#);
+/// ```
+pub fn aria_pressed<MSG>(value: Option<bool>) -> Attribute<MSG> {
+    aria_tristate("aria-pressed", value)
+}
+
+/// `aria-checked`, whether a checkbox, a radio or a switch is checked, `None` is a checkbox
+/// which only has some of its children checked, which is rendered as `"mixed"`
+pub fn aria_checked<MSG>(value: Option<bool>) -> Attribute<MSG> {
+    aria_tristate("aria-checked", value)
+}
@@ -1,4 +1,3 @@
-use crate::html::attributes::Value;
 use crate::vdom::attr;
 use crate::vdom::AttributeValue;
 
@@ -23,9 +22,9 @@ macro_rules! declare_attributes {
                 #[inline]
                 #[allow(non_snake_case)]
                 pub fn $name<V, MSG>(v: V) -> $crate::vdom::Attribute<MSG>
-                    where V: Into<Value>,
+                    where V: Into<AttributeValue<MSG>>,
                     {
-                        attr(stringify!($name), AttributeValue::from(v.into()))
+                        attr(stringify!($name), v)
                 }
             }
          )*
@@ -43,9 +42,9 @@ macro_rules! declare_attributes {
                 #[inline]
                 #[allow(non_snake_case)]
                 pub fn $name<V, MSG>(v: V) -> $crate::vdom::Attribute<MSG>
-                    where V: Into<Value>,
+                    where V: Into<AttributeValue<MSG>>,
                     {
-                        attr($attribute, AttributeValue::from(v.into()))
+                        attr($attribute, v)
                 }
              }
          )*
@@ -94,7 +93,7 @@ macro_rules! declare_html_attributes_special{
          $name:ident : $attribute:tt;
        )*
      ) => {
-        declare_attributes!{ $($name => $attribute;)*}
+        declare_attributes!{ $($(#[$attr])* $name => $attribute;)*}
 
         #[cfg(feature = "with-lookup")]
         /// These are html attributes with names that are non proper rust identifier therefore
@@ -105,7 +104,6 @@ macro_rules! declare_html_attributes_special{
 
 /// common used html attributes
 pub mod commons {
-    use crate::html::attributes::Value;
     use crate::vdom::attr;
     use crate::vdom::AttributeValue;
     // List from html attributes
@@ -118,7 +116,6 @@ pub mod commons {
         allow;
         alt;
         autocapitalize;
-        autocomplete;
         autofocus;
         autoplay;
         background;
@@ -164,7 +161,6 @@ pub mod commons {
         importance;
         integrity;
         intrinsicsize;
-        inputmode;
         ismap;
         itemprop;
         keytype;
@@ -206,14 +202,12 @@ pub mod commons {
         size;
         sizes;
         slot;
-        spellcheck;
         src;
         srcdoc;
         srclang;
         srcset;
         start;
         step;
-        tabindex;
         target;
         title;
         translate;
@@ -241,4 +235,12 @@ declare_html_attributes_special! {
     flex_direction : "flex-direction";
     r#loop : "loop";
     r#type : "type";
+    /// An alias of `r#for`, such as for associating a `label` with its input
+    for_ : "for";
+    /// An alias of `r#type`
+    type_ : "type";
+    /// An alias of `r#loop`
+    loop_ : "loop";
+    /// An alias of `r#async`
+    async_ : "async";
 }
@@ -0,0 +1,86 @@
+use crate::vdom::{attr, Attribute, AttributeName};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, TimeZone, Timelike};
+
+/// A date or a time value which is formatted the way the datetime attributes expect it.
+///
+/// | value | format | for |
+/// |---|---|---|
+/// | `DateTime<Tz>` | `2026-10-15T08:30:00+02:00`, or `2026-10-15T06:30:00Z` in UTC | the `datetime` of `time`, `del` and `ins` |
+/// | `NaiveDateTime` | `2026-10-15T08:30:00` | the `value`, `min` and `max` of `input[type=datetime-local]` |
+/// | `NaiveDate` | `2026-10-15` | the `value`, `min` and `max` of `input[type=date]` |
+/// | `NaiveTime` | `08:30:00` | the `value`, `min` and `max` of `input[type=time]` |
+///
+/// The fraction of a second is truncated to milliseconds, and is only included when the
+/// milliseconds are not zero, such as `08:30:00.250`.
+pub trait DatetimeValue {
+    /// format this value for a datetime attribute
+    fn to_datetime_string(&self) -> String;
+}
+
+impl<Tz: TimeZone> DatetimeValue for DateTime<Tz> {
+    fn to_datetime_string(&self) -> String {
+        let format = if has_millis(self.nanosecond()) {
+            SecondsFormat::Millis
+        } else {
+            SecondsFormat::Secs
+        };
+        self.to_rfc3339_opts(format, true)
+    }
+}
+
+impl DatetimeValue for NaiveDateTime {
+    fn to_datetime_string(&self) -> String {
+        if has_millis(self.nanosecond()) {
+            self.format("%Y-%m-%dT%H:%M:%S%.3f").to_string()
+        } else {
+            self.format("%Y-%m-%dT%H:%M:%S").to_string()
+        }
+    }
+}
+
+impl DatetimeValue for NaiveDate {
+    fn to_datetime_string(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+}
+
+impl DatetimeValue for NaiveTime {
+    fn to_datetime_string(&self) -> String {
+        if has_millis(self.nanosecond()) {
+            self.format("%H:%M:%S%.3f").to_string()
+        } else {
+            self.format("%H:%M:%S").to_string()
+        }
+    }
+}
+
+/// whether the fraction of a second is not zero when it is truncated to milliseconds
+fn has_millis(nanosecond: u32) -> bool {
+    nanosecond % 1_000_000_000 >= 1_000_000
+}
+
+/// an attribute which value is the date or the time `value` in the format which the attribute
+/// expects, see [`DatetimeValue`] for the format of each kind of value.
+///
+/// # Examples
+/// ```rust
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use sauron::{html::attributes::datetime_attr, *};
+///
+/// let published = Utc.with_ymd_and_hms(2026, 10, 15, 6, 30, 0).unwrap();
+/// let view: Node<()> = time([datetime_attr("datetime", &published)], [text("today")]);
+/// assert_eq!(
+///     view.render_to_string(),
+///     r#"<time datetime="2026-10-15T06:30:00Z">today</time>"#
+/// );
+///
+/// let due = NaiveDate::from_ymd_opt(2026, 10, 31).unwrap();
+/// let picker: Node<()> = input([r#type("date"), datetime_attr("value", &due)]);
+/// assert_eq!(
+///     picker.render_to_string(),
+///     r#"<input type="date" value="2026-10-31"/>"#
+/// );
+/// ```
+pub fn datetime_attr<MSG>(name: AttributeName, value: &impl DatetimeValue) -> Attribute<MSG> {
+    attr(name, value.to_datetime_string())
+}
@@ -0,0 +1,301 @@
+use crate::vdom::{attr, Attribute};
+use std::fmt;
+
+/// The virtual keyboard which is shown when editing an element, see [`inputmode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputMode {
+    /// no virtual keyboard, for elements which show their own keyboard
+    None,
+    /// the standard keyboard of the locale
+    Text,
+    /// digits and the decimal separator of the locale
+    Decimal,
+    /// digits only, such as for a pin or a quantity
+    Numeric,
+    /// digits, `*` and `#`, for telephone numbers
+    Tel,
+    /// a keyboard which has a search key in place of the enter key
+    Search,
+    /// a keyboard which has the `@` and `.` keys, for email addresses
+    Email,
+    /// a keyboard which has the `/` and `.` keys, for urls
+    Url,
+}
+
+impl fmt::Display for InputMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mode = match self {
+            Self::None => "none",
+            Self::Text => "text",
+            Self::Decimal => "decimal",
+            Self::Numeric => "numeric",
+            Self::Tel => "tel",
+            Self::Search => "search",
+            Self::Email => "email",
+            Self::Url => "url",
+        };
+        write!(f, "{mode}")
+    }
+}
+
+/// A token of the [autocomplete](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/autocomplete)
+/// attribute, see [`autocomplete`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutocompleteToken {
+    /// the browser should not fill in the value
+    Off,
+    /// the browser may fill in the value, without a hint of what is expected
+    On,
+    /// the address is for shipping, which goes before the field name
+    Shipping,
+    /// the address is for billing, which goes before the field name
+    Billing,
+    /// the contact is a home one, which goes before a contact field name
+    Home,
+    /// the contact is a work one, which goes before a contact field name
+    Work,
+    /// the contact is a mobile one, which goes before a contact field name
+    Mobile,
+    /// the contact is a fax, which goes before a contact field name
+    Fax,
+    /// the contact is a pager, which goes before a contact field name
+    Pager,
+    /// the full name
+    Name,
+    /// the prefix or the title, such as `Mrs.`
+    HonorificPrefix,
+    /// the given name or the first name
+    GivenName,
+    /// the middle name
+    AdditionalName,
+    /// the family name or the last name
+    FamilyName,
+    /// the suffix, such as `Jr.`
+    HonorificSuffix,
+    /// the nickname or the handle
+    Nickname,
+    /// an email address
+    Email,
+    /// a username or an account name
+    Username,
+    /// a new password, such as when signing up or changing the password
+    NewPassword,
+    /// the current password of the account in the username field
+    CurrentPassword,
+    /// a one time code, such as the one sent by sms
+    OneTimeCode,
+    /// the job title
+    OrganizationTitle,
+    /// the company or the organization name
+    Organization,
+    /// the whole street address, for a `textarea`
+    StreetAddress,
+    /// the first line of the street address
+    AddressLine1,
+    /// the second line of the street address
+    AddressLine2,
+    /// the third line of the street address
+    AddressLine3,
+    /// the broadest administrative level of the address, such as the state or the province
+    AddressLevel1,
+    /// the second administrative level of the address, such as the city
+    AddressLevel2,
+    /// the third administrative level of the address
+    AddressLevel3,
+    /// the finest administrative level of the address
+    AddressLevel4,
+    /// the country code
+    Country,
+    /// the country name
+    CountryName,
+    /// the postal code or the zip code
+    PostalCode,
+    /// the full name printed on the payment card
+    CcName,
+    /// the given name printed on the payment card
+    CcGivenName,
+    /// the family name printed on the payment card
+    CcFamilyName,
+    /// the number of the payment card
+    CcNumber,
+    /// the expiration date of the payment card
+    CcExp,
+    /// the expiration month of the payment card
+    CcExpMonth,
+    /// the expiration year of the payment card
+    CcExpYear,
+    /// the security code of the payment card
+    CcCsc,
+    /// the type of the payment card, such as `Visa`
+    CcType,
+    /// the currency of the transaction
+    TransactionCurrency,
+    /// the amount of the transaction
+    TransactionAmount,
+    /// the preferred language
+    Language,
+    /// the whole birthday
+    Bday,
+    /// the day of the birthday
+    BdayDay,
+    /// the month of the birthday
+    BdayMonth,
+    /// the year of the birthday
+    BdayYear,
+    /// the gender identity
+    Sex,
+    /// the whole telephone number, including the country code
+    Tel,
+    /// the country code of the telephone number
+    TelCountryCode,
+    /// the telephone number without the country code
+    TelNational,
+    /// the area code of the telephone number
+    TelAreaCode,
+    /// the telephone number without the country and the area code
+    TelLocal,
+    /// the extension of the telephone number
+    TelExtension,
+    /// an instant messaging url
+    Impp,
+    /// a url, such as the home page
+    Url,
+    /// the url of an image, such as the avatar
+    Photo,
+    /// a passkey, which goes last after the other tokens
+    Webauthn,
+}
+
+impl fmt::Display for AutocompleteToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let token = match self {
+            Self::Off => "off",
+            Self::On => "on",
+            Self::Shipping => "shipping",
+            Self::Billing => "billing",
+            Self::Home => "home",
+            Self::Work => "work",
+            Self::Mobile => "mobile",
+            Self::Fax => "fax",
+            Self::Pager => "pager",
+            Self::Name => "name",
+            Self::HonorificPrefix => "honorific-prefix",
+            Self::GivenName => "given-name",
+            Self::AdditionalName => "additional-name",
+            Self::FamilyName => "family-name",
+            Self::HonorificSuffix => "honorific-suffix",
+            Self::Nickname => "nickname",
+            Self::Email => "email",
+            Self::Username => "username",
+            Self::NewPassword => "new-password",
+            Self::CurrentPassword => "current-password",
+            Self::OneTimeCode => "one-time-code",
+            Self::OrganizationTitle => "organization-title",
+            Self::Organization => "organization",
+            Self::StreetAddress => "street-address",
+            Self::AddressLine1 => "address-line1",
+            Self::AddressLine2 => "address-line2",
+            Self::AddressLine3 => "address-line3",
+            Self::AddressLevel1 => "address-level1",
+            Self::AddressLevel2 => "address-level2",
+            Self::AddressLevel3 => "address-level3",
+            Self::AddressLevel4 => "address-level4",
+            Self::Country => "country",
+            Self::CountryName => "country-name",
+            Self::PostalCode => "postal-code",
+            Self::CcName => "cc-name",
+            Self::CcGivenName => "cc-given-name",
+            Self::CcFamilyName => "cc-family-name",
+            Self::CcNumber => "cc-number",
+            Self::CcExp => "cc-exp",
+            Self::CcExpMonth => "cc-exp-month",
+            Self::CcExpYear => "cc-exp-year",
+            Self::CcCsc => "cc-csc",
+            Self::CcType => "cc-type",
+            Self::TransactionCurrency => "transaction-currency",
+            Self::TransactionAmount => "transaction-amount",
+            Self::Language => "language",
+            Self::Bday => "bday",
+            Self::BdayDay => "bday-day",
+            Self::BdayMonth => "bday-month",
+            Self::BdayYear => "bday-year",
+            Self::Sex => "sex",
+            Self::Tel => "tel",
+            Self::TelCountryCode => "tel-country-code",
+            Self::TelNational => "tel-national",
+            Self::TelAreaCode => "tel-area-code",
+            Self::TelLocal => "tel-local",
+            Self::TelExtension => "tel-extension",
+            Self::Impp => "impp",
+            Self::Url => "url",
+            Self::Photo => "photo",
+            Self::Webauthn => "webauthn",
+        };
+        write!(f, "{token}")
+    }
+}
+
+/// set the [inputmode](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/inputmode)
+/// of an element, which is the virtual keyboard shown on touch devices when editing it.
+///
+/// Use [`attr`] such as `attr("inputmode", mode)` to set it from a string.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::attributes::InputMode, *};
+///
+/// let view: Node<()> = input([r#type("text"), inputmode(InputMode::Numeric)]);
+/// assert_eq!(view.render_to_string(), r#"<input type="text" inputmode="numeric"/>"#);
+/// ```
+pub fn inputmode<MSG>(mode: InputMode) -> Attribute<MSG> {
+    attr("inputmode", mode.to_string())
+}
+
+/// set the [autocomplete](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/autocomplete)
+/// of a form field, which is the tokens separated by spaces, such as `shipping postal-code`.
+///
+/// `Off` and `On` must be used alone, a warning is logged in debug builds when they are
+/// combined with the other tokens.
+/// Use [`attr`] such as `attr("autocomplete", "off")` to set it from a string.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::attributes::AutocompleteToken::*, *};
+///
+/// let view: Node<()> = input([autocomplete(&[Shipping, PostalCode])]);
+/// assert_eq!(view.render_to_string(), r#"<input autocomplete="shipping postal-code"/>"#);
+/// ```
+pub fn autocomplete<MSG>(tokens: &[AutocompleteToken]) -> Attribute<MSG> {
+    #[cfg(debug_assertions)]
+    if tokens.len() > 1
+        && tokens
+            .iter()
+            .any(|token| matches!(token, AutocompleteToken::Off | AutocompleteToken::On))
+    {
+        log::warn!("autocomplete `off` and `on` can not be combined with other tokens: {tokens:?}");
+    }
+    let tokens = tokens
+        .iter()
+        .map(|token| token.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    attr("autocomplete", tokens)
+}
+
+/// set the [spellcheck](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/spellcheck)
+/// of an element.
+///
+/// This is not a boolean attribute, so `false` is rendered as `spellcheck="false"`
+/// to turn off the spell checking that the browser would otherwise do on editable text.
+/// Use [`attr`] such as `attr("spellcheck", value)` to set it from a string.
+///
+/// # Examples
+/// ```rust
+/// use sauron::*;
+///
+/// let view: Node<()> = textarea([spellcheck(false)], []);
+/// assert_eq!(view.render_to_string(), r#"<textarea spellcheck="false"></textarea>"#);
+/// ```
+pub fn spellcheck<MSG>(is_enabled: bool) -> Attribute<MSG> {
+    attr("spellcheck", if is_enabled { "true" } else { "false" })
+}
@@ -0,0 +1,150 @@
+use crate::vdom::{attr, Attribute, AttributeName, Value};
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::panic::Location;
+
+/// the maximum number of the memoized values, the least recently used value is evicted
+/// when it is exceeded, so the cache doesn't grow with each of the keys which are used once
+const MEMO_CAPACITY: usize = 1024;
+
+/// the place where `attr_memo` is called, the attribute name and the hash of its key
+type MemoKey = (&'static Location<'static>, AttributeName, u64);
+
+thread_local! {
+    static MEMO: RefCell<MemoCache> = RefCell::new(MemoCache::default());
+}
+
+/// the memoized attribute values, which keeps track of when each value is last used
+#[derive(Default)]
+struct MemoCache {
+    values: HashMap<MemoKey, (Value, u64)>,
+    /// the keys of the values ordered by when they are last used, the oldest first
+    recently_used: BTreeMap<u64, MemoKey>,
+    tick: u64,
+}
+
+impl MemoCache {
+    fn get(&mut self, key: &MemoKey) -> Option<Value> {
+        self.tick += 1;
+        let (value, last_used) = self.values.get_mut(key)?;
+        self.recently_used.remove(last_used);
+        *last_used = self.tick;
+        self.recently_used.insert(self.tick, *key);
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: MemoKey, value: Value) {
+        if self.values.len() >= MEMO_CAPACITY {
+            if let Some((_, oldest)) = self.recently_used.pop_first() {
+                self.values.remove(&oldest);
+            }
+        }
+        self.tick += 1;
+        if let Some((_, last_used)) = self.values.insert(key, (value, self.tick)) {
+            self.recently_used.remove(&last_used);
+        }
+        self.recently_used.insert(self.tick, key);
+    }
+}
+
+/// an attribute which value is computed by the closure `f`, which keeps the expression
+/// which computes the value out of the attributes of the element.
+///
+/// The closure is called right away when the attribute is created, this is not deferred.
+/// Use [`attr_memo`] to skip computing the same value again.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::attributes::attr_lazy, *};
+///
+/// let scores = [3, 5, 8];
+/// let view: Node<()> = div(
+///     [attr_lazy("data-total", || scores.iter().sum::<i32>())],
+///     [],
+/// );
+/// assert_eq!(view.render_to_string(), r#"<div data-total="16"></div>"#);
+/// ```
+pub fn attr_lazy<MSG, F, V>(name: AttributeName, f: F) -> Attribute<MSG>
+where
+    F: FnOnce() -> V,
+    V: Into<Value>,
+{
+    attr(name, f().into())
+}
+
+/// an attribute which value is computed by the closure `f` only when the `key` changes,
+/// otherwise the value which was computed for the same `key` is reused.
+///
+/// The `key` should be made of everything the value is computed from,
+/// such as the id and the version of an item.
+/// The values are memoized for each place in the code where `attr_memo` is called and
+/// for each attribute name, so the same key in different places doesn't share the values.
+/// The closure is called right away when there is no value for the key.
+/// Once there are more than a thousand memoized values, the least recently used one is evicted.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::attributes::attr_memo, *};
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let view = |version: u32| -> Node<()> {
+///     div(
+///         [attr_memo(version, "title", || {
+///             calls.set(calls.get() + 1);
+///             format!("version {version}")
+///         })],
+///         [],
+///     )
+/// };
+/// assert_eq!(view(1).render_to_string(), r#"<div title="version 1"></div>"#);
+/// assert_eq!(view(1).render_to_string(), r#"<div title="version 1"></div>"#);
+/// assert_eq!(calls.get(), 1);
+/// assert_eq!(view(2).render_to_string(), r#"<div title="version 2"></div>"#);
+/// assert_eq!(calls.get(), 2);
+/// ```
+#[track_caller]
+pub fn attr_memo<MSG, K, F, V>(key: K, name: AttributeName, f: F) -> Attribute<MSG>
+where
+    K: Hash,
+    F: FnOnce() -> V,
+    V: Into<Value>,
+{
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    let memo_key = (Location::caller(), name, hasher.finish());
+    let memoized = MEMO.with_borrow_mut(|memo| memo.get(&memo_key));
+    let value = memoized.unwrap_or_else(|| {
+        let value: Value = f().into();
+        MEMO.with_borrow_mut(|memo| memo.insert(memo_key, value.clone()));
+        value
+    });
+    attr(name, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memo_key(key: u64) -> MemoKey {
+        (Location::caller(), "title", key)
+    }
+
+    #[test]
+    fn least_recently_used_value_is_evicted() {
+        let mut memo = MemoCache::default();
+        for key in 0..MEMO_CAPACITY as u64 {
+            memo.insert(memo_key(key), Value::from(key));
+        }
+        // the first value is used again, so the second one is now the least recently used
+        assert_eq!(memo.get(&memo_key(0)), Some(Value::from(0u64)));
+        memo.insert(memo_key(MEMO_CAPACITY as u64), Value::from("new"));
+
+        assert_eq!(memo.values.len(), MEMO_CAPACITY);
+        assert_eq!(memo.get(&memo_key(0)), Some(Value::from(0u64)));
+        assert_eq!(memo.get(&memo_key(1)), None);
+        assert_eq!(memo.get(&memo_key(2)), Some(Value::from(2u64)));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::vdom::{attr, Attribute};
+use std::fmt;
+
+/// The descriptor of an image candidate in a `srcset`, see [`srcset_entries`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SrcsetDescriptor {
+    /// the intrinsic width of the image in pixels, rendered as `640w`
+    Width(u32),
+    /// the pixel density the image is meant for, rendered as `2x`
+    Density(f32),
+}
+
+impl fmt::Display for SrcsetDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Width(width) => write!(f, "{width}w"),
+            Self::Density(density) => write!(f, "{density}x"),
+        }
+    }
+}
+
+/// Creates a `srcset` attribute from the image candidates, each of which is a url and its
+/// descriptor, such that `[("small.jpg", Width(480)), ("large.jpg", Width(1080))]`
+/// is rendered as `small.jpg 480w, large.jpg 1080w`.
+///
+/// The candidates should either all be widths, which are paired with [`sizes_entries`],
+/// or all be densities. Mixing them is invalid html and the browser ignores the `srcset`,
+/// so a warning is logged in debug builds when it happens.
+/// The urls must not contain spaces or commas, encode them as `%20` and `%2C` instead.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{
+///     html::attributes::{sizes_entries, srcset_entries, SrcsetDescriptor::*},
+///     *,
+/// };
+///
+/// let view: Node<()> = img([
+///     srcset_entries([("small.jpg", Width(480)), ("large.jpg", Width(1080))]),
+///     sizes_entries([("(max-width: 600px)", "480px")], "1080px"),
+/// ]);
+/// assert_eq!(
+///     view.render_to_string(),
+///     r#"<img srcset="small.jpg 480w, large.jpg 1080w" sizes="(max-width: 600px) 480px, 1080px"/>"#
+/// );
+///
+/// let logo: Node<()> = img([srcset_entries([("logo.png", Density(1.0)), ("logo@2x.png", Density(2.0))])]);
+/// assert_eq!(logo.render_to_string(), r#"<img srcset="logo.png 1x, logo@2x.png 2x"/>"#);
+/// ```
+pub fn srcset_entries<MSG>(
+    entries: impl IntoIterator<Item = (impl ToString, SrcsetDescriptor)>,
+) -> Attribute<MSG> {
+    let entries: Vec<(String, SrcsetDescriptor)> = entries
+        .into_iter()
+        .map(|(url, descriptor)| (url.to_string(), descriptor))
+        .collect();
+    #[cfg(debug_assertions)]
+    {
+        let has_width = entries
+            .iter()
+            .any(|(_, descriptor)| matches!(descriptor, SrcsetDescriptor::Width(_)));
+        let has_density = entries
+            .iter()
+            .any(|(_, descriptor)| matches!(descriptor, SrcsetDescriptor::Density(_)));
+        if has_width && has_density {
+            log::warn!(
+                "srcset mixes width and density descriptors, which is ignored by the browser"
+            );
+        }
+    }
+    let srcset = entries
+        .iter()
+        .map(|(url, descriptor)| format!("{url} {descriptor}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    attr("srcset", srcset)
+}
+
+/// Creates a `sizes` attribute from the media conditions and the display width of the image
+/// when the condition matches, followed by the `default` display width,
+/// such that `[("(max-width: 600px)", "480px")]` and `"800px"` is rendered as
+/// `(max-width: 600px) 480px, 800px`. See [`srcset_entries`] for an example.
+pub fn sizes_entries<MSG>(
+    entries: impl IntoIterator<Item = (impl ToString, impl ToString)>,
+    default: impl ToString,
+) -> Attribute<MSG> {
+    let sizes = entries
+        .into_iter()
+        .map(|(media, size)| format!("{} {}", media.to_string(), size.to_string()))
+        .chain([default.to_string()])
+        .collect::<Vec<_>>()
+        .join(", ");
+    attr("sizes", sizes)
+}
@@ -1,7 +1,7 @@
 //! Provides list of HTML and SVG tags, style properties
 use crate::{
     html::{
-        attributes::{HTML_ATTRS, HTML_ATTRS_SPECIAL},
+        attributes::{HTML_ATTRS, HTML_ATTRS_SPECIAL, HTML_ATTRS_TYPED},
         tags::{
             commons::HTML_TAGS, self_closing::HTML_SC_TAGS, HTML_TAGS_NON_COMMON,
             HTML_TAGS_WITH_MACRO_NON_COMMON,
@@ -38,6 +38,36 @@ static ALL_HTML_TAGS: Lazy<BTreeSet<&&'static str>> = Lazy::new(|| {
         .collect()
 });
 
+/// The [boolean attributes](https://html.spec.whatwg.org/multipage/indices.html#attributes-3)
+/// of html, where the presence of the attribute is what matters and not its value
+const BOOLEAN_ATTRIBUTES: [&str; 25] = [
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "inert",
+    "ismap",
+    "itemscope",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "selected",
+];
+
 static SELF_CLOSING_TAGS: Lazy<BTreeSet<&&'static str>> =
     Lazy::new(|| HTML_SC_TAGS.iter().collect());
 
@@ -45,6 +75,7 @@ static ALL_ATTRS: Lazy<BTreeMap<&'static str, &'static str>> = Lazy::new(|| {
     BTreeMap::from_iter(
         HTML_ATTRS
             .iter()
+            .chain(HTML_ATTRS_TYPED.iter())
             .chain(SVG_ATTRS.iter())
             .map(|att| (*att, *att))
             .chain(
@@ -111,3 +142,24 @@ pub fn match_tag(tag: &str) -> Option<&'static str> {
 pub fn is_self_closing(tag: &str) -> bool {
     SELF_CLOSING_TAGS.contains(&tag)
 }
+
+/// Returns true if this is a boolean attribute of html such as `disabled` or `required`,
+/// which is meaningful by its mere presence regardless of its value
+pub fn is_boolean_attribute(att: &str) -> bool {
+    match_boolean_attribute(att).is_some()
+}
+
+/// return the matching boolean attribute, see [`is_boolean_attribute`]
+pub fn match_boolean_attribute(att: &str) -> Option<&'static str> {
+    BOOLEAN_ATTRIBUTES.iter().find(|v| **v == att).copied()
+}
+
+/// Returns true if this is an html tag, including the tags which are also svg tags such as `a`
+pub fn is_html_tag(tag: &str) -> bool {
+    ALL_HTML_TAGS.contains(&tag)
+}
+
+/// Returns true if this is an svg tag, including the tags which are also html tags such as `a`
+pub fn is_svg_tag(tag: &str) -> bool {
+    ALL_SVG_TAGS.contains(&tag)
+}
@@ -13,7 +13,7 @@ macro_rules! declare_tags {
             $(#[$attr])*
             #[inline]
             #[allow(non_snake_case)]
-            pub fn $name<MSG>(attrs: impl IntoIterator<Item = $crate::vdom::Attribute<MSG>>, children: impl IntoIterator<Item = $crate::vdom::Node<MSG>>) -> $crate::vdom::Node<MSG>
+            pub fn $name<MSG>(attrs: impl IntoIterator<Item = $crate::vdom::Attribute<MSG>>, children: impl $crate::vdom::IntoChildren<MSG>) -> $crate::vdom::Node<MSG>
                 {
                     $crate::html::html_element(None, stringify!($name), attrs, children, false)
                 }
@@ -23,7 +23,8 @@ macro_rules! declare_tags {
     }
 }
 
-/// declare self closing tags
+/// declare self closing tags, which are the void elements that can not have children,
+/// so these only take the attributes
 macro_rules! declare_sc_tags {
     ( $(
          $(#[$attr:meta])*
@@ -33,14 +34,14 @@ macro_rules! declare_sc_tags {
 
         $(
             doc_comment!{
-                concat!("Creates an html [",stringify!($name),"](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/",stringify!($name),") element"),
+                concat!("Creates an html [",stringify!($name),"](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/",stringify!($name),") element, which is a void element that has no children"),
 
             $(#[$attr])*
             #[inline]
             #[allow(non_snake_case)]
-            pub fn $name<MSG>(attrs: impl IntoIterator<Item = $crate::vdom::Attribute<MSG>>, children: impl IntoIterator<Item = $crate::vdom::Node<MSG>>) -> $crate::vdom::Node<MSG>
+            pub fn $name<MSG>(attrs: impl IntoIterator<Item = $crate::vdom::Attribute<MSG>>) -> $crate::vdom::Node<MSG>
                 {
-                    $crate::html::html_element(None, stringify!($name), attrs, children, true)
+                    $crate::html::html_element(None, stringify!($name), attrs, Vec::<$crate::vdom::Node<MSG>>::new(), true)
                 }
             }
 
@@ -218,7 +219,8 @@ declare_tags_and_macro_non_common! {
     data;  // data for local variable is commonly used everywhere
 }
 
-/// self closing tags
+/// self closing tags, such as `img([src("logo.png")])`, which only take the attributes
+/// since they are void elements which can not have children.
 pub mod self_closing {
     // self closing tags such as `<input/>, `<br/>`
     declare_sc_tags! {
@@ -0,0 +1,161 @@
+//! A scrollable list which only creates the nodes of the visible items
+use crate::dom::events::{on_mount, on_scroll};
+use crate::html::attributes::{key, styles};
+use crate::html::div;
+use crate::html::units::px;
+use crate::vdom::{Attribute, Node};
+use std::ops::Range;
+use std::rc::Rc;
+
+/// The number of items rendered above and below the visible items by default
+pub const DEFAULT_OVERSCAN: usize = 5;
+
+/// The dimensions and the scroll position of a [`virtual_list`].
+///
+/// Only lists where all the items have the same height are supported,
+/// since the position of each item is computed from its index.
+/// Items with a varying content should be clipped or truncated to `item_height`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualListConfig {
+    /// the total number of items of the list
+    pub item_count: usize,
+    /// the height of each item in pixels
+    pub item_height: f64,
+    /// the height of the scrollable viewport of the list in pixels
+    pub viewport_height: f64,
+    /// the current scroll position of the list in pixels,
+    /// which is kept in the model and updated from the scroll msg of the list
+    pub scroll_top: f64,
+    /// the number of items rendered above and below the visible items,
+    /// so the items are already there when scrolling fast
+    pub overscan: usize,
+}
+
+impl VirtualListConfig {
+    /// a list of `item_count` items of `item_height` in a viewport of `viewport_height`,
+    /// scrolled to the top
+    pub fn new(item_count: usize, item_height: f64, viewport_height: f64) -> Self {
+        Self {
+            item_count,
+            item_height,
+            viewport_height,
+            scroll_top: 0.0,
+            overscan: DEFAULT_OVERSCAN,
+        }
+    }
+
+    /// set the current scroll position of the list
+    pub fn with_scroll_top(mut self, scroll_top: f64) -> Self {
+        self.scroll_top = scroll_top;
+        self
+    }
+
+    /// set the number of items rendered above and below the visible items
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// the indexes of the items which are rendered at the current scroll position,
+    /// which are the visible items and the overscan around them
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sauron::html::VirtualListConfig;
+    ///
+    /// let config = VirtualListConfig::new(100_000, 20.0, 200.0).with_scroll_top(1000.0);
+    /// assert_eq!(config.visible_range(), 45..65);
+    /// ```
+    pub fn visible_range(&self) -> Range<usize> {
+        if self.item_height <= 0.0 {
+            return 0..0;
+        }
+        let first_visible = (self.scroll_top.max(0.0) / self.item_height).floor() as usize;
+        let visible_count = (self.viewport_height / self.item_height).ceil() as usize;
+        let start = first_visible
+            .saturating_sub(self.overscan)
+            .min(self.item_count);
+        let end = (first_visible + visible_count + self.overscan).min(self.item_count);
+        start..end
+    }
+
+    /// the height of all the items
+    pub fn total_height(&self) -> f64 {
+        self.item_count as f64 * self.item_height
+    }
+}
+
+/// Creates a scrollable list which only creates the nodes of the items in the
+/// [visible range](VirtualListConfig::visible_range), such that a list with a huge number of
+/// items stays fast. The items are absolutely positioned inside a spacer which has the height
+/// of all the items, so the scrollbar reflects the whole list.
+///
+/// `on_scroll` is called with the new scroll position, which should be stored in the model
+/// and passed back as [`VirtualListConfig::scroll_top`] so the view renders the items at that
+/// position. When the list is created again, such as when switching back to a tab,
+/// it is scrolled to the `scroll_top` of the config, restoring the scroll position.
+///
+/// `render_item` is called with the index of each of the rendered items.
+/// # Example
+/// ```rust,ignore
+/// let config = VirtualListConfig::new(self.rows.len(), 24.0, 480.0).with_scroll_top(self.scroll_top);
+/// virtual_list(
+///     config,
+///     [class("rows")],
+///     Msg::Scrolled,
+///     |index| text(&self.rows[index]),
+/// )
+/// ```
+pub fn virtual_list<MSG, F, R>(
+    config: VirtualListConfig,
+    attrs: impl IntoIterator<Item = Attribute<MSG>>,
+    on_scroll_top: F,
+    render_item: R,
+) -> Node<MSG>
+where
+    MSG: 'static,
+    F: Fn(f64) -> MSG + 'static,
+    R: Fn(usize) -> Node<MSG>,
+{
+    let on_scroll_top = Rc::new(on_scroll_top);
+    let on_mount_scroll_top = Rc::clone(&on_scroll_top);
+    let scroll_top = config.scroll_top;
+    let items = config.visible_range().map(|index| {
+        div(
+            [
+                key(index),
+                styles([
+                    ("position", "absolute".to_string()),
+                    ("top", px(index as f64 * config.item_height)),
+                    ("left", "0".to_string()),
+                    ("right", "0".to_string()),
+                    ("height", px(config.item_height)),
+                ]),
+            ],
+            [render_item(index)],
+        )
+    });
+    div(
+        attrs.into_iter().chain([
+            styles([
+                ("height", px(config.viewport_height)),
+                ("overflow-y", "auto".to_string()),
+            ]),
+            on_scroll(move |(top, _left)| on_scroll_top(f64::from(top))),
+            on_mount(move |mount| {
+                mount
+                    .target_node
+                    .as_element()
+                    .set_scroll_top(scroll_top as i32);
+                on_mount_scroll_top(scroll_top)
+            }),
+        ]),
+        [div(
+            [styles([
+                ("position", "relative".to_string()),
+                ("height", px(config.total_height())),
+            ])],
+            items,
+        )],
+    )
+}
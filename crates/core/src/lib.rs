@@ -21,7 +21,8 @@ pub mod prelude {
         attributes::commons::*,
         attributes::key,
         attributes::{
-            attr, checked, class, classes, classes_flag, disabled, empty_attr, r#type, styles_flag,
+            attr, autocomplete, checked, class, classes, classes_flag, disabled, empty_attr,
+            inputmode, r#type, spellcheck, styles_flag, tabindex,
         },
         br, comment,
         commons::*,
@@ -49,10 +50,10 @@ pub mod prelude {
         pub use wasm_bindgen::prelude::*;
         pub use serde_wasm_bindgen;
         pub use crate::html::events::*;
-        pub use crate::dom::{Application, SkipDiff, skip_if, events, Program, document, Document, now, window, Window, Dispatch,
+        pub use crate::dom::{Application, Embedded, SkipDiff, skip_if, events, Program, document, Document, now, window, Window, Dispatch,
             AnimationFrameHandle, Component, StatefulComponent, Effects, Measurements, MountAction,
             MountTarget, Cmd, TimeoutCallbackHandle, DomAttrValue,
-            stateful_component, Time,
+            stateful_component, Time, use_context, RectInfo,
         };
     }}
 }
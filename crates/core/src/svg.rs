@@ -25,7 +25,7 @@ pub const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
 pub fn svg_element<MSG>(
     tag: &'static str,
     attrs: impl IntoIterator<Item = vdom::Attribute<MSG>>,
-    children: impl IntoIterator<Item = vdom::Node<MSG>>,
+    children: impl vdom::IntoChildren<MSG>,
 ) -> vdom::Node<MSG> {
     crate::html::html_element(Some(SVG_NAMESPACE), tag, attrs, children, false)
 }
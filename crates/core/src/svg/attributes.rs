@@ -19,9 +19,9 @@ macro_rules! declare_xlink_attributes {
             #[inline]
             #[allow(non_snake_case)]
             pub fn $name<V, MSG>(v: V) -> crate::vdom::Attribute<MSG>
-                where V: Into<Value>,
+                where V: Into<AttributeValue<MSG>>,
                 {
-                    attr_ns(Some(XLINK_NAMESPACE), $attribute, AttributeValue::from(v.into()))
+                    attr_ns(Some(XLINK_NAMESPACE), $attribute, v)
                 }
          )*
 
@@ -31,6 +31,107 @@ macro_rules! declare_xlink_attributes {
     }
 }
 
+/// The commonly used svg presentation attributes, which can be applied at once
+/// with [`presentation`] instead of listing them one by one.
+///
+/// Presentation attributes have the lowest precedence: any css rule that applies to the element,
+/// including the `style` attribute and the inherited properties, overrides them.
+/// This makes them suitable for the defaults which a stylesheet can still override.
+/// # Examples
+/// ```rust
+/// use sauron::{*, svg::*, svg::attributes::{presentation, Presentation}};
+///
+/// let line_style = Presentation {
+///     stroke: Some("steelblue".into()),
+///     stroke_width: Some(2.into()),
+///     fill: Some("none".into()),
+///     ..Default::default()
+/// };
+/// let path: Node<()> = path(presentation(&line_style), []);
+/// assert_eq!(
+///     path.render_to_string(),
+///     r#"<path fill="none" stroke="steelblue" stroke-width="2"></path>"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Presentation {
+    /// `fill`, the paint of the interior of the shape
+    pub fill: Option<Value>,
+    /// `fill-opacity`
+    pub fill_opacity: Option<Value>,
+    /// `fill-rule`, either `nonzero` or `evenodd`
+    pub fill_rule: Option<Value>,
+    /// `stroke`, the paint of the outline of the shape
+    pub stroke: Option<Value>,
+    /// `stroke-width`
+    pub stroke_width: Option<Value>,
+    /// `stroke-opacity`
+    pub stroke_opacity: Option<Value>,
+    /// `stroke-dasharray`
+    pub stroke_dasharray: Option<Value>,
+    /// `stroke-linecap`, one of `butt`, `round` or `square`
+    pub stroke_linecap: Option<Value>,
+    /// `stroke-linejoin`, one of `miter`, `round` or `bevel`
+    pub stroke_linejoin: Option<Value>,
+    /// `opacity` of the whole element
+    pub opacity: Option<Value>,
+    /// `font-family`
+    pub font_family: Option<Value>,
+    /// `font-size`
+    pub font_size: Option<Value>,
+    /// `font-weight`
+    pub font_weight: Option<Value>,
+    /// `text-anchor`, one of `start`, `middle` or `end`
+    pub text_anchor: Option<Value>,
+    /// `dominant-baseline`
+    pub dominant_baseline: Option<Value>,
+    /// `visibility`
+    pub visibility: Option<Value>,
+}
+
+/// the attributes of the presentation which are set, in the order of the fields
+pub fn presentation<MSG>(presentation: &Presentation) -> Vec<crate::vdom::Attribute<MSG>> {
+    let Presentation {
+        fill,
+        fill_opacity,
+        fill_rule,
+        stroke,
+        stroke_width,
+        stroke_opacity,
+        stroke_dasharray,
+        stroke_linecap,
+        stroke_linejoin,
+        opacity,
+        font_family,
+        font_size,
+        font_weight,
+        text_anchor,
+        dominant_baseline,
+        visibility,
+    } = presentation;
+    [
+        ("fill", fill),
+        ("fill-opacity", fill_opacity),
+        ("fill-rule", fill_rule),
+        ("stroke", stroke),
+        ("stroke-width", stroke_width),
+        ("stroke-opacity", stroke_opacity),
+        ("stroke-dasharray", stroke_dasharray),
+        ("stroke-linecap", stroke_linecap),
+        ("stroke-linejoin", stroke_linejoin),
+        ("opacity", opacity),
+        ("font-family", font_family),
+        ("font-size", font_size),
+        ("font-weight", font_weight),
+        ("text-anchor", text_anchor),
+        ("dominant-baseline", dominant_baseline),
+        ("visibility", visibility),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| value.clone().map(|value| attr(name, value)))
+    .collect()
+}
+
 /// declare svg attributes, at the same time fill up the
 /// SVG_ATTR const with all the common svg attributes
 macro_rules! declare_svg_attributes{
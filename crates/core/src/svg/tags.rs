@@ -13,7 +13,7 @@ macro_rules! declare_svg_tags{
                 $(#[$attr])*
                 #[inline]
                 #[allow(non_snake_case)]
-                pub fn $name<MSG>(attrs: impl IntoIterator<Item = $crate::vdom::Attribute<MSG>>, children: impl IntoIterator<Item = $crate::vdom::Node<MSG>>) -> $crate::vdom::Node<MSG>
+                pub fn $name<MSG>(attrs: impl IntoIterator<Item = $crate::vdom::Attribute<MSG>>, children: impl $crate::vdom::IntoChildren<MSG>) -> $crate::vdom::Node<MSG>
                     {
                         $crate::svg::svg_element(stringify!($name), attrs, children)
                 }
@@ -33,7 +33,7 @@ macro_rules! declare_svg_tags{
                 $(#[$attr])*
                 #[inline]
                 #[allow(non_snake_case)]
-                pub fn $name<MSG>(attrs: impl IntoIterator<Item = $crate::vdom::Attribute<MSG>>, children: impl IntoIterator<Item = $crate::vdom::Node<MSG>>) -> $crate::vdom::Node<MSG>
+                pub fn $name<MSG>(attrs: impl IntoIterator<Item = $crate::vdom::Attribute<MSG>>, children: impl $crate::vdom::IntoChildren<MSG>) -> $crate::vdom::Node<MSG>
                     {
                         $crate::svg::svg_element($tagname, attrs, children)
                  }
@@ -8,30 +8,53 @@
 use crate::dom::Event;
 pub use attribute::Attribute;
 pub use attribute::Callback;
+pub use attribute::EventOptions;
 pub use attribute::GroupedAttributeValues;
+pub use children::IntoChildren;
+pub use debug_node::DebugNode;
 pub use element::Element;
 pub use leaf::Leaf;
+pub use snapshot::{SerializableAttribute, SerializableElement, SerializableNode};
+pub use suspense::{suspense, Suspense, SuspenseTask, SuspenseView};
 pub use templated_view::TemplatedView;
 
 mod attribute;
+mod children;
+mod debug_node;
 mod element;
 mod leaf;
 mod map_msg;
 mod render;
+mod renderer;
+mod sanitize;
+mod snapshot;
+mod suspense;
 mod templated_view;
 
 pub use attribute::special::{
-    key, replace, skip, skip_criteria, KEY, REPLACE, SKIP, SKIP_CRITERIA,
+    key, portal_target, replace, skip, skip_criteria, KEY, PORTAL_TARGET, REPLACE, SKIP,
+    SKIP_CRITERIA,
 };
 #[cfg(feature = "ensure-attr-set")]
 pub(crate) use attribute::special::{CHECKED, DISABLED, OPEN, VALUE};
-pub use attribute::{attr, attr_ns, AttributeName, AttributeValue, Namespace, Style, Tag, Value};
-pub use diff::{diff, diff_recursive};
-pub use node::{element, element_ns, fragment, leaf, node_list, Node};
+pub use attribute::{
+    attr, attr_ns, AttributeKey, AttributeName, AttributeValue, Color, Namespace, ParseColorError,
+    Style, Tag, Value,
+};
+pub use diff::{diff, diff_recursive, diff_with_options, DiffOptions};
+pub use diff_pretty::diff_pretty;
+pub use node::{element, element_ns, fragment, leaf, node_list, shared, Node};
 pub use patch::{Patch, PatchType, TreePath};
+pub use render::{IndentStyle, SerializeOptions};
+pub use renderer::{apply_patches, RenderPatch, Renderer};
+pub(crate) use sanitize::entity_len;
+pub use sanitize::{
+    Sanitizer, DEFAULT_ALLOWED_URL_SCHEMES, DEFAULT_BLOCKED_TAGS, DEFAULT_URL_ATTRIBUTES,
+};
 
 pub mod diff;
 mod diff_lis;
+mod diff_pretty;
 mod node;
 pub mod patch;
 
@@ -6,12 +6,14 @@ use derive_where::derive_where;
 use indexmap::IndexMap;
 
 pub use attribute_value::AttributeValue;
-pub use callback::Callback;
+pub use callback::{Callback, EventOptions};
+pub use color::{Color, ParseColorError};
 pub use style::Style;
 pub use value::Value;
 
 mod attribute_value;
 pub mod callback;
+mod color;
 pub(crate) mod special;
 mod style;
 mod value;
@@ -25,6 +27,10 @@ pub type Tag = &'static str;
 /// The type of Attribute Name
 pub type AttributeName = &'static str;
 
+/// The identity of an attribute, which is its namespace together with its name.
+/// A namespaced attribute such as `xlink:href` is distinct from a plain `href`
+pub type AttributeKey = (Option<Namespace>, AttributeName);
+
 /// These are the plain attributes of an element
 #[derive_where(Clone, Debug, PartialEq, Eq)]
 pub struct Attribute<MSG> {
@@ -93,6 +99,11 @@ impl<MSG> Attribute<MSG> {
         self.namespace.as_ref()
     }
 
+    /// return the identity of this attribute, which is its namespace and name
+    pub fn key(&self) -> AttributeKey {
+        (self.namespace, self.name)
+    }
+
     /// returns true if this attribute is an event listener
     pub fn is_event_listener(&self) -> bool {
         self.value
@@ -132,7 +143,7 @@ impl<MSG> Attribute<MSG> {
         }
     }
 
-    fn is_just_empty(&self) -> bool {
+    pub(crate) fn is_just_empty(&self) -> bool {
         self.value
             .first()
             .map(|av| av.is_just_empty())
@@ -143,7 +154,7 @@ impl<MSG> Attribute<MSG> {
         self.name == "mount"
     }
 
-    /// merge the values of attributes with the same name
+    /// merge the values of attributes with the same name and namespace
     /// also exclude the empty attribute
     pub fn merge_attributes_of_same_name<'a>(
         attributes: impl IntoIterator<Item = &'a Attribute<MSG>> + Iterator,
@@ -151,15 +162,15 @@ impl<MSG> Attribute<MSG> {
     where
         MSG: 'a,
     {
-        let mut merged: IndexMap<&AttributeName, Attribute<MSG>> =
+        let mut merged: IndexMap<AttributeKey, Attribute<MSG>> =
             IndexMap::with_capacity(attributes.size_hint().0);
         for att in attributes.into_iter() {
             if !att.is_just_empty() {
-                if let Some(existing) = merged.get_mut(&att.name) {
+                if let Some(existing) = merged.get_mut(&att.key()) {
                     existing.value.extend(att.value.clone());
                 } else {
                     merged.insert(
-                        &att.name,
+                        att.key(),
                         Attribute {
                             namespace: att.namespace,
                             name: att.name,
@@ -55,12 +55,41 @@ where
     }
 }
 
+/// `None` becomes an empty value, which is not rendered and
+/// removes the attribute when diffed against a previous `Some` value
+impl<MSG, V> From<Option<V>> for AttributeValue<MSG>
+where
+    V: Into<Value>,
+{
+    fn from(v: Option<V>) -> Self {
+        match v {
+            Some(v) => Self::Simple(v.into()),
+            None => Self::Empty,
+        }
+    }
+}
+
 impl<MSG> AttributeValue<MSG> {
     /// create an attribute from Vec<Style>
     pub fn from_styles(styles: impl IntoIterator<Item = Style>) -> Self {
         Self::Style(styles.into_iter().collect())
     }
 
+    /// returns true if the values are equal, where the float values and the float values of
+    /// the styles are equal if they differ by no more than `epsilon`, see [`Value::approx_eq`]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (Self::Simple(this), Self::Simple(other)) => this.approx_eq(other, epsilon),
+            (Self::Style(this), Self::Style(other)) => {
+                this.len() == other.len()
+                    && this.iter().zip(other).all(|(this, other)| {
+                        this.name == other.name && this.value.approx_eq(&other.value, epsilon)
+                    })
+            }
+            _ => self == other,
+        }
+    }
+
     /// return the value if it is a Simple variant
     pub fn get_simple(&self) -> Option<&Value> {
         match self {
@@ -1,6 +1,13 @@
 //! Callbacks contains function that can be called at a later time.
 //! This is used in containing an event listener attached to an DOM element.
-use std::{any::TypeId, cell::RefCell, fmt, rc::Rc};
+use std::{
+    any::TypeId,
+    cell::RefCell,
+    collections::hash_map::DefaultHasher,
+    fmt,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
 
 /// A generic sized representation of a function that can be
 /// attached to a Node. The callback will essentially be owned by the element
@@ -24,14 +31,46 @@ use std::{any::TypeId, cell::RefCell, fmt, rc::Rc};
 ///         callback is necessary.
 ///
 pub struct Callback<IN, OUT> {
-    /// the function to be executed
-    func: Rc<RefCell<dyn FnMut(IN) -> OUT>>,
+    /// the function to be executed, which returns `None` when it produces no output
+    func: Rc<RefCell<dyn FnMut(IN) -> Option<OUT>>>,
     /// the type_id of the function
     func_type_id: TypeId,
     /// the type type_id of the event this callback will be attached to
     event_type_id: TypeId,
     /// the type_id of the return type of this callback when executed.
     msg_type_id: TypeId,
+    /// how the callback is attached as an event listener
+    options: EventOptions,
+    /// the hash of the stable identity given with [`Callback::with_id`],
+    /// which is used in place of the func_type_id when comparing the callbacks
+    id: Option<u64>,
+    /// the identity of the closure which is created for this callback as an event listener,
+    /// it is the `id` combined with the msg mappers, see [`Callback::map_msg`]
+    pool_id: Option<u64>,
+}
+
+/// Options of how an event listener is attached and how the event is handled
+/// before the listener is called.
+/// The defaults are all `false`, which matches the defaults of `addEventListener`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EventOptions {
+    /// call `prevent_default` on the event before calling the listener
+    pub prevent_default: bool,
+    /// call `stop_propagation` on the event before calling the listener
+    pub stop_propagation: bool,
+    /// call `stop_immediate_propagation` on the event before calling the listener
+    pub stop_immediate_propagation: bool,
+    /// the listener never prevents the default action of the event, so the browser
+    /// doesn't have to wait for it before scrolling. Use this for `scroll`, `wheel` and
+    /// touch events. The browser ignores `prevent_default` on a passive listener
+    pub passive: bool,
+    /// the listener is called in the capturing phase, before the listeners of the descendants
+    pub capture: bool,
+    /// the msg of the listener is updated right away while the event is still being
+    /// dispatched, instead of being queued for the next update.
+    /// This is what allows `update` to call `prevent_default` on an `EventHandle`,
+    /// see `on_with_event`
+    pub dispatch_sync: bool,
 }
 
 impl<IN, F, OUT> From<F> for Callback<IN, OUT>
@@ -40,12 +79,15 @@ where
     OUT: 'static,
     IN: 'static,
 {
-    fn from(func: F) -> Self {
+    fn from(mut func: F) -> Self {
         Self {
-            func: Rc::new(RefCell::new(func)),
+            func: Rc::new(RefCell::new(move |input| Some(func(input)))),
             func_type_id: TypeId::of::<F>(),
             event_type_id: TypeId::of::<IN>(),
             msg_type_id: TypeId::of::<OUT>(),
+            options: EventOptions::default(),
+            id: None,
+            pool_id: None,
         }
     }
 }
@@ -70,11 +112,98 @@ where
     IN: 'static,
     OUT: 'static,
 {
+    /// create a callback which may produce no output, such as an event listener
+    /// which ignores the event in some cases
+    pub fn from_optional<F>(func: F) -> Self
+    where
+        F: FnMut(IN) -> Option<OUT> + 'static,
+    {
+        Self {
+            func: Rc::new(RefCell::new(func)),
+            func_type_id: TypeId::of::<F>(),
+            event_type_id: TypeId::of::<IN>(),
+            msg_type_id: TypeId::of::<OUT>(),
+            options: EventOptions::default(),
+            id: None,
+            pool_id: None,
+        }
+    }
+
+    /// create a callback with a stable identity `id`, so that the callbacks with the same `id`
+    /// are equal even when they are created from different closures,
+    /// and the callbacks of the same closure with different `id` are not equal.
+    ///
+    /// The diff doesn't replace an event listener with an equal one, so the listener which is
+    /// attached in the previous render is kept. The `id` should therefore be made of
+    /// everything the closure captures, such as the id of the item in a list.
+    /// For the same reason, the `Program` creates a single JS closure for the event listeners
+    /// with the same event name, `id` and options, which is shared by all the elements they are
+    /// attached to, such as a callback which reads the item from the event target in a long list.
+    /// Once mapped with [`map_msg`](Self::map_msg), the closure is only shared with the callbacks
+    /// which are mapped with the same function that captures nothing, such as `Msg::Left`.
+    ///
+    /// Otherwise, the callbacks are compared structurally, which is by the type of their closure,
+    /// and a callback with an `id` is never equal to a callback without one.
+    /// In both cases the type of the input and the output, and the options have to be the same.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sauron::*;
+    ///
+    /// let remove = |item: usize| -> Node<usize> {
+    ///     button([attr("click", EventCallback::with_id(("remove", item), move |_| item))], [])
+    /// };
+    /// assert!(diff(&remove(1), &remove(1)).is_empty());
+    /// assert!(!diff(&remove(1), &remove(2)).is_empty());
+    /// ```
+    pub fn with_id<F>(id: impl Hash, func: F) -> Self
+    where
+        F: FnMut(IN) -> OUT + 'static,
+    {
+        let mut hasher = DefaultHasher::new();
+        id.hash(&mut hasher);
+        let id = hasher.finish();
+        Self {
+            id: Some(id),
+            pool_id: Some(id),
+            ..Self::from(func)
+        }
+    }
+
     /// This method calls the actual callback.
+    ///
+    /// # Panics
+    /// when the callback is created with [`Callback::from_optional`] and produced no output,
+    /// use [`Callback::try_emit`] for such callbacks
     pub fn emit(&self, input: IN) -> OUT {
+        self.try_emit(input)
+            .expect("the callback produced no output, use `try_emit` instead")
+    }
+
+    /// call the actual callback, which returns `None` when it produced no output
+    pub fn try_emit(&self, input: IN) -> Option<OUT> {
         (self.func.borrow_mut())(input)
     }
 
+    /// set the options of how this callback is attached as an event listener
+    pub fn with_options(mut self, options: EventOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// the options of how this callback is attached as an event listener
+    pub fn options(&self) -> EventOptions {
+        self.options
+    }
+
+    /// the identity of the event listener closure of this callback, which can be shared
+    /// by the elements that have the callback.
+    /// This is `None` when the callback has no id, or when it is mapped with a closure
+    /// that captures some values, since two of such mappers can not be told apart
+    pub(crate) fn pool_id(&self) -> Option<u64> {
+        self.pool_id
+    }
+
     /// map this Callback msg such that `Callback<IN, OUT>` becomes `Callback<IN, MSG2>`
     /// Note: the original func_type_id is preserved here
     pub fn map_msg<F, MSG2>(self, cb2: F) -> Callback<IN, MSG2>
@@ -83,15 +212,27 @@ where
         MSG2: 'static,
     {
         let source_func_type_id = self.func_type_id;
-        let cb = move |input| {
-            let out = self.emit(input);
-            cb2(out)
-        };
+        let options = self.options;
+        let id = self.id;
+        // a mapper which captures nothing is identified by its type,
+        // such as `Msg::Left` and `Msg::Right` which are mapping the same child view
+        let pool_id = self
+            .pool_id
+            .filter(|_| std::mem::size_of::<F>() == 0)
+            .map(|pool_id| {
+                let mut hasher = DefaultHasher::new();
+                (pool_id, TypeId::of::<F>()).hash(&mut hasher);
+                hasher.finish()
+            });
+        let cb = move |input| self.try_emit(input).map(&cb2);
         Callback {
             func: Rc::new(RefCell::new(cb)),
             func_type_id: source_func_type_id,
             event_type_id: TypeId::of::<IN>(),
             msg_type_id: TypeId::of::<OUT>(),
+            options,
+            id,
+            pool_id,
         }
     }
 }
@@ -109,16 +250,26 @@ impl<IN, OUT> Clone for Callback<IN, OUT> {
             func_type_id: self.func_type_id,
             event_type_id: self.event_type_id,
             msg_type_id: self.msg_type_id,
+            options: self.options,
+            id: self.id,
+            pool_id: self.pool_id,
         }
     }
 }
 
 /// Compare if the callbacks are equal
-/// Note, we are only comparing the type_id of the function, the input and the output
+/// Note, we are only comparing the type_id of the function, the input and the output,
+/// or the id of the callbacks which are created with [`Callback::with_id`]
 impl<IN, OUT> PartialEq for Callback<IN, OUT> {
     fn eq(&self, other: &Self) -> bool {
+        let is_same_func = match (self.id, other.id) {
+            (Some(id), Some(other_id)) => id == other_id,
+            (None, None) => self.func_type_id == other.func_type_id,
+            _ => false,
+        };
         self.event_type_id == other.event_type_id
             && self.msg_type_id == other.msg_type_id
-            && self.func_type_id == other.func_type_id
+            && is_same_func
+            && self.options == other.options
     }
 }
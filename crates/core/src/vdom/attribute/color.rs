@@ -0,0 +1,116 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A typed css color, which can be used as a value of attributes such as `fill` and `stroke`
+/// and of styles such as `color` and `background-color`.
+///
+/// An opaque color is rendered as a 6 digit hex such as `#ff8800`,
+/// while a translucent color is rendered as `rgba(255, 136, 0, 0.5)`.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{vdom::Color, *};
+///
+/// assert_eq!(Color::rgb(255, 136, 0).to_string(), "#ff8800");
+/// assert_eq!(Color::rgba(255, 136, 0, 0.5).to_string(), "rgba(255, 136, 0, 0.5)");
+/// assert_eq!(Color::hex("#f80").unwrap(), Color::rgb(255, 136, 0));
+///
+/// let node: Node<()> = div([style! {color: Color::rgb(255, 0, 0)}], []);
+/// assert_eq!(node.render_to_string(), r#"<div style="color:#ff0000;"></div>"#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: f32,
+}
+
+/// The error when parsing an invalid hex color, see [`Color::hex`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid hex color: {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+impl Color {
+    /// an opaque color from its red, green and blue components
+    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 1.0 }
+    }
+
+    /// a color from its red, green and blue components and its alpha,
+    /// the alpha is clamped from `0.0` (transparent) to `1.0` (opaque)
+    pub fn rgba(r: u8, g: u8, b: u8, a: f32) -> Self {
+        let a = if a.is_nan() { 1.0 } else { a.clamp(0.0, 1.0) };
+        Self { r, g, b, a }
+    }
+
+    /// parse a hex color in the form of `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`,
+    /// the short forms are expanded such that `#f80` is the same as `#ff8800`
+    pub fn hex(hex: &str) -> Result<Self, ParseColorError> {
+        let error = || ParseColorError {
+            input: hex.to_string(),
+        };
+        let digits = hex.strip_prefix('#').ok_or_else(error)?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+        let short = |i: usize| u8::from_str_radix(&digits[i..=i], 16).map(|v| v * 17);
+        let long = |i: usize| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16);
+        let components = match digits.len() {
+            3 | 4 => (0..digits.len()).map(short).collect::<Result<Vec<_>, _>>(),
+            6 | 8 => (0..digits.len() / 2)
+                .map(long)
+                .collect::<Result<Vec<_>, _>>(),
+            _ => return Err(error()),
+        }
+        .map_err(|_| error())?;
+        let alpha = components
+            .get(3)
+            .map(|a| f32::from(*a) / 255.0)
+            .unwrap_or(1.0);
+        Ok(Self::rgba(
+            components[0],
+            components[1],
+            components[2],
+            alpha,
+        ))
+    }
+
+    /// the red, green and blue components of this color
+    pub fn components(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+
+    /// the alpha of this color, from `0.0` (transparent) to `1.0` (opaque)
+    pub fn alpha(&self) -> f32 {
+        self.a
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::hex(s)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self { r, g, b, a } = self;
+        if *a == 1.0 {
+            write!(f, "#{r:02x}{g:02x}{b:02x}")
+        } else {
+            write!(f, "rgba({r}, {g}, {b}, {a})")
+        }
+    }
+}
@@ -23,6 +23,10 @@ pub static SKIP: &AttributeName = &"skip";
 /// The skip criteria attribute
 pub static SKIP_CRITERIA: &AttributeName = &"skip_criteria";
 
+/// NOTE: this is specific to sauron framework
+/// The css selector of the element where the portal is mounted into
+pub static PORTAL_TARGET: &AttributeName = &"portal_target";
+
 ///
 /// NOTE: The following attributes have special behaviour in the dom, the framework
 /// need to call the specific methods to reflect the state of this attribute to the element
@@ -86,3 +90,12 @@ where
 pub fn replace<MSG>(v: bool) -> Attribute<MSG> {
     attr(REPLACE, v)
 }
+
+/// the css selector of the element where the children of this element is mounted into,
+/// see [`crate::html::portal`]
+pub fn portal_target<V, MSG>(v: V) -> Attribute<MSG>
+where
+    V: Into<Value>,
+{
+    attr(PORTAL_TARGET, v.into())
+}
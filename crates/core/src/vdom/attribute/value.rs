@@ -1,3 +1,4 @@
+use crate::vdom::Color;
 use std::borrow::Cow;
 use std::fmt;
 
@@ -5,6 +6,14 @@ use std::fmt;
 /// This is needed since html attributes can have different value types
 /// such as checked(bool), name(String), tab_index(i32)
 /// Note: memory size of Value is 32 bytes, in comparison String is 24 bytes
+///
+/// When rendered, the integers are formatted in decimal without any separators,
+/// the floats are formatted with the shortest representation that round trips,
+/// such that `1.0` is rendered as `1` and `0.5` as `0.5`,
+/// bools are rendered as `true` or `false`, and colors are rendered as in [`Color`].
+///
+/// An optional value such as `attr("data-id", None::<u16>)` is converted into an empty attribute
+/// value instead, which is not rendered at all.
 #[derive(Debug, Clone)]
 pub enum Value {
     /// bool value
@@ -41,6 +50,8 @@ pub enum Value {
     F32(f32),
     /// f64 value
     F64(f64),
+    /// a css color
+    Color(Color),
 }
 
 impl Value {
@@ -54,6 +65,19 @@ impl Value {
         }
     }
 
+    /// returns true if the values are equal, where the float values are equal
+    /// if they differ by no more than `epsilon`
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (Self::F32(v), Self::F32(o)) => v == o || f64::from(v - o).abs() <= epsilon,
+            (Self::F64(v), Self::F64(o)) => v == o || (v - o).abs() <= epsilon,
+            (Self::Vec(v), Self::Vec(o)) => {
+                v.len() == o.len() && v.iter().zip(o).all(|(v, o)| v.approx_eq(o, epsilon))
+            }
+            _ => self == other,
+        }
+    }
+
     /// returns the bool value if this a Bool variant
     pub fn as_bool(&self) -> Option<bool> {
         match self {
@@ -82,6 +106,7 @@ impl Value {
             Self::Isize(v) => Some(*v as f32),
             Self::F32(v) => Some(*v),
             Self::F64(v) => Some(*v as f32),
+            Self::Color(_v) => None,
         }
     }
 
@@ -105,6 +130,7 @@ impl Value {
             Self::Isize(v) => Some(*v as f64),
             Self::F32(v) => Some(f64::from(*v)),
             Self::F64(v) => Some(*v),
+            Self::Color(_v) => None,
         }
     }
 
@@ -128,6 +154,7 @@ impl Value {
             Self::Isize(v) => Some(*v as i32),
             Self::F32(v) => Some(*v as i32),
             Self::F64(v) => Some(*v as i32),
+            Self::Color(_v) => None,
         }
     }
 
@@ -151,6 +178,7 @@ impl Value {
             Self::Isize(v) => Some(*v as i64),
             Self::F32(v) => Some(*v as i64),
             Self::F64(v) => Some(*v as i64),
+            Self::Color(_v) => None,
         }
     }
 
@@ -203,6 +231,7 @@ impl PartialEq for Value {
             (Self::Isize(v), Self::Isize(o)) => v == o,
             (Self::F32(v), Self::F32(o)) => v == o,
             (Self::F64(v), Self::F64(o)) => v == o,
+            (Self::Color(v), Self::Color(o)) => v == o,
             _ => false,
         }
     }
@@ -239,6 +268,7 @@ impl fmt::Display for Value {
             Self::Isize(v) => write!(f, "{}", v),
             Self::F32(v) => write!(f, "{}", v),
             Self::F64(v) => write!(f, "{}", v),
+            Self::Color(v) => write!(f, "{}", v),
         }
     }
 }
@@ -261,6 +291,18 @@ impl From<String> for Value {
     }
 }
 
+impl From<Cow<'static, str>> for Value {
+    fn from(v: Cow<'static, str>) -> Self {
+        Self::Cow(v)
+    }
+}
+
+impl From<char> for Value {
+    fn from(v: char) -> Self {
+        Self::Cow(v.to_string().into())
+    }
+}
+
 impl<T, const N: usize> From<[T; N]> for Value
 where
     T: Into<Value> + Clone,
@@ -299,6 +341,7 @@ impl_from!(i128 => I128);
 impl_from!(isize => Isize);
 impl_from!(f32 => F32);
 impl_from!(f64 => F64);
+impl_from!(Color => Color);
 
 impl<V0, V1> From<(V0, V1)> for Value
 where
@@ -0,0 +1,42 @@
+use crate::vdom::Node;
+
+/// The children of an element, which can be a single node, an `Option` of a node,
+/// or a list of nodes such as a `Vec`, an array or an iterator.
+///
+/// # Examples
+/// ```rust
+/// use sauron::*;
+///
+/// let is_admin = false;
+/// let view: Node<()> = div(
+///     [class("card")],
+///     [
+///         h1([], text("title")),
+///         p([], is_admin.then(|| text("admin"))),
+///         ul([], (1..=2).map(|i| li([], text(i)))),
+///     ],
+/// );
+/// assert_eq!(
+///     view.render_to_string(),
+///     r#"<div class="card"><h1>title</h1><p></p><ul><li>1</li><li>2</li></ul></div>"#
+/// );
+/// ```
+pub trait IntoChildren<MSG> {
+    /// convert into the list of child nodes
+    fn into_children(self) -> Vec<Node<MSG>>;
+}
+
+impl<MSG> IntoChildren<MSG> for Node<MSG> {
+    fn into_children(self) -> Vec<Node<MSG>> {
+        vec![self]
+    }
+}
+
+impl<MSG, I> IntoChildren<MSG> for I
+where
+    I: IntoIterator<Item = Node<MSG>>,
+{
+    fn into_children(self) -> Vec<Node<MSG>> {
+        self.into_iter().collect()
+    }
+}
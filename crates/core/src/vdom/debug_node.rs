@@ -0,0 +1,124 @@
+//! A simplified tree of a node for asserting on the view in tests
+use crate::vdom::{Node, SerializableElement, SerializableNode};
+
+/// A simplified tree of a node, which is compared with `assert_eq!` in tests,
+/// see [`Node::to_debug`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DebugNode {
+    /// an element with its attributes sorted by name, rendered as in html
+    Element {
+        /// the tag of the element
+        tag: String,
+        /// the name and the value of the attributes, sorted by name
+        attributes: Vec<(String, String)>,
+        /// the child nodes of the element
+        children: Vec<DebugNode>,
+    },
+    /// a text node, or an html entity such as `&nbsp;`
+    Text(String),
+    /// a comment node
+    Comment(String),
+    /// a doctype
+    DocType(String),
+    /// the nodes of a fragment or of a node list, this is only used at the root
+    /// since the fragments in the children are flattened into the children of the element
+    Fragment(Vec<DebugNode>),
+}
+
+impl DebugNode {
+    /// an element node, with the attributes given in any order
+    pub fn element<'a>(
+        tag: &str,
+        attributes: impl IntoIterator<Item = (&'a str, &'a str)>,
+        children: impl IntoIterator<Item = DebugNode>,
+    ) -> Self {
+        let mut attributes: Vec<(String, String)> = attributes
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        attributes.sort();
+        Self::Element {
+            tag: tag.to_string(),
+            attributes,
+            children: children.into_iter().collect(),
+        }
+    }
+
+    /// a text node
+    pub fn text(text: impl ToString) -> Self {
+        Self::Text(text.to_string())
+    }
+
+    fn push_flattened(self, nodes: &mut Vec<DebugNode>) {
+        match self {
+            Self::Fragment(children) => nodes.extend(children),
+            node => nodes.push(node),
+        }
+    }
+}
+
+impl From<SerializableNode> for DebugNode {
+    fn from(node: SerializableNode) -> Self {
+        match node {
+            SerializableNode::Element(element) => element.into(),
+            SerializableNode::Text(text) | SerializableNode::Symbol(text) => Self::Text(text),
+            SerializableNode::Comment(comment) => Self::Comment(comment),
+            SerializableNode::DocType(doctype) => Self::DocType(doctype),
+            SerializableNode::Fragment(nodes) => Self::Fragment(flatten(nodes)),
+        }
+    }
+}
+
+impl From<SerializableElement> for DebugNode {
+    fn from(element: SerializableElement) -> Self {
+        let mut attributes: Vec<(String, String)> = element
+            .attributes
+            .into_iter()
+            .map(|attr| (attr.name, attr.value))
+            .collect();
+        attributes.sort();
+        Self::Element {
+            tag: element.tag,
+            attributes,
+            children: flatten(element.children),
+        }
+    }
+}
+
+fn flatten(nodes: Vec<SerializableNode>) -> Vec<DebugNode> {
+    let mut flattened = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        DebugNode::from(node).push_flattened(&mut flattened);
+    }
+    flattened
+}
+
+impl<MSG> Node<MSG> {
+    /// Convert this node into a [`DebugNode`], which is a simplified tree of the view
+    /// for comparing it with `assert_eq!` in tests.
+    ///
+    /// This omits what can not be compared or doesn't affect the rendered html:
+    /// - the event listeners, and the attributes which only have event listeners such as `on_click`
+    /// - the namespaces of the elements and the attributes
+    /// - whether an element is self closing
+    /// - the fragments and the node lists in the children, their nodes are flattened into the children of the element
+    ///
+    /// The attributes of the same name are merged, and all the attributes are sorted by name,
+    /// so the order they are declared in the view doesn't matter.
+    /// Html entities are text nodes, and components are replaced with their view
+    /// except for stateful components which are a comment since their view lives in the DOM.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sauron::{vdom::DebugNode, *};
+    ///
+    /// let view: Node<()> = button([on_click(|_| ()), r#type("button"), class("save")], [text("save")]);
+    /// assert_eq!(
+    ///     view.to_debug(),
+    ///     DebugNode::element("button", [("class", "save"), ("type", "button")], [DebugNode::text("save")])
+    /// );
+    /// ```
+    pub fn to_debug(&self) -> DebugNode {
+        self.to_serializable().into()
+    }
+}
@@ -1,11 +1,10 @@
 //! provides diffing algorithm which returns patches
-use super::{diff_lis, Attribute, Element, Node, Patch, TreePath};
-use super::{Tag, KEY, REPLACE, SKIP, SKIP_CRITERIA};
+use super::{diff_lis, Attribute, AttributeValue, Element, Node, Patch, TreePath};
+use super::{Tag, KEY, PORTAL_TARGET, REPLACE, SKIP, SKIP_CRITERIA};
 use crate::dom::skip_diff::SkipAttrs;
 use crate::dom::SkipPath;
-use crate::vdom::AttributeValue;
 use crate::vdom::Leaf;
-use std::{cmp, mem};
+use std::{cmp, mem, rc::Rc};
 
 #[cfg(feature = "use-skipdiff")]
 static USE_SKIP_DIFF: bool = true;
@@ -51,12 +50,177 @@ static USE_SKIP_DIFF: bool = false;
 /// );
 /// ```
 pub fn diff<'a, MSG>(old_node: &'a Node<MSG>, new_node: &'a Node<MSG>) -> Vec<Patch<'a, MSG>> {
+    diff_with_options(old_node, new_node, &DiffOptions::default())
+}
+
+/// Options which controls how the nodes are compared when diffing
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DiffOptions {
+    /// The tolerance when comparing float attribute and style values, such that
+    /// `10.0000001` and `10.0` are treated as the same value and doesn't produce a patch.
+    /// This avoids patching the DOM on every frame when the coordinates of svg elements are
+    /// computed with rounding errors. The default `None` compares the values exactly.
+    ///
+    /// The values are compared against the previous view. A change which is within the
+    /// tolerance is not patched, so the `Program` keeps the previous value in its view with
+    /// [`keep_skipped_values`](Self::keep_skipped_values), such that the view matches the DOM and
+    /// a value which slowly moves in tiny steps is patched once it has moved away by more than
+    /// the tolerance from the value in the DOM, instead of staying in place forever.
+    pub float_epsilon: Option<f64>,
+}
+
+impl DiffOptions {
+    /// returns true if the attribute values are the same, within the float tolerance
+    fn is_same_values<MSG>(
+        &self,
+        old: &[AttributeValue<MSG>],
+        new: &[AttributeValue<MSG>],
+    ) -> bool {
+        match self.float_epsilon {
+            Some(epsilon) => {
+                old.len() == new.len()
+                    && old
+                        .iter()
+                        .zip(new.iter())
+                        .all(|(old, new)| old.approx_eq(new, epsilon))
+            }
+            None => old == new,
+        }
+    }
+
+    /// copy the values of the `old` node which are changed within the float tolerance
+    /// into the `new` node, since these changes are not patched and the DOM still has
+    /// the old values. The nodes are paired the same way as in the diff, the keyed children
+    /// by their key and the other children by their index.
+    pub fn keep_skipped_values<MSG>(&self, old: &Node<MSG>, new: &mut Node<MSG>) {
+        if let Some(epsilon) = self.float_epsilon {
+            keep_skipped_values(old, new, epsilon);
+        }
+    }
+}
+
+fn keep_skipped_values<MSG>(old: &Node<MSG>, new: &mut Node<MSG>, epsilon: f64) {
+    if let Node::Leaf(Leaf::Shared(old)) = old {
+        return keep_skipped_values(old, new, epsilon);
+    }
+    if should_replace(old, new) {
+        return;
+    }
+    match (old, new) {
+        (Node::Element(old), Node::Element(new)) => {
+            keep_skipped_attribute_values(&old.attrs, &mut new.attrs, epsilon);
+            keep_skipped_children_values(&old.children, &mut new.children, epsilon);
+        }
+        (Node::Leaf(Leaf::Fragment(old)), Node::Leaf(Leaf::Fragment(new))) => {
+            keep_skipped_children_values(old, new, epsilon);
+        }
+        (Node::Leaf(Leaf::StatelessComponent(old)), Node::Leaf(Leaf::StatelessComponent(new))) => {
+            keep_skipped_values(&old.view, &mut new.view, epsilon);
+        }
+        (Node::Leaf(Leaf::Suspense(old)), Node::Leaf(Leaf::Suspense(new)))
+            if old.task == new.task =>
+        {
+            keep_skipped_values(&old.view, &mut new.view, epsilon);
+        }
+        _ => (),
+    }
+}
+
+fn keep_skipped_children_values<MSG>(old: &[Node<MSG>], new: &mut [Node<MSG>], epsilon: f64) {
+    if is_any_keyed(old) || is_any_keyed(new) {
+        for (index, new_child) in new.iter_mut().enumerate() {
+            let Some(new_key) = new_child.attribute_value(KEY) else {
+                continue;
+            };
+            // the keys are usually at the same index, so it is checked first
+            let is_same_key =
+                |old_child: &&Node<MSG>| old_child.attribute_value(KEY).as_ref() == Some(&new_key);
+            let old_child = old
+                .get(index)
+                .filter(is_same_key)
+                .or_else(|| old.iter().find(is_same_key));
+            if let Some(old_child) = old_child {
+                keep_skipped_values(old_child, new_child, epsilon);
+            }
+        }
+    } else {
+        for (old_child, new_child) in old.iter().zip(new.iter_mut()) {
+            keep_skipped_values(old_child, new_child, epsilon);
+        }
+    }
+}
+
+/// the attributes are compared per name, the same way as in [`create_attribute_patches`]
+fn keep_skipped_attribute_values<MSG>(
+    old: &[Attribute<MSG>],
+    new: &mut [Attribute<MSG>],
+    epsilon: f64,
+) {
+    let old_grouped = Element::group_indexed_attributes_per_name(old);
+    let skipped: Vec<(usize, usize)> = Element::group_indexed_attributes_per_name(new)
+        .iter()
+        .filter_map(|(name, new_attrs)| {
+            let old_attrs = old_grouped.get(name)?;
+            let is_same_values = old_attrs.len() == new_attrs.len()
+                && old_attrs.iter().zip(new_attrs).all(|((_, old), (_, new))| {
+                    old.value.len() == new.value.len()
+                        && old
+                            .value
+                            .iter()
+                            .zip(&new.value)
+                            .all(|(old, new)| old.approx_eq(new, epsilon))
+                });
+            is_same_values.then(|| {
+                new_attrs
+                    .iter()
+                    .zip(old_attrs)
+                    .map(|((new_index, _), (old_index, _))| (*new_index, *old_index))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .flatten()
+        .collect();
+    for (new_index, old_index) in skipped {
+        for (new_value, old_value) in new[new_index].value.iter_mut().zip(&old[old_index].value) {
+            let is_plain = matches!(
+                (&*new_value, old_value),
+                (AttributeValue::Simple(_), AttributeValue::Simple(_))
+                    | (AttributeValue::Style(_), AttributeValue::Style(_))
+            );
+            if is_plain && new_value != old_value {
+                *new_value = old_value.clone();
+            }
+        }
+    }
+}
+
+/// Return the patches needed for `old_node` to have the same DOM as `new_node`,
+/// comparing the nodes with the diff `options`
+///
+/// # Example
+/// ```rust
+/// use sauron::{diff::*, svg::*, *};
+///
+/// let old: Node<()> = circle([attr("cx", 10.0)], []);
+/// let new: Node<()> = circle([attr("cx", 10.0000001)], []);
+/// let options = DiffOptions {
+///     float_epsilon: Some(0.001),
+/// };
+/// assert!(diff_with_options(&old, &new, &options).is_empty());
+/// assert_eq!(diff(&old, &new).len(), 1);
+/// ```
+pub fn diff_with_options<'a, MSG>(
+    old_node: &'a Node<MSG>,
+    new_node: &'a Node<MSG>,
+    options: &DiffOptions,
+) -> Vec<Patch<'a, MSG>> {
     diff_recursive(
         old_node,
         new_node,
         &SkipPath {
             path: TreePath::root(),
             skip_diff: None,
+            options: *options,
         },
     )
 }
@@ -102,10 +266,21 @@ fn should_replace<'a, MSG>(old_node: &'a Node<MSG>, new_node: &'a Node<MSG>) ->
     }
     // replace if they have different element tag
     if let (Node::Element(old_element), Node::Element(new_element)) = (old_node, new_node) {
-        // Replace if there are different element tags
+        // Replace if there are different element tags,
+        // the attributes and children of the old element are not patched into the new one
         if old_element.tag != new_element.tag {
             return true;
         }
+        // the same tag in a different namespace such as the `a` of svg and html
+        // is a different kind of element which can only be created anew
+        if old_element.namespace != new_element.namespace {
+            return true;
+        }
+        // a portal is mounted into its target when created,
+        // so it is created anew into the new target
+        if old_element.first_value(PORTAL_TARGET) != new_element.first_value(PORTAL_TARGET) {
+            return true;
+        }
     }
     false
 }
@@ -122,6 +297,31 @@ pub fn diff_recursive<'a, MSG>(
         }
     }
 
+    // the same shared node has nothing to diff,
+    // otherwise the shared nodes are diffed by the nodes they contain
+    match (old_node, new_node) {
+        (Node::Leaf(Leaf::Shared(old)), Node::Leaf(Leaf::Shared(new))) if Rc::ptr_eq(old, new) => {
+            return vec![];
+        }
+        (Node::Leaf(Leaf::Shared(old)), _) => return diff_recursive(old, new_node, path),
+        (_, Node::Leaf(Leaf::Shared(new))) => return diff_recursive(old_node, new, path),
+        _ => (),
+    }
+
+    // the pending node of the same suspense is diffed as is,
+    // while a new suspense replaces the old node so that its task is started
+    match (old_node, new_node) {
+        (Node::Leaf(Leaf::Suspense(old)), Node::Leaf(Leaf::Suspense(new)))
+            if old.task == new.task =>
+        {
+            return diff_recursive(&old.view, &new.view, path);
+        }
+        (Node::Leaf(Leaf::Suspense(old)), new) if !matches!(new, Node::Leaf(Leaf::Suspense(_))) => {
+            return diff_recursive(&old.view, new_node, path);
+        }
+        _ => (),
+    }
+
     let skip = |old_node: &'a Node<MSG>, new_node: &'a Node<MSG>| {
         let new_skip_criteria = new_node.attribute_value(SKIP_CRITERIA);
         let old_skip_criteria = old_node.attribute_value(SKIP_CRITERIA);
@@ -157,6 +357,7 @@ pub fn diff_recursive<'a, MSG>(
         (Node::Leaf(old_leaf), Node::Leaf(new_leaf)) => {
             match (old_leaf, new_leaf) {
                 (Leaf::Text(_), Leaf::Text(_))
+                | (Leaf::PreText(_), Leaf::PreText(_))
                 | (Leaf::Symbol(_), Leaf::Symbol(_))
                 | (Leaf::Comment(_), Leaf::Comment(_))
                 | (Leaf::DocType(_), Leaf::DocType(_)) => {
@@ -178,6 +379,7 @@ pub fn diff_recursive<'a, MSG>(
                     let new_path = SkipPath {
                         path: path.path.clone(),
                         skip_diff: old_comp.view.skip_diff(),
+                        options: path.options,
                     };
 
                     let old_real_view = old_comp.view.unwrap_template_ref();
@@ -349,80 +551,82 @@ fn create_attribute_patches<'a, MSG>(
     new_attributes: &'a [Attribute<MSG>],
     path: &SkipPath,
 ) -> Vec<Patch<'a, MSG>> {
-    let skip_indices = if let Some(skip_diff) = &path.skip_diff {
-        if let SkipAttrs::Indices(skip_indices) = &skip_diff.skip_attrs {
-            skip_indices.clone()
-        } else {
-            vec![]
-        }
-    } else {
-        vec![]
+    // return early if both attributes are empty
+    if old_attributes.is_empty() && new_attributes.is_empty() {
+        return vec![];
+    }
+
+    // the common case where nothing has changed, this is checked first
+    // to avoid allocating the grouped attributes
+    let is_unchanged = old_attributes.len() == new_attributes.len()
+        && old_attributes
+            .iter()
+            .zip(new_attributes.iter())
+            .all(|(old, new)| {
+                old.key() == new.key() && path.options.is_same_values(&old.value, &new.value)
+            });
+    if is_unchanged {
+        return vec![];
+    }
+
+    let skip_indices: &[usize] = match &path.skip_diff {
+        Some(skip_diff) => match &skip_diff.skip_attrs {
+            SkipAttrs::Indices(skip_indices) => skip_indices,
+            _ => &[],
+        },
+        None => &[],
     };
 
     let has_skip_indices = !skip_indices.is_empty();
+    // returns true if all of these attributes are to be skipped
+    let is_skipped = |indexed_attrs: &[(usize, &Attribute<MSG>)]| {
+        USE_SKIP_DIFF
+            && has_skip_indices
+            && indexed_attrs.iter().all(|(i, _)| skip_indices.contains(i))
+    };
 
     let mut patches = vec![];
 
-    // return early if both attributes are empty
-    if old_attributes.is_empty() && new_attributes.is_empty() {
-        return vec![];
-    }
-
     let mut add_attributes: Vec<&Attribute<MSG>> = vec![];
     let mut remove_attributes: Vec<&Attribute<MSG>> = vec![];
 
-    let new_attributes_grouped = Element::group_indexed_attributes_per_name(new_attributes);
-    let old_attributes_grouped = Element::group_indexed_attributes_per_name(old_attributes);
+    let mut new_attributes_grouped = Element::group_indexed_attributes_per_name(new_attributes);
+    let mut old_attributes_grouped = Element::group_indexed_attributes_per_name(old_attributes);
+    // attributes with only empty values are not set, such as `title(None)`,
+    // treat them as absent, so going from `Some` to `None` removes the attribute
+    new_attributes_grouped.retain(|_, attrs| !attrs.iter().all(|(_, a)| a.is_just_empty()));
+    old_attributes_grouped.retain(|_, attrs| !attrs.iter().all(|(_, a)| a.is_just_empty()));
 
     // for all new elements that doesn't exist in the old elements
     // or the values differ
     // add it to the AddAttribute patches
     for (new_attr_name, new_attrs) in new_attributes_grouped.iter() {
-        let old_indexed_attr_values = old_attributes_grouped.get(new_attr_name).map(|attrs| {
-            attrs
-                .iter()
-                .map(|(i, attr)| (*i, &attr.value))
-                .collect::<Vec<_>>()
-        });
-
-        let new_indexed_attr_values = new_attributes_grouped.get(new_attr_name).map(|attrs| {
-            attrs
-                .iter()
-                .map(|(i, attr)| (*i, &attr.value))
-                .collect::<Vec<_>>()
-        });
-
-        if let Some(old_indexed_attr_values) = old_indexed_attr_values {
-            let new_indexed_attr_values =
-                new_indexed_attr_values.expect("must have new attr values");
-            let (_new_indices, new_attr_values): (Vec<usize>, Vec<&Vec<AttributeValue<MSG>>>) =
-                new_indexed_attr_values.into_iter().unzip();
-            let (old_indices, old_attr_values): (Vec<usize>, Vec<&Vec<AttributeValue<MSG>>>) =
-                old_indexed_attr_values.into_iter().unzip();
-            if USE_SKIP_DIFF && has_skip_indices && is_subset_of(&old_indices, &skip_indices) {
+        if let Some(old_attrs) = old_attributes_grouped.get(new_attr_name) {
+            let is_same_values = old_attrs.len() == new_attrs.len()
+                && old_attrs
+                    .iter()
+                    .zip(new_attrs.iter())
+                    .all(|((_, old), (_, new))| {
+                        path.options.is_same_values(&old.value, &new.value)
+                    });
+            if is_skipped(old_attrs) {
                 //
-            } else if old_attr_values != new_attr_values {
-                for (_i, new_att) in new_attrs {
-                    add_attributes.push(new_att);
-                }
+            } else if !is_same_values {
+                add_attributes.extend(new_attrs.iter().map(|(_, new_att)| *new_att));
             }
         } else {
             // these are new attributes
-            for (_i, new_att) in new_attrs {
-                add_attributes.push(new_att);
-            }
+            add_attributes.extend(new_attrs.iter().map(|(_, new_att)| *new_att));
         }
     }
 
     // if this attribute name does not exist anymore
     // to the new element, remove it
-    for (old_attr_name, old_indexed_attrs) in old_attributes_grouped.into_iter() {
-        let (old_indices, old_attrs): (Vec<usize>, Vec<&Attribute<MSG>>) =
-            old_indexed_attrs.into_iter().unzip();
-        if USE_SKIP_DIFF && has_skip_indices && is_subset_of(&old_indices, &skip_indices) {
+    for (old_attr_name, old_attrs) in old_attributes_grouped.iter() {
+        if is_skipped(old_attrs) {
             //
         } else if !new_attributes_grouped.contains_key(old_attr_name) {
-            remove_attributes.extend(old_attrs.clone());
+            remove_attributes.extend(old_attrs.iter().map(|(_, old_att)| *old_att));
         }
     }
 
@@ -442,9 +646,3 @@ fn create_attribute_patches<'a, MSG>(
     }
     patches
 }
-
-/// returns true if all the elements in subset is in big_set
-/// This also returns the indices of big_set that are not found in the subset
-fn is_subset_of<T: PartialEq>(subset: &[T], big_set: &[T]) -> bool {
-    subset.iter().all(|set| big_set.contains(set))
-}
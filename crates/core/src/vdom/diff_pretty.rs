@@ -0,0 +1,59 @@
+//! A textual diff of 2 node trees, for debugging why the view has changed
+use crate::vdom::Node;
+
+/// Renders a unified diff of the pretty printed html of the `old` and `new` node trees.
+///
+/// Each line is prefixed with a marker:
+///  - `"- "` the line is only in the `old` tree
+///  - `"+ "` the line is only in the `new` tree
+///  - `"  "` the line is the same in both trees
+///
+/// Changes to the attributes of an element show up as its opening tag being removed
+/// and added back.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::*, vdom::diff_pretty, *};
+///
+/// let old: Node<()> = ul([], [li([], [text("one")]), li([], [text("two")])]);
+/// let new: Node<()> = ul([], [li([], [text("one")]), li([], [text("three")])]);
+/// assert_eq!(
+///     diff_pretty(&old, &new),
+///     "  <ul>\n    <li>one</li>\n-   <li>two</li>\n+   <li>three</li>\n  </ul>"
+/// );
+/// ```
+pub fn diff_pretty<MSG>(old: &Node<MSG>, new: &Node<MSG>) -> String {
+    let old_html = old.render_to_string_pretty();
+    let new_html = new.render_to_string_pretty();
+    let old_lines: Vec<&str> = old_html.lines().collect();
+    let new_lines: Vec<&str> = new_html.lines().collect();
+
+    // the length of the longest common subsequence of the remaining lines
+    let mut lcs = vec![vec![0; new_lines.len() + 1]; old_lines.len() + 1];
+    for i in (0..old_lines.len()).rev() {
+        for j in (0..new_lines.len()).rev() {
+            lcs[i][j] = if old_lines[i] == new_lines[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old_lines.len() || j < new_lines.len() {
+        if i < old_lines.len() && j < new_lines.len() && old_lines[i] == new_lines[j] {
+            diff.push(format!("  {}", old_lines[i]));
+            i += 1;
+            j += 1;
+        } else if i < old_lines.len() && (j == new_lines.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push(format!("- {}", old_lines[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", new_lines[j]));
+            j += 1;
+        }
+    }
+    diff.join("\n")
+}
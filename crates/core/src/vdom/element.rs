@@ -1,5 +1,5 @@
-use super::attribute::{AttributeName, Namespace, Tag};
-use super::{Attribute, Node};
+use super::attribute::{AttributeKey, AttributeName, Namespace, Tag};
+use super::{Attribute, IntoChildren, Node};
 
 use crate::vdom::AttributeValue;
 use crate::vdom::Leaf;
@@ -41,11 +41,12 @@ impl<MSG> Element<MSG> {
         namespace: Option<Namespace>,
         tag: Tag,
         attrs: impl IntoIterator<Item = Attribute<MSG>>,
-        children: impl IntoIterator<Item = Node<MSG>>,
+        children: impl IntoChildren<MSG>,
         self_closing: bool,
     ) -> Self {
         //unroll the nodelist
         let children = children
+            .into_children()
             .into_iter()
             .flat_map(|child| match child {
                 Node::Leaf(Leaf::NodeList(node_list)) => node_list,
@@ -67,8 +68,8 @@ impl<MSG> Element<MSG> {
     }
 
     /// add children virtual node to this element
-    pub fn add_children(&mut self, children: impl IntoIterator<Item = Node<MSG>>) {
-        self.children.extend(children);
+    pub fn add_children(&mut self, children: impl IntoChildren<MSG>) {
+        self.children.extend(children.into_children());
     }
 
     /// returns a refernce to the children of this node
@@ -166,6 +167,73 @@ impl<MSG> Element<MSG> {
         }
     }
 
+    /// return the space separated tokens of the attribute with this `name`,
+    /// such as the values of `class`, `rel` or `aria-labelledby`
+    pub fn tokens(&self, name: &AttributeName) -> Vec<String> {
+        self.attrs
+            .iter()
+            .filter(|att| att.name == *name)
+            .flat_map(|att| att.value())
+            .filter_map(|v| v.get_simple())
+            .flat_map(|v| {
+                v.to_string()
+                    .split_whitespace()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// returns true if the attribute with this `name` contains the `token`
+    pub fn has_token(&self, name: &AttributeName, token: &str) -> bool {
+        self.tokens(name).iter().any(|t| t == token)
+    }
+
+    /// add the `token` to the space separated tokens of the attribute with this `name`.
+    /// The attribute is created if it doesn't exist yet, and nothing is changed if the token
+    /// is already there.
+    pub fn add_token(&mut self, name: AttributeName, token: &str) {
+        if self.has_token(&name, token) {
+            return;
+        }
+        let value = AttributeValue::Simple(Value::from(token.to_string()));
+        if let Some(existing) = self.attrs.iter_mut().find(|att| att.name == name) {
+            existing.value.push(value);
+        } else {
+            self.attrs.push(Attribute::new(None, name, value));
+        }
+    }
+
+    /// remove the `token` from the space separated tokens of the attribute with this `name`.
+    /// The attribute is removed when it has no more values, and nothing is changed if the
+    /// attribute doesn't exist.
+    pub fn remove_token(&mut self, name: &AttributeName, token: &str) {
+        for att in self.attrs.iter_mut().filter(|att| att.name == *name) {
+            att.value = att
+                .value
+                .drain(..)
+                .filter_map(|v| {
+                    let Some(simple) = v.get_simple() else {
+                        return Some(v);
+                    };
+                    let simple = simple.to_string();
+                    if !simple.split_whitespace().any(|t| t == token) {
+                        return Some(v);
+                    }
+                    let remaining: Vec<&str> =
+                        simple.split_whitespace().filter(|t| *t != token).collect();
+                    if remaining.is_empty() {
+                        None
+                    } else {
+                        Some(AttributeValue::Simple(Value::from(remaining.join(" "))))
+                    }
+                })
+                .collect();
+        }
+        self.attrs
+            .retain(|att| att.name != *name || !att.value.is_empty());
+    }
+
     /// return all the attribute values which the name &AttributeName
     pub fn attribute_value(&self, name: &AttributeName) -> Option<Vec<&AttributeValue<MSG>>> {
         let result: Vec<&AttributeValue<MSG>> = self
@@ -188,18 +256,59 @@ impl<MSG> Element<MSG> {
             .and_then(|att_values| att_values.first().and_then(|v| v.get_simple()))
     }
 
-    /// grouped the attributes, but retain the index of the attribute
+    /// get a clone of the first value of the attribute which has the name `att_name` of this element,
+    /// such as for keeping the value across an await point in server side rendering
+    /// where the element itself can not be borrowed.
+    ///
+    /// This clones the value, use [`first_value`](Self::first_value) to borrow it instead.
+    pub fn get_attribute_value_owned(&self, att_name: &AttributeName) -> Option<Value> {
+        self.first_value(att_name).cloned()
+    }
+
+    /// return the first event listener attribute of this element with the event `name`
+    pub fn get_event(&self, name: &AttributeName) -> Option<&Attribute<MSG>> {
+        self.attrs
+            .iter()
+            .find(|att| att.name == *name && att.is_event_listener())
+    }
+
+    /// return all the event listener attributes of this element with the event `name`,
+    /// in the order they are attached to this element
+    pub fn get_events(&self, name: &AttributeName) -> Vec<&Attribute<MSG>> {
+        self.attrs
+            .iter()
+            .filter(|att| att.name == *name && att.is_event_listener())
+            .collect()
+    }
+
+    /// return the attributes of this element which name matches the predicate `pred`,
+    /// in the order they are attached to this element, such as all the `data-*` attributes.
+    ///
+    /// The attributes of the same name are not merged, and the event listeners are included
+    /// when their event name matches.
+    /// # Examples
+    /// ```rust
+    /// use sauron::*;
+    ///
+    /// let view: Node<()> = div([attr("data-id", 1), class("card"), attr("data-role", "item")], []);
+    /// let data_attrs = view.element_ref().unwrap().attrs_matching(|name| name.starts_with("data-"));
+    /// assert_eq!(data_attrs.iter().map(|att| att.name).collect::<Vec<_>>(), ["data-id", "data-role"]);
+    /// ```
+    pub fn attrs_matching(&self, pred: impl Fn(&str) -> bool) -> Vec<&Attribute<MSG>> {
+        self.attrs.iter().filter(|att| pred(att.name)).collect()
+    }
+
+    /// grouped the attributes by their namespace and name, but retain the index of the attribute
     /// relative to its location in the element
     pub fn group_indexed_attributes_per_name<'a>(
         attrs: &'a [Attribute<MSG>],
-    ) -> IndexMap<&'a AttributeName, Vec<(usize, &'a Attribute<MSG>)>> {
-        let mut grouped: IndexMap<&'a AttributeName, Vec<(usize, &'a Attribute<MSG>)>> =
-            IndexMap::new();
+    ) -> IndexMap<AttributeKey, Vec<(usize, &'a Attribute<MSG>)>> {
+        let mut grouped: IndexMap<AttributeKey, Vec<(usize, &'a Attribute<MSG>)>> = IndexMap::new();
         for (i, attr) in attrs.iter().enumerate() {
-            if let Some(existing) = grouped.get_mut(&attr.name) {
+            if let Some(existing) = grouped.get_mut(&attr.key()) {
                 existing.push((i, attr));
             } else {
-                grouped.insert(&attr.name, vec![(i, attr)]);
+                grouped.insert(attr.key(), vec![(i, attr)]);
             }
         }
         grouped
@@ -209,4 +318,40 @@ impl<MSG> Element<MSG> {
     pub fn has_mount_callback(&self) -> bool {
         self.attributes().iter().any(|a| a.is_mount_callback())
     }
+
+    /// compare this element to `other` where the order of the attributes is insignificant.
+    /// The attributes are compared as a multiset of name and value, so duplicate attribute names
+    /// have to appear the same number of times in both elements.
+    /// The children are still compared in order, descendant elements are compared the same way.
+    pub fn eq_unordered_attrs(&self, other: &Self) -> bool {
+        if self.namespace != other.namespace
+            || self.tag != other.tag
+            || self.self_closing != other.self_closing
+            || self.attrs.len() != other.attrs.len()
+            || self.children.len() != other.children.len()
+        {
+            return false;
+        }
+        let mut matched = vec![false; other.attrs.len()];
+        let same_attrs = self.attrs.iter().all(|attr| {
+            let found = other
+                .attrs
+                .iter()
+                .enumerate()
+                .position(|(i, o)| !matched[i] && o == attr);
+            if let Some(i) = found {
+                matched[i] = true;
+            }
+            found.is_some()
+        });
+        same_attrs
+            && self
+                .children
+                .iter()
+                .zip(other.children.iter())
+                .all(|(a, b)| match (a, b) {
+                    (Node::Element(a), Node::Element(b)) => a.eq_unordered_attrs(b),
+                    _ => a == b,
+                })
+    }
 }
@@ -5,15 +5,20 @@ use crate::vdom::Attribute;
 use crate::vdom::AttributeName;
 use crate::vdom::AttributeValue;
 use crate::vdom::Node;
+use crate::vdom::SuspenseView;
 use crate::vdom::TemplatedView;
 use derive_where::derive_where;
 use std::borrow::Cow;
+use std::rc::Rc;
 
 /// A leaf node value of html dom tree
 #[derive_where(Clone, Debug)]
 pub enum Leaf<MSG> {
     /// Text variant of a virtual node
     Text(Cow<'static, str>),
+    /// A text node whose whitespace is kept as is when serializing the node,
+    /// see [`pre_text`](crate::html::pre_text)
+    PreText(Cow<'static, str>),
     /// Html entities such as &nbsp; &gt;
     Symbol(Cow<'static, str>),
     /// A comment node
@@ -33,12 +38,18 @@ pub enum Leaf<MSG> {
     StatelessComponent(StatelessModel<MSG>),
     /// a view where a template and skip diff is provided
     TemplatedView(TemplatedView<MSG>),
+    /// a node which is shared across views without deep cloning it,
+    /// diffing is skipped when the old and new shared nodes are the same `Rc`
+    Shared(Rc<Node<MSG>>),
+    /// a pending node of a suspense, which is replaced once the suspense is resolved
+    Suspense(SuspenseView<MSG>),
 }
 
 impl<MSG> PartialEq for Leaf<MSG> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Text(v), Self::Text(o)) => v == o,
+            (Self::PreText(v), Self::PreText(o)) => v == o,
             (Self::Symbol(v), Self::Symbol(o)) => v == o,
             (Self::Comment(v), Self::Comment(o)) => v == o,
             (Self::DocType(v), Self::DocType(o)) => v == o,
@@ -47,6 +58,8 @@ impl<MSG> PartialEq for Leaf<MSG> {
             #[cfg(feature = "with-dom")]
             (Self::StatefulComponent(v), Self::StatefulComponent(o)) => v == o,
             (Self::StatelessComponent(v), Self::StatelessComponent(o)) => v == o,
+            (Self::Shared(v), Self::Shared(o)) => Rc::ptr_eq(v, o) || v == o,
+            (Self::Suspense(v), Self::Suspense(o)) => v == o,
             _ => false,
         }
     }
@@ -55,15 +68,20 @@ impl<MSG> PartialEq for Leaf<MSG> {
 impl<MSG> Eq for Leaf<MSG> {}
 
 impl<MSG> Leaf<MSG> {
-    /// returns true if this a text node
+    /// returns true if this a text node, including a preformatted text node
     pub fn is_text(&self) -> bool {
-        matches!(self, Self::Text(_))
+        matches!(self, Self::Text(_) | Self::PreText(_))
     }
 
-    /// return the text content if it is a text node
+    /// returns true if this a preformatted text node
+    pub fn is_pre_text(&self) -> bool {
+        matches!(self, Self::PreText(_))
+    }
+
+    /// return the text content if it is a text node, including a preformatted text node
     pub fn as_text(&self) -> Option<&str> {
         match self {
-            Self::Text(ref text) => Some(text),
+            Self::Text(ref text) | Self::PreText(ref text) => Some(text),
             _ => None,
         }
     }
@@ -73,6 +91,8 @@ impl<MSG> Leaf<MSG> {
         match self {
             Self::StatelessComponent(comp) => comp.attribute_value(name),
             Self::TemplatedView(templated_view) => templated_view.view.attribute_value(name),
+            Self::Shared(node) => node.attribute_value(name),
+            Self::Suspense(suspense) => suspense.view.attribute_value(name),
             _ => None,
         }
     }
@@ -83,6 +103,8 @@ impl<MSG> Leaf<MSG> {
         match self {
             Self::StatelessComponent(comp) => comp.attributes(),
             Self::TemplatedView(templated_view) => templated_view.view.attributes(),
+            Self::Shared(node) => node.attributes(),
+            Self::Suspense(suspense) => suspense.view.attributes(),
             _ => None,
         }
     }
@@ -3,7 +3,9 @@ use crate::vdom::AttributeValue;
 use crate::vdom::Element;
 use crate::vdom::Leaf;
 use crate::vdom::Node;
+use crate::vdom::SuspenseView;
 use crate::vdom::TemplatedView;
+use std::rc::Rc;
 
 impl<MSG> Node<MSG> {
     /// map the msg of this node such that Node<MSG> becomes Node<MSG2>
@@ -97,6 +99,7 @@ impl<MSG> Leaf<MSG> {
     {
         match self {
             Self::Text(v) => Leaf::Text(v),
+            Self::PreText(v) => Leaf::PreText(v),
             Self::Symbol(v) => Leaf::Symbol(v),
             Self::Comment(v) => Leaf::Comment(v),
             Self::DocType(v) => Leaf::DocType(v),
@@ -115,6 +118,13 @@ impl<MSG> Leaf<MSG> {
             Self::StatefulComponent(v) => Leaf::StatefulComponent(v.map_msg(cb)),
             Self::StatelessComponent(v) => Leaf::StatelessComponent(v.map_msg(cb)),
             Self::TemplatedView(v) => Leaf::TemplatedView(v.map_msg(cb)),
+            // the shared node has to be cloned, since it is still referenced with the old msg type
+            Self::Shared(node) => Leaf::Shared(Rc::new((*node).clone().map_msg(cb))),
+            // the task is kept, since it does not depend on the msg
+            Self::Suspense(suspense) => Leaf::Suspense(SuspenseView {
+                view: Box::new(suspense.view.map_msg(cb)),
+                task: suspense.task,
+            }),
         }
     }
 }
@@ -1,13 +1,18 @@
 use super::{AttributeName, Namespace, Tag};
 use crate::dom::SkipDiff;
+use crate::vdom::render::WHITESPACE_SENSITIVE_TAGS;
 use crate::vdom::Attribute;
 use crate::vdom::AttributeValue;
 use crate::vdom::Element;
+use crate::vdom::IntoChildren;
 use crate::vdom::Leaf;
+use crate::vdom::TreePath;
 use crate::vdom::Value;
 use derive_where::derive_where;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
 
 /// represents a node in a virtual dom
 /// A node could be an element which can contain one or more children of nodes.
@@ -91,6 +96,14 @@ impl<MSG> Node<MSG> {
         }
     }
 
+    /// returns true if this is a preformatted text node, see [`pre_text`](crate::html::pre_text)
+    pub fn is_pre_text(&self) -> bool {
+        match self {
+            Self::Leaf(leaf) => leaf.is_pre_text(),
+            _ => false,
+        }
+    }
+
     /// return the text if this is text node leaf
     pub fn as_text(&self) -> Option<&str> {
         match self {
@@ -126,7 +139,7 @@ impl<MSG> Node<MSG> {
     /// Consume a mutable self and add a children to this node it if is an element
     /// will have no effect if it is a text node.
     /// This is used in building the nodes in a builder pattern
-    pub fn with_children(mut self, children: impl IntoIterator<Item = Node<MSG>>) -> Self {
+    pub fn with_children(mut self, children: impl IntoChildren<MSG>) -> Self {
         if let Some(element) = self.element_mut() {
             element.add_children(children);
         } else {
@@ -136,10 +149,7 @@ impl<MSG> Node<MSG> {
     }
 
     /// add children but not consume self
-    pub fn add_children(
-        &mut self,
-        children: impl IntoIterator<Item = Node<MSG>>,
-    ) -> Result<(), Error> {
+    pub fn add_children(&mut self, children: impl IntoChildren<MSG>) -> Result<(), Error> {
         if let Some(element) = self.element_mut() {
             element.add_children(children);
             Ok(())
@@ -172,6 +182,21 @@ impl<MSG> Node<MSG> {
         }
     }
 
+    /// wrap this node inside a new element with `tag` and `attrs`,
+    /// this node can be an element, a text or any other leaf.
+    /// This is used in building layout decorators and higher order view functions.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sauron::{html::*, *};
+    ///
+    /// let node: Node<()> = text("hello").wrap("div", [class("wrapper")]);
+    /// assert_eq!(node.render_to_string(), r#"<div class="wrapper">hello</div>"#);
+    /// ```
+    pub fn wrap(self, tag: Tag, attrs: impl IntoIterator<Item = Attribute<MSG>>) -> Self {
+        element(tag, attrs, [self])
+    }
+
     /// get the attributes of this node
     /// returns None if it is a text node
     pub fn attributes(&self) -> Option<&[Attribute<MSG>]> {
@@ -197,6 +222,8 @@ impl<MSG> Node<MSG> {
         match self {
             Self::Element(elm) => elm.children(),
             Self::Leaf(Leaf::StatefulComponent(comp)) => &comp.children,
+            Self::Leaf(Leaf::Shared(node)) => node.children(),
+            Self::Leaf(Leaf::Suspense(suspense)) => suspense.view.children(),
             _ => &[],
         }
     }
@@ -216,6 +243,46 @@ impl<MSG> Node<MSG> {
         }
     }
 
+    /// Replace the node at the `path` of child indexes with the `replacement`,
+    /// returns the node which is replaced, or None if there is no node at the path.
+    ///
+    /// An empty path replaces this node itself. The path is traversed through the same
+    /// children as [`children`](Self::children), a shared node on the path is copied
+    /// before its child is replaced so the other views which share it are not changed.
+    /// # Examples
+    /// ```rust
+    /// use sauron::*;
+    ///
+    /// let mut view: Node<()> = ul(vec![], vec![li(vec![], vec![text("one")]), li(vec![], vec![text("two")])]);
+    /// let old = view.replace_node_at_path([1, 0], text("2"));
+    /// assert_eq!(old, Some(text("two")));
+    /// assert_eq!(view.render_to_string(), "<ul><li>one</li><li>2</li></ul>");
+    /// assert_eq!(view.replace_node_at_path([2], text("3")), None);
+    /// ```
+    pub fn replace_node_at_path(
+        &mut self,
+        path: impl Into<TreePath>,
+        replacement: Node<MSG>,
+    ) -> Option<Node<MSG>> {
+        let path = path.into();
+        let mut node = self;
+        for idx in path.path {
+            node = node.path_children_mut().get_mut(idx)?;
+        }
+        Some(std::mem::replace(node, replacement))
+    }
+
+    /// the mutable counterpart of [`children`](Self::children)
+    fn path_children_mut(&mut self) -> &mut [Node<MSG>] {
+        match self {
+            Self::Element(elm) => elm.children_mut(),
+            Self::Leaf(Leaf::StatefulComponent(comp)) => &mut comp.children,
+            Self::Leaf(Leaf::Shared(node)) => Rc::make_mut(node).path_children_mut(),
+            Self::Leaf(Leaf::Suspense(suspense)) => suspense.view.path_children_mut(),
+            _ => &mut [],
+        }
+    }
+
     /// Removes an child node  from this element and returns it.
     ///
     /// The removed child is replaced by the last child of the element's children.
@@ -263,6 +330,258 @@ impl<MSG> Node<MSG> {
         cnt
     }
 
+    /// Iterates over the descendants of this node, not including this node itself,
+    /// in depth-first pre-order: a node is yielded before its children,
+    /// and the children are visited in order, the same as the document order of the html.
+    ///
+    /// The iteration is lazy, it only keeps a stack of the child iterators of the
+    /// current path. Fragments, node lists and shared nodes are yielded as well,
+    /// followed by the nodes they contain.
+    /// # Examples
+    /// ```rust
+    /// use sauron::*;
+    ///
+    /// let view: Node<()> = ul(vec![], vec![li(vec![], vec![text("one")]), li(vec![], vec![])]);
+    /// let tags: Vec<_> = view.descendants().map(|node| node.tag()).collect();
+    /// assert_eq!(tags, vec![Some(&"li"), None, Some(&"li")]);
+    /// ```
+    pub fn descendants(&self) -> impl Iterator<Item = &Node<MSG>> {
+        Descendants {
+            stack: vec![self.child_nodes().iter()],
+        }
+    }
+
+    /// Iterates over the descendant elements of this node, in the same order as [`Node::descendants`]
+    pub fn descendant_elements(&self) -> impl Iterator<Item = &Element<MSG>> {
+        self.descendants().filter_map(|node| node.element_ref())
+    }
+
+    /// Returns the distinct event names which are listened to in this node tree,
+    /// including this node, such as `click` and `input`.
+    ///
+    /// This is useful for auditing the events used by a view,
+    /// and for knowing which listeners to attach when the events are delegated to the root.
+    /// # Examples
+    /// ```rust
+    /// use sauron::*;
+    ///
+    /// let view: Node<()> = div(
+    ///     [on_click(|_| ())],
+    ///     [input([on_input(|_| ()), on_click(|_| ())]), text("hi")],
+    /// );
+    /// assert_eq!(view.event_names().into_iter().collect::<Vec<_>>(), ["click", "input"]);
+    /// ```
+    pub fn event_names(&self) -> BTreeSet<&'static str> {
+        std::iter::once(self)
+            .chain(self.descendants())
+            .filter_map(|node| node.element_ref())
+            .flat_map(|element| element.attributes())
+            .filter(|attr| attr.is_event_listener())
+            .map(|attr| *attr.name())
+            .collect()
+    }
+
+    /// the nodes contained in this node, which includes the nodes of fragments and node lists
+    /// and the node wrapped in a shared node.
+    fn child_nodes(&self) -> &[Node<MSG>] {
+        match self {
+            Node::Element(element) => element.children(),
+            Node::Leaf(Leaf::Fragment(nodes)) | Node::Leaf(Leaf::NodeList(nodes)) => nodes,
+            Node::Leaf(Leaf::Shared(node)) => std::slice::from_ref(node.as_ref()),
+            Node::Leaf(Leaf::Suspense(suspense)) => std::slice::from_ref(suspense.view.as_ref()),
+            Node::Leaf(_) => &[],
+        }
+    }
+
+    /// Returns the number of elements of each tag in this node tree, including this node.
+    /// Nodes in fragments, node lists and shared nodes are counted as well.
+    ///
+    /// This is useful for spotting an accidental explosion of nodes in a view.
+    pub fn count_by_tag(&self) -> BTreeMap<String, usize> {
+        let mut counts: BTreeMap<&Tag, usize> = BTreeMap::new();
+        self.tally_tags(&mut counts);
+        counts
+            .into_iter()
+            .map(|(tag, count)| (tag.to_string(), count))
+            .collect()
+    }
+
+    fn tally_tags<'a>(&'a self, counts: &mut BTreeMap<&'a Tag, usize>) {
+        let children = match self {
+            Node::Element(element) => {
+                *counts.entry(element.tag()).or_default() += 1;
+                element.children()
+            }
+            Node::Leaf(Leaf::Fragment(nodes)) | Node::Leaf(Leaf::NodeList(nodes)) => nodes,
+            Node::Leaf(Leaf::Shared(node)) => return node.tally_tags(counts),
+            Node::Leaf(Leaf::Suspense(suspense)) => return suspense.view.tally_tags(counts),
+            Node::Leaf(_) => return,
+        };
+        for child in children {
+            child.tally_tags(counts);
+        }
+    }
+
+    /// Returns the ids which are used by more than one element in this node tree,
+    /// in the order they are first repeated.
+    ///
+    /// Duplicate ids breaks `getElementById` as well as the focus and scroll commands
+    /// which look up the element by its id.
+    pub fn duplicate_ids(&self) -> Vec<String> {
+        let mut seen = BTreeSet::new();
+        let mut duplicates = vec![];
+        self.collect_duplicate_ids(&mut seen, &mut duplicates);
+        duplicates
+    }
+
+    fn collect_duplicate_ids(&self, seen: &mut BTreeSet<String>, duplicates: &mut Vec<String>) {
+        let children = match self {
+            Node::Element(element) => {
+                if let Some(id) = element.first_value(&"id") {
+                    let id = id.to_string();
+                    if !seen.insert(id.clone()) && !duplicates.contains(&id) {
+                        duplicates.push(id);
+                    }
+                }
+                element.children()
+            }
+            Node::Leaf(Leaf::Fragment(nodes)) | Node::Leaf(Leaf::NodeList(nodes)) => nodes,
+            Node::Leaf(Leaf::Shared(node)) => return node.collect_duplicate_ids(seen, duplicates),
+            Node::Leaf(Leaf::Suspense(suspense)) => {
+                return suspense.view.collect_duplicate_ids(seen, duplicates)
+            }
+            Node::Leaf(_) => return,
+        };
+        for child in children {
+            child.collect_duplicate_ids(seen, duplicates);
+        }
+    }
+
+    /// Returns a description of each element in this node tree which is created in a namespace
+    /// that doesn't match its tag or its parent, such as a `div` in the svg namespace,
+    /// a `circle` without the svg namespace or an html element directly inside an svg element.
+    ///
+    /// These elements are created in the DOM but are not rendered as intended.
+    /// Html content inside an svg should be put inside a `foreignObject`.
+    #[cfg(feature = "with-lookup")]
+    pub fn namespace_mismatches(&self) -> Vec<String> {
+        let mut mismatches = vec![];
+        self.collect_namespace_mismatches(None, &mut mismatches);
+        mismatches
+    }
+
+    #[cfg(feature = "with-lookup")]
+    fn collect_namespace_mismatches(
+        &self,
+        parent: Option<&Element<MSG>>,
+        mismatches: &mut Vec<String>,
+    ) {
+        use crate::html::lookup::{is_html_tag, is_svg_tag};
+        use crate::svg::SVG_NAMESPACE;
+
+        let is_svg = |element: &Element<MSG>| element.namespace() == Some(&SVG_NAMESPACE);
+        let children = match self {
+            Node::Element(element) => {
+                let tag = element.tag();
+                let mismatch = if is_svg(element) && !is_svg_tag(tag) && is_html_tag(tag) {
+                    Some(format!(
+                        "`{tag}` is an html element but is created in the svg namespace"
+                    ))
+                } else if !is_svg(element) && is_svg_tag(tag) && !is_html_tag(tag) {
+                    Some(format!(
+                        "`{tag}` is an svg element but is created without the svg namespace"
+                    ))
+                } else {
+                    match parent {
+                        Some(parent)
+                            if is_svg(parent) && !is_svg(element) && *parent.tag() != "foreignObject" =>
+                        {
+                            Some(format!(
+                                "`{tag}` is created in the html namespace inside the svg element `{}`, \
+                                put it inside a `foreignObject`",
+                                parent.tag()
+                            ))
+                        }
+                        _ => None,
+                    }
+                };
+                mismatches.extend(mismatch);
+                for child in element.children() {
+                    child.collect_namespace_mismatches(Some(element), mismatches);
+                }
+                return;
+            }
+            Node::Leaf(Leaf::Fragment(nodes)) | Node::Leaf(Leaf::NodeList(nodes)) => nodes,
+            Node::Leaf(Leaf::Shared(node)) => {
+                return node.collect_namespace_mismatches(parent, mismatches)
+            }
+            Node::Leaf(Leaf::Suspense(suspense)) => {
+                return suspense
+                    .view
+                    .collect_namespace_mismatches(parent, mismatches)
+            }
+            Node::Leaf(_) => return,
+        };
+        for child in children {
+            child.collect_namespace_mismatches(parent, mismatches);
+        }
+    }
+
+    /// Recursively removes the empty text nodes in this node tree.
+    ///
+    /// The content of whitespace sensitive elements such as `<pre>` and `<textarea>` is kept
+    /// intact. Nodes inside a shared node are not touched.
+    pub fn trim_empty_text(self) -> Self {
+        self.trim_text(false)
+    }
+
+    /// Recursively removes the empty and whitespace-only text nodes in this node tree.
+    ///
+    /// Unlike [`Node::trim_empty_text`] this can change how the view is rendered,
+    /// since the whitespace in between inline elements is significant.
+    pub fn trim_whitespace_text(self) -> Self {
+        self.trim_text(true)
+    }
+
+    fn trim_text(self, whitespace_only: bool) -> Self {
+        let trim_children = |nodes: Vec<Node<MSG>>| -> Vec<Node<MSG>> {
+            let trimmed: Vec<Node<MSG>> = nodes
+                .into_iter()
+                .filter(|node| match node.as_text() {
+                    // the whitespace of a preformatted text is significant
+                    Some(text) if whitespace_only && !node.is_pre_text() => !text.trim().is_empty(),
+                    Some(text) => !text.is_empty(),
+                    None => true,
+                })
+                .map(|node| node.trim_text(whitespace_only))
+                .collect();
+            // the separators are only needed in between 2 text nodes
+            let is_orphan_separator = |i: usize| {
+                matches!(&trimmed[i], Node::Leaf(Leaf::Comment(c)) if c == "separator")
+                    && !(i > 0
+                        && trimmed[i - 1].is_text()
+                        && trimmed.get(i + 1).is_some_and(Node::is_text))
+            };
+            let orphans: Vec<bool> = (0..trimmed.len()).map(is_orphan_separator).collect();
+            trimmed
+                .into_iter()
+                .zip(orphans)
+                .filter_map(|(node, orphan)| (!orphan).then_some(node))
+                .collect()
+        };
+        match self {
+            Node::Element(mut element) => {
+                if !WHITESPACE_SENSITIVE_TAGS.contains(element.tag()) {
+                    element.children = trim_children(element.children);
+                }
+                Node::Element(element)
+            }
+            Node::Leaf(Leaf::Fragment(nodes)) => Node::Leaf(Leaf::Fragment(trim_children(nodes))),
+            Node::Leaf(Leaf::NodeList(nodes)) => Node::Leaf(Leaf::NodeList(trim_children(nodes))),
+            Node::Leaf(leaf) => Node::Leaf(leaf),
+        }
+    }
+
     /// remove the existing attributes and set with the new value
     pub fn set_attributes(
         &mut self,
@@ -301,6 +620,15 @@ impl<MSG> Node<MSG> {
             .and_then(|att_values| att_values.first().and_then(|v| v.get_simple()))
     }
 
+    /// get a clone of the first value of the attribute which has the name `att_name` of this node,
+    /// such as for keeping the value across an await point in server side rendering
+    /// where the node itself can not be borrowed.
+    ///
+    /// This clones the value, use [`first_value`](Self::first_value) to borrow it instead.
+    pub fn get_attribute_value_owned(&self, att_name: &AttributeName) -> Option<Value> {
+        self.first_value(att_name).cloned()
+    }
+
     /// return the skip diff if this node has one
     pub fn skip_diff(&self) -> Option<SkipDiff> {
         match self {
@@ -345,7 +673,7 @@ impl<MSG> Node<MSG> {
 pub fn element<MSG>(
     tag: Tag,
     attrs: impl IntoIterator<Item = Attribute<MSG>>,
-    children: impl IntoIterator<Item = Node<MSG>>,
+    children: impl IntoChildren<MSG>,
 ) -> Node<MSG> {
     element_ns(None, tag, attrs, children, false)
 }
@@ -367,7 +695,7 @@ pub fn element_ns<MSG>(
     namespace: Option<Namespace>,
     tag: Tag,
     attrs: impl IntoIterator<Item = Attribute<MSG>>,
-    children: impl IntoIterator<Item = Node<MSG>>,
+    children: impl IntoChildren<MSG>,
     self_closing: bool,
 ) -> Node<MSG> {
     Node::Element(Element::new(namespace, tag, attrs, children, self_closing))
@@ -383,7 +711,48 @@ pub fn node_list<MSG>(nodes: impl IntoIterator<Item = Node<MSG>>) -> Node<MSG> {
     Node::Leaf(Leaf::NodeList(nodes.into_iter().collect()))
 }
 
+/// create a node which shares a precomputed `node`, such as a large static part of a view.
+/// The node is not deep cloned when the view is cloned and diffing it against the same
+/// shared node emits no patches.
+///
+/// # Example
+/// ```rust
+/// use sauron::{*, vdom::{diff, shared}};
+/// use std::rc::Rc;
+///
+/// let footer: Rc<Node<()>> = Rc::new(footer(vec![], vec![text("static footer")]));
+/// let old: Node<()> = div(vec![], vec![shared(Rc::clone(&footer))]);
+/// let new: Node<()> = div(vec![], vec![shared(Rc::clone(&footer))]);
+/// assert!(diff(&old, &new).is_empty());
+/// ```
+pub fn shared<MSG>(node: Rc<Node<MSG>>) -> Node<MSG> {
+    Node::Leaf(Leaf::Shared(node))
+}
+
 /// create fragment node
 pub fn fragment<MSG>(nodes: impl IntoIterator<Item = Node<MSG>>) -> Node<MSG> {
     Node::Leaf(Leaf::Fragment(nodes.into_iter().collect()))
 }
+
+/// a depth-first pre-order iterator over the descendants of a node
+struct Descendants<'a, MSG> {
+    stack: Vec<std::slice::Iter<'a, Node<MSG>>>,
+}
+
+impl<'a, MSG> Iterator for Descendants<'a, MSG> {
+    type Item = &'a Node<MSG>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let siblings = self.stack.last_mut()?;
+            if let Some(node) = siblings.next() {
+                let child_nodes = node.child_nodes();
+                if !child_nodes.is_empty() {
+                    self.stack.push(child_nodes.iter());
+                }
+                return Some(node);
+            }
+            self.stack.pop();
+        }
+    }
+}
@@ -1,24 +1,107 @@
 //! This contains a trait to be able to render
 //! virtual dom into a writable buffer
 //!
+use crate::html::lookup::is_boolean_attribute;
 use crate::vdom::Style;
 use crate::vdom::Value;
 use crate::{
     vdom::GroupedAttributeValues,
-    vdom::{Attribute, Element, Leaf, Node},
+    vdom::{Attribute, Element, Leaf, Node, PORTAL_TARGET},
 };
 use std::fmt;
 
 const DEFAULT_INDENT_SIZE: usize = 2;
 
+/// elements where the whitespace in the text content is significant
+pub(crate) const WHITESPACE_SENSITIVE_TAGS: &[&str] = &["pre", "textarea"];
+
+/// Options which controls the content when serializing the node into html.
+/// This is separate from the pretty or compressed mode, which only controls whether there are
+/// new lines and indentation in between elements.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// collapse the runs of whitespace in text nodes into a single space,
+    /// matching how html renders the text by default.
+    /// The text inside `<pre>` and `<textarea>` are kept as is.
+    pub normalize_whitespace: bool,
+    /// the unit of indentation of the nested elements in the pretty mode
+    pub indent: IndentStyle,
+    /// render the attributes of each element sorted by name instead of in the order they
+    /// appear in the view, so reordering the attributes in the view code doesn't change the
+    /// output, such as for snapshots of server rendered pages.
+    ///
+    /// Browsers don't depend on the order of the attributes when parsing html, however the
+    /// order is visible to scripts which read `element.attributes` and to tools which match on
+    /// the html text, so this is off by default.
+    pub sort_attributes: bool,
+}
+
+/// The unit of indentation of the nested elements when rendering pretty html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentStyle {
+    /// indent with this number of spaces per level
+    Spaces(usize),
+    /// indent with a tab per level
+    Tabs,
+}
+
+impl Default for IndentStyle {
+    fn default() -> Self {
+        Self::Spaces(DEFAULT_INDENT_SIZE)
+    }
+}
+
+impl IndentStyle {
+    /// the indentation of a single level
+    fn unit(&self) -> String {
+        match self {
+            Self::Spaces(n) => " ".repeat(*n),
+            Self::Tabs => "\t".to_string(),
+        }
+    }
+}
+
+impl SerializeOptions {
+    /// the options to use for the children of the element with this `tag`
+    fn for_children_of(&self, tag: &str) -> Self {
+        if WHITESPACE_SENSITIVE_TAGS.contains(&tag) {
+            Self {
+                normalize_whitespace: false,
+                ..*self
+            }
+        } else {
+            *self
+        }
+    }
+}
+
+/// collapse each run of whitespace into a single space
+fn normalize_whitespace(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut in_whitespace = false;
+    for ch in text.chars() {
+        if ch.is_ascii_whitespace() {
+            if !in_whitespace {
+                normalized.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            normalized.push(ch);
+            in_whitespace = false;
+        }
+    }
+    normalized
+}
+
 /// add an indent if applicable
-fn maybe_indent(buffer: &mut dyn fmt::Write, indent: usize, compressed: bool) -> fmt::Result {
+fn maybe_indent(
+    buffer: &mut dyn fmt::Write,
+    indent: usize,
+    compressed: bool,
+    indent_style: IndentStyle,
+) -> fmt::Result {
     if !compressed {
-        write!(
-            buffer,
-            "\n{}",
-            " ".repeat(DEFAULT_INDENT_SIZE).repeat(indent)
-        )?;
+        write!(buffer, "\n{}", indent_style.unit().repeat(indent))?;
     }
     Ok(())
 }
@@ -47,10 +130,23 @@ impl<MSG> Node<MSG> {
         buffer: &mut dyn fmt::Write,
         indent: usize,
         compressed: bool,
+    ) -> fmt::Result {
+        self.render_with_options(buffer, indent, compressed, &SerializeOptions::default())
+    }
+
+    /// render the node to a writable buffer, with the serialize options
+    pub fn render_with_options(
+        &self,
+        buffer: &mut dyn fmt::Write,
+        indent: usize,
+        compressed: bool,
+        options: &SerializeOptions,
     ) -> fmt::Result {
         match self {
-            Node::Element(element) => element.render_with_indent(buffer, indent, compressed),
-            Node::Leaf(leaf) => leaf.render_with_indent(buffer, indent, compressed),
+            Node::Element(element) => {
+                element.render_with_options(buffer, indent, compressed, options)
+            }
+            Node::Leaf(leaf) => leaf.render_with_options(buffer, indent, compressed, options),
         }
     }
 
@@ -77,6 +173,84 @@ impl<MSG> Node<MSG> {
         self.render(&mut buffer).expect("must render");
         buffer
     }
+
+    /// render compressed html to string, with the serialize options
+    pub fn render_to_string_with_options(&self, options: &SerializeOptions) -> String {
+        let mut buffer = String::new();
+        self.render_with_options(&mut buffer, 0, true, options)
+            .expect("must render");
+        buffer
+    }
+
+    /// render to string with nice indention, with the serialize options
+    pub fn render_to_string_pretty_with_options(&self, options: &SerializeOptions) -> String {
+        let mut buffer = String::new();
+        self.render_with_options(&mut buffer, 0, false, options)
+            .expect("must render");
+        buffer
+    }
+
+    /// render only the children of this node, without the node itself
+    pub fn render_inner_with_indent(
+        &self,
+        buffer: &mut dyn fmt::Write,
+        indent: usize,
+        compressed: bool,
+    ) -> fmt::Result {
+        match self {
+            Node::Element(element) => element.render_inner_with_indent(buffer, indent, compressed),
+            Node::Leaf(Leaf::Fragment(nodes)) | Node::Leaf(Leaf::NodeList(nodes)) => {
+                render_nodes(nodes, buffer, indent, compressed)
+            }
+            Node::Leaf(Leaf::StatelessComponent(comp)) => comp
+                .view
+                .render_inner_with_indent(buffer, indent, compressed),
+            Node::Leaf(Leaf::TemplatedView(view)) => view
+                .view
+                .render_inner_with_indent(buffer, indent, compressed),
+            Node::Leaf(Leaf::Shared(node)) => {
+                node.render_inner_with_indent(buffer, indent, compressed)
+            }
+            Node::Leaf(Leaf::Suspense(suspense)) => suspense
+                .view
+                .render_inner_with_indent(buffer, indent, compressed),
+            // the rest of the leaf nodes has no children
+            Node::Leaf(_) => Ok(()),
+        }
+    }
+
+    /// render the children of this node into compressed html, without the node itself.
+    /// This is the counterpart of `render_to_string` which also includes the node itself
+    pub fn inner_html(&self) -> String {
+        let mut buffer = String::new();
+        self.render_inner_with_indent(&mut buffer, 0, true)
+            .expect("must render");
+        buffer
+    }
+
+    /// render the children of this node with nice indention, without the node itself
+    pub fn inner_html_pretty(&self) -> String {
+        let mut buffer = String::new();
+        self.render_inner_with_indent(&mut buffer, 0, false)
+            .expect("must render");
+        buffer
+    }
+}
+
+/// render the nodes one after the other, each on its own line when not compressed
+fn render_nodes<MSG>(
+    nodes: &[Node<MSG>],
+    buffer: &mut dyn fmt::Write,
+    indent: usize,
+    compressed: bool,
+) -> fmt::Result {
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            maybe_indent(buffer, indent, compressed, IndentStyle::default())?;
+        }
+        node.render_with_indent(buffer, indent, compressed)?;
+    }
+    Ok(())
 }
 
 impl<MSG> Leaf<MSG> {
@@ -86,9 +260,23 @@ impl<MSG> Leaf<MSG> {
         buffer: &mut dyn fmt::Write,
         indent: usize,
         compressed: bool,
+    ) -> fmt::Result {
+        self.render_with_options(buffer, indent, compressed, &SerializeOptions::default())
+    }
+
+    /// render leaf nodes, with the serialize options
+    pub fn render_with_options(
+        &self,
+        buffer: &mut dyn fmt::Write,
+        indent: usize,
+        compressed: bool,
+        options: &SerializeOptions,
     ) -> fmt::Result {
         match self {
-            Leaf::Text(text) => {
+            Leaf::Text(text) if options.normalize_whitespace => {
+                write!(buffer, "{}", normalize_whitespace(text))
+            }
+            Leaf::Text(text) | Leaf::PreText(text) => {
                 write!(buffer, "{text}")
             }
             Leaf::Symbol(symbol) => {
@@ -102,21 +290,27 @@ impl<MSG> Leaf<MSG> {
             }
             Leaf::Fragment(nodes) => {
                 for node in nodes {
-                    node.render_with_indent(buffer, indent, compressed)?;
+                    node.render_with_options(buffer, indent, compressed, options)?;
                 }
                 Ok(())
             }
             Leaf::NodeList(node_list) => {
                 for node in node_list {
-                    node.render_with_indent(buffer, indent, compressed)?;
+                    node.render_with_options(buffer, indent, compressed, options)?;
                 }
                 Ok(())
             }
             Leaf::StatefulComponent(_comp) => {
                 write!(buffer, "<!-- stateful component -->")
             }
-            Leaf::StatelessComponent(comp) => comp.view.render(buffer),
-            Leaf::TemplatedView(view) => view.view.render(buffer),
+            Leaf::StatelessComponent(comp) => {
+                comp.view.render_with_options(buffer, 0, false, options)
+            }
+            Leaf::TemplatedView(view) => view.view.render_with_options(buffer, 0, false, options),
+            Leaf::Shared(node) => node.render_with_options(buffer, indent, compressed, options),
+            Leaf::Suspense(suspense) => suspense
+                .view
+                .render_with_options(buffer, indent, compressed, options),
         }
     }
 }
@@ -129,10 +323,29 @@ impl<MSG> Element<MSG> {
         indent: usize,
         compressed: bool,
     ) -> fmt::Result {
+        self.render_with_options(buffer, indent, compressed, &SerializeOptions::default())
+    }
+
+    /// render element nodes, with the serialize options
+    pub fn render_with_options(
+        &self,
+        buffer: &mut dyn fmt::Write,
+        indent: usize,
+        compressed: bool,
+        options: &SerializeOptions,
+    ) -> fmt::Result {
+        let child_options = options.for_children_of(self.tag());
         write!(buffer, "<{}", self.tag())?;
 
-        let merged_attributes: Vec<Attribute<MSG>> =
-            Attribute::merge_attributes_of_same_name(self.attributes().iter());
+        // the portal target is only used when mounting into the dom
+        let mut merged_attributes: Vec<Attribute<MSG>> = Attribute::merge_attributes_of_same_name(
+            self.attributes()
+                .iter()
+                .filter(|attr| attr.name() != PORTAL_TARGET),
+        );
+        if options.sort_attributes {
+            merged_attributes.sort_by_key(|attr| *attr.name());
+        }
 
         for attr in &merged_attributes {
             write!(buffer, " ")?;
@@ -155,18 +368,24 @@ impl<MSG> Element<MSG> {
         if is_lone_child_text_node {
             first_child
                 .unwrap()
-                .render_with_indent(buffer, indent, compressed)?;
+                .render_with_options(buffer, indent, compressed, &child_options)?;
         } else {
-            // otherwise print all child nodes with each line and indented
+            // otherwise print all child nodes with each line and indented,
+            // except right before and after a preformatted text which is kept as is
+            let mut prev_is_pre_text = false;
             for child in self.children() {
-                maybe_indent(buffer, indent + 1, compressed)?;
-                child.render_with_indent(buffer, indent + 1, compressed)?;
+                if !prev_is_pre_text && !child.is_pre_text() {
+                    maybe_indent(buffer, indent + 1, compressed, options.indent)?;
+                }
+                child.render_with_options(buffer, indent + 1, compressed, &child_options)?;
+                prev_is_pre_text = child.is_pre_text();
             }
         }
 
         // do not make a new line it if is only a text child node or it has no child nodes
-        if !is_lone_child_text_node && !children.is_empty() {
-            maybe_indent(buffer, indent, compressed)?;
+        let last_is_pre_text = children.last().is_some_and(Node::is_pre_text);
+        if !is_lone_child_text_node && !children.is_empty() && !last_is_pre_text {
+            maybe_indent(buffer, indent, compressed, options.indent)?;
         }
 
         if !self.self_closing {
@@ -176,6 +395,33 @@ impl<MSG> Element<MSG> {
     }
 }
 
+impl<MSG> Element<MSG> {
+    /// render only the children of this element, without the element tag
+    pub fn render_inner_with_indent(
+        &self,
+        buffer: &mut dyn fmt::Write,
+        indent: usize,
+        compressed: bool,
+    ) -> fmt::Result {
+        render_nodes(self.children(), buffer, indent, compressed)
+    }
+}
+
+/// the prefix used for serializing attributes of the well known namespaces
+fn namespace_prefix(namespace: &str) -> Option<&'static str> {
+    match namespace {
+        "http://www.w3.org/1999/xlink" => Some("xlink"),
+        "http://www.w3.org/XML/1998/namespace" => Some("xml"),
+        "http://www.w3.org/2000/xmlns/" => Some("xmlns"),
+        _ => None,
+    }
+}
+
+/// escape the characters which would otherwise end or alter a double quoted attribute value
+fn escape_attribute_value(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;")
+}
+
 impl<MSG> Attribute<MSG> {
     /// render attributes
     fn render(&self, buffer: &mut dyn fmt::Write) -> fmt::Result {
@@ -194,9 +440,11 @@ impl<MSG> Attribute<MSG> {
         // https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#attr-fe-disabled
         let boolean_attributes = ["open", "checked", "disabled"];
 
+        // a value other than a bool, such as `disabled="disabled"` or an empty value,
+        // is present and therefore true
         let bool_value: bool = plain_values
             .first()
-            .and_then(|v| v.as_bool())
+            .map(|v| v.as_bool().unwrap_or(true))
             .unwrap_or(false);
 
         // skip this attribute if the boolean attributes evaluates to false
@@ -204,15 +452,33 @@ impl<MSG> Attribute<MSG> {
 
         if !should_skip_attribute {
             if let Some(merged_plain_values) = Value::merge_to_string(plain_values) {
-                write!(buffer, "{}=\"{}\"", self.name(), merged_plain_values)?;
+                self.render_qualified_name(buffer)?;
+                // an empty value of a boolean attribute is the same as its presence,
+                // the other attributes keep their empty value such as `alt=""`
+                if !merged_plain_values.is_empty() || !is_boolean_attribute(self.name()) {
+                    write!(
+                        buffer,
+                        "=\"{}\"",
+                        escape_attribute_value(&merged_plain_values)
+                    )?;
+                }
             }
             if let Some(merged_styles) = Style::merge_to_string(styles) {
-                write!(buffer, "{}=\"{}\"", self.name(), merged_styles)?;
+                self.render_qualified_name(buffer)?;
+                write!(buffer, "=\"{}\"", escape_attribute_value(&merged_styles))?;
             }
         }
         Ok(())
     }
 
+    /// render the name of the attribute prefixed with its namespace, ie: `xlink:href`
+    fn render_qualified_name(&self, buffer: &mut dyn fmt::Write) -> fmt::Result {
+        match self.namespace.and_then(namespace_prefix) {
+            Some(prefix) if !self.name.contains(':') => write!(buffer, "{prefix}:{}", self.name),
+            _ => write!(buffer, "{}", self.name),
+        }
+    }
+
     /// render compressed html to string
     pub fn render_to_string(&self) -> String {
         let mut buffer = String::new();
@@ -255,6 +521,103 @@ mod test {
         assert_eq!(expected, buffer);
     }
 
+    #[test]
+    fn test_inner_html() {
+        let view: Node<()> = div(
+            vec![class("container")],
+            vec![
+                h1(vec![], vec![text("title")]),
+                p(vec![], vec![text("content")]),
+            ],
+        );
+        assert_eq!(view.inner_html(), "<h1>title</h1><p>content</p>");
+        assert_eq!(view.inner_html_pretty(), "<h1>title</h1>\n<p>content</p>");
+        assert_eq!(text::<()>("hello").inner_html(), "");
+    }
+
+    #[test]
+    fn test_render_normalize_whitespace() {
+        let view: Node<()> = div(
+            vec![],
+            vec![
+                p(vec![], vec![text("  hello \n\t world  ")]),
+                pre(vec![], vec![text("  keep \n  this")]),
+                textarea(vec![], vec![text("a  b")]),
+            ],
+        );
+        let options = SerializeOptions {
+            normalize_whitespace: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            view.render_to_string_with_options(&options),
+            "<div><p> hello world </p><pre>  keep \n  this</pre><textarea>a  b</textarea></div>"
+        );
+        assert_eq!(
+            view.render_to_string_with_options(&SerializeOptions::default()),
+            view.render_to_string()
+        );
+    }
+
+    #[test]
+    fn test_render_indent_style() {
+        let view: Node<()> = div(
+            vec![],
+            vec![ul(vec![], vec![li(vec![], vec![text("item")])])],
+        );
+        let tabs = SerializeOptions {
+            indent: IndentStyle::Tabs,
+            ..Default::default()
+        };
+        assert_eq!(
+            view.render_to_string_pretty_with_options(&tabs),
+            "<div>\n\t<ul>\n\t\t<li>item</li>\n\t</ul>\n</div>"
+        );
+        let four_spaces = SerializeOptions {
+            indent: IndentStyle::Spaces(4),
+            ..Default::default()
+        };
+        assert_eq!(
+            view.render_to_string_pretty_with_options(&four_spaces),
+            "<div>\n    <ul>\n        <li>item</li>\n    </ul>\n</div>"
+        );
+        assert_eq!(
+            view.render_to_string_pretty_with_options(&SerializeOptions::default()),
+            view.render_to_string_pretty()
+        );
+    }
+
+    #[test]
+    fn test_render_sort_attributes() {
+        let view: Node<()> = div(
+            vec![
+                id("main"),
+                class("frame"),
+                attr("data-b", 2),
+                attr("data-a", 1),
+            ],
+            vec![input(vec![r#type("text"), attr("aria-label", "name")])],
+        );
+        let sorted = SerializeOptions {
+            sort_attributes: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            view.render_to_string_with_options(&sorted),
+            r#"<div class="frame" data-a="1" data-b="2" id="main"><input aria-label="name" type="text"/></div>"#
+        );
+        assert_eq!(
+            view.render_to_string(),
+            r#"<div id="main" class="frame" data-b="2" data-a="1"><input type="text" aria-label="name"/></div>"#
+        );
+    }
+
+    #[test]
+    fn test_render_portal_skips_the_portal_target() {
+        let view: Node<()> = portal("#modals", [span([], [text("hi")])]);
+        assert_eq!(view.render_to_string(), "<div><span>hi</span></div>");
+    }
+
     #[test]
     fn test_render_class_flag() {
         let view: Node<()> = div(
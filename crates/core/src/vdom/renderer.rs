@@ -0,0 +1,288 @@
+//! The backend which the patches from the diff are applied to, such as the browser DOM
+//! or a native GUI toolkit
+use crate::vdom::{Attribute, Node, Patch, PatchType, TreePath};
+use indexmap::IndexMap;
+
+/// A backend which renders the nodes and applies the [`Patch`]es of the diff to them,
+/// so the same diff engine drives the browser DOM and the other backends such as a canvas
+/// or a native GUI toolkit.
+///
+/// There is a method for each of the [`PatchType`]s, see [`apply_patches`] which finds the
+/// target nodes of the patches and calls these methods through [`RenderPatch`].
+/// The `Program` implements this for the browser DOM, which is the default backend.
+///
+/// The methods take `&self` since the nodes are usually handles into the backend,
+/// use interior mutability when the backend needs to mutate its own state.
+pub trait Renderer<MSG> {
+    /// the node of the backend, which is a handle to the created node that is cheap to clone
+    type Node: Clone;
+    /// the attribute of the backend, which is created before it is set to a node
+    type Attribute;
+    /// the error when a patch can not be applied
+    type Error;
+
+    /// create the backend node of the virtual `node`, together with its descendants
+    fn create_node(&self, node: &Node<MSG>) -> Self::Node;
+
+    /// create the backend attribute of the virtual `attr`
+    fn create_attribute(&self, attr: &Attribute<MSG>) -> Self::Attribute;
+
+    /// the child of the `node` at `index`, which is used to find the target of a patch
+    /// from its [`TreePath`]
+    fn child_node(&self, node: &Self::Node, index: usize) -> Option<Self::Node>;
+
+    /// insert the `nodes` into the `parent`, right before its child `target`
+    fn insert_before(
+        &self,
+        parent: &Self::Node,
+        target: &Self::Node,
+        nodes: Vec<Self::Node>,
+    ) -> Result<(), Self::Error>;
+
+    /// insert the `nodes` into the `parent`, right after its child `target`
+    fn insert_after(
+        &self,
+        parent: &Self::Node,
+        target: &Self::Node,
+        nodes: Vec<Self::Node>,
+    ) -> Result<(), Self::Error>;
+
+    /// append the `children` at the end of the children of `target`
+    fn append_children(
+        &self,
+        target: &Self::Node,
+        children: Vec<Self::Node>,
+    ) -> Result<(), Self::Error>;
+
+    /// remove all the children of `target`
+    fn clear_children(&self, target: &Self::Node) -> Result<(), Self::Error>;
+
+    /// remove the child `target` from the `parent`, it is not used again
+    fn remove_node(&self, parent: &Self::Node, target: &Self::Node) -> Result<(), Self::Error>;
+
+    /// move the existing children `nodes` of the `parent` before its child `target`
+    fn move_before(
+        &self,
+        parent: &Self::Node,
+        target: &Self::Node,
+        nodes: Vec<Self::Node>,
+    ) -> Result<(), Self::Error>;
+
+    /// move the existing children `nodes` of the `parent` after its child `target`
+    fn move_after(
+        &self,
+        parent: &Self::Node,
+        target: &Self::Node,
+        nodes: Vec<Self::Node>,
+    ) -> Result<(), Self::Error>;
+
+    /// replace the child `target` of the `parent` with the `replacement` nodes, which is never empty
+    fn replace_node(
+        &self,
+        parent: &Self::Node,
+        target: &Self::Node,
+        replacement: Vec<Self::Node>,
+    ) -> Result<(), Self::Error>;
+
+    /// set the attributes of `target`, the attributes of the same name are already merged
+    fn set_attributes(
+        &self,
+        target: &Self::Node,
+        attrs: Vec<Self::Attribute>,
+    ) -> Result<(), Self::Error>;
+
+    /// remove the attributes from `target`, including the event listeners among them
+    fn remove_attributes(
+        &self,
+        target: &Self::Node,
+        attrs: Vec<Self::Attribute>,
+    ) -> Result<(), Self::Error>;
+}
+
+/// A [`Patch`] which nodes and attributes are already created in the backend,
+/// so it doesn't borrow the views anymore and can be applied later,
+/// such as in the next animation frame.
+#[derive(Debug)]
+pub enum RenderPatch<N, A> {
+    /// Insert nodes before the target node
+    InsertBeforeNode {
+        /// nodes to be inserted before the target node
+        nodes: Vec<N>,
+    },
+    /// Insert nodes after the target node
+    InsertAfterNode {
+        /// the nodes to be inserted after the target node
+        nodes: Vec<N>,
+    },
+    /// Append nodes into the target node
+    AppendChildren {
+        /// the children nodes to be appended into the target node
+        children: Vec<N>,
+    },
+    /// Add attributes to the target node
+    AddAttributes {
+        /// the attributes to be added to the target node
+        attrs: Vec<A>,
+    },
+    /// Remove attributes from the target node
+    RemoveAttributes {
+        /// the attributes to be removed
+        attrs: Vec<A>,
+    },
+    /// Replace the target node with the replacement node
+    ReplaceNode {
+        /// the replacement node
+        replacement: Vec<N>,
+    },
+    /// Remove the target node
+    RemoveNode,
+    /// Clear the children of the target node
+    ClearChildren,
+    /// Move the target node before the node specified in the path location
+    MoveBeforeNode {
+        /// before the node at this location
+        for_moving: Vec<N>,
+    },
+    /// Move the target node after the node specified in the path location
+    MoveAfterNode {
+        /// after the node at this location
+        for_moving: Vec<N>,
+    },
+}
+
+impl<N, A> RenderPatch<N, A> {
+    /// create the nodes and attributes of the `patch` with the `renderer`,
+    /// `find_node` returns the backend node at the path of the nodes which are moved
+    pub fn create<MSG, R>(
+        renderer: &R,
+        patch: &Patch<MSG>,
+        find_node: impl Fn(&TreePath) -> N,
+    ) -> Self
+    where
+        R: Renderer<MSG, Node = N, Attribute = A>,
+    {
+        let create_nodes = |nodes: &mut dyn Iterator<Item = &Node<MSG>>| {
+            nodes.map(|node| renderer.create_node(node)).collect()
+        };
+        match &patch.patch_type {
+            PatchType::InsertBeforeNode { nodes } => Self::InsertBeforeNode {
+                nodes: create_nodes(&mut nodes.iter().map(|node| node.as_ref())),
+            },
+            PatchType::InsertAfterNode { nodes } => Self::InsertAfterNode {
+                nodes: create_nodes(&mut nodes.iter().copied()),
+            },
+            PatchType::AppendChildren { children } => Self::AppendChildren {
+                children: create_nodes(&mut children.iter().copied()),
+            },
+            PatchType::AddAttributes { attrs } => {
+                // the attributes of the same name are merged prior to creating them
+                let attrs = Attribute::merge_attributes_of_same_name(attrs.iter().copied());
+                Self::AddAttributes {
+                    attrs: attrs
+                        .iter()
+                        .map(|attr| renderer.create_attribute(attr))
+                        .collect(),
+                }
+            }
+            PatchType::RemoveAttributes { attrs } => Self::RemoveAttributes {
+                attrs: attrs
+                    .iter()
+                    .map(|attr| renderer.create_attribute(attr))
+                    .collect(),
+            },
+            PatchType::ReplaceNode { replacement } => Self::ReplaceNode {
+                replacement: create_nodes(&mut replacement.iter().copied()),
+            },
+            PatchType::RemoveNode => Self::RemoveNode,
+            PatchType::ClearChildren => Self::ClearChildren,
+            PatchType::MoveBeforeNode { nodes_path } => Self::MoveBeforeNode {
+                for_moving: nodes_path.iter().map(&find_node).collect(),
+            },
+            PatchType::MoveAfterNode { nodes_path } => Self::MoveAfterNode {
+                for_moving: nodes_path.iter().map(&find_node).collect(),
+            },
+        }
+    }
+
+    /// apply this patch to the `target` node, which is a child of the `parent` node
+    pub fn apply<MSG, R>(self, renderer: &R, parent: &N, target: &N) -> Result<(), R::Error>
+    where
+        R: Renderer<MSG, Node = N, Attribute = A>,
+    {
+        match self {
+            Self::InsertBeforeNode { nodes } => renderer.insert_before(parent, target, nodes),
+            Self::InsertAfterNode { nodes } => renderer.insert_after(parent, target, nodes),
+            Self::AppendChildren { children } => renderer.append_children(target, children),
+            Self::AddAttributes { attrs } => renderer.set_attributes(target, attrs),
+            Self::RemoveAttributes { attrs } => renderer.remove_attributes(target, attrs),
+            Self::ReplaceNode { replacement } => renderer.replace_node(parent, target, replacement),
+            Self::RemoveNode => renderer.remove_node(parent, target),
+            Self::ClearChildren => renderer.clear_children(target),
+            Self::MoveBeforeNode { for_moving } => renderer.move_before(parent, target, for_moving),
+            Self::MoveAfterNode { for_moving } => renderer.move_after(parent, target, for_moving),
+        }
+    }
+}
+
+/// Apply the `patches` to the `root` node of a backend, which is a child of the `mount` node,
+/// and return the root node after the patches since the root itself may be replaced.
+///
+/// The target nodes of all the patches are found before any of them is applied,
+/// since the paths of the patches are the locations of the nodes in the old view.
+///
+/// # Panics
+/// Panics if there is no node at the path of a patch, which means the backend nodes
+/// don't match the old view the patches were created from.
+pub fn apply_patches<MSG, R>(
+    renderer: &R,
+    mount: &R::Node,
+    root: &R::Node,
+    patches: &[Patch<MSG>],
+) -> Result<R::Node, R::Error>
+where
+    R: Renderer<MSG>,
+{
+    let nodes_lookup: IndexMap<&TreePath, (R::Node, R::Node)> = patches
+        .iter()
+        .map(|patch| patch.path())
+        .chain(patches.iter().flat_map(|patch| patch.node_paths()))
+        .map(|path| {
+            let found = find_node(renderer, mount, root, path).unwrap_or_else(|| {
+                panic!("there is no node at path: {path:?}, the nodes don't match the old view")
+            });
+            (path, found)
+        })
+        .collect();
+    let mut root = root.clone();
+    for patch in patches {
+        let (target, parent) = &nodes_lookup[patch.path()];
+        let render_patch =
+            RenderPatch::create(renderer, patch, |path| nodes_lookup[path].0.clone());
+        if let RenderPatch::ReplaceNode { replacement } = &render_patch {
+            if patch.path().is_empty() {
+                root = replacement[0].clone();
+            }
+        }
+        render_patch.apply(renderer, parent, target)?;
+    }
+    Ok(root)
+}
+
+/// find the node at the `path` and its parent
+fn find_node<MSG, R>(
+    renderer: &R,
+    mount: &R::Node,
+    root: &R::Node,
+    path: &TreePath,
+) -> Option<(R::Node, R::Node)>
+where
+    R: Renderer<MSG>,
+{
+    let mut parent = mount.clone();
+    let mut node = root.clone();
+    for idx in path.path.iter() {
+        let child = renderer.child_node(&node, *idx)?;
+        parent = std::mem::replace(&mut node, child);
+    }
+    Some((node, parent))
+}
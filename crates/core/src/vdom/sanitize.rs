@@ -0,0 +1,378 @@
+//! Sanitize a node tree which is built from untrusted content,
+//! such as html parsed from user input.
+use crate::vdom::{Attribute, AttributeName, AttributeValue, Element, Leaf, Node, Tag};
+use std::rc::Rc;
+
+/// Tags which are removed together with their children by the default policy
+pub const DEFAULT_BLOCKED_TAGS: &[Tag] = &[
+    "script",
+    "style",
+    "iframe",
+    "frame",
+    "frameset",
+    "object",
+    "embed",
+    "applet",
+    "base",
+    "link",
+    "meta",
+    "noscript",
+    "template",
+    "svg",
+    "math",
+    "animate",
+    "set",
+    "animateMotion",
+    "animateTransform",
+];
+
+/// Url schemes allowed by the default policy.
+/// Relative urls, which have no scheme, are always allowed.
+pub const DEFAULT_ALLOWED_URL_SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// Attributes which value is treated as a url
+pub const DEFAULT_URL_ATTRIBUTES: &[AttributeName] = &[
+    "href",
+    "src",
+    "action",
+    "formaction",
+    "poster",
+    "background",
+    "cite",
+    "data",
+    "srcset",
+    "xlink:href",
+];
+
+/// Attributes of the svg animation elements, such as `<animate>` and `<set>`,
+/// which set the value of the animated attribute
+const ANIMATION_VALUE_ATTRIBUTES: &[AttributeName] = &["values", "to", "from", "by"];
+
+/// Removes event handlers, disallowed tags and unsafe urls from a node tree.
+///
+/// The default policy:
+/// - removes all event listeners and any attribute which name starts with `on`, ie: `onclick`
+/// - removes the elements in [`DEFAULT_BLOCKED_TAGS`] together with all of their children,
+///   such as `script`, `style`, `iframe`, `object` and `embed`
+/// - removes url attributes ([`DEFAULT_URL_ATTRIBUTES`]) which scheme is not
+///   in [`DEFAULT_ALLOWED_URL_SCHEMES`], such as `javascript:`, `vbscript:` and `data:` urls.
+///   Each of the candidates of a `srcset` is checked, and so are the `url(..)` in the
+///   `style` attribute and the values of an svg animation of a url attribute
+/// - removes symbols which contain markup or a stray `&`, since symbols are inserted as raw html
+///
+/// Components and templated views are sanitized through their views.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sanitizer {
+    /// when set, only elements with these tags are kept,
+    /// the rest are removed together with their children
+    pub allowed_tags: Option<Vec<Tag>>,
+    /// elements with these tags are removed together with their children
+    pub blocked_tags: Vec<Tag>,
+    /// the url schemes allowed in url attributes
+    pub allowed_url_schemes: Vec<&'static str>,
+    /// the attributes which value is treated as a url
+    pub url_attributes: Vec<AttributeName>,
+}
+
+impl Default for Sanitizer {
+    fn default() -> Self {
+        Self {
+            allowed_tags: None,
+            blocked_tags: DEFAULT_BLOCKED_TAGS.to_vec(),
+            allowed_url_schemes: DEFAULT_ALLOWED_URL_SCHEMES.to_vec(),
+            url_attributes: DEFAULT_URL_ATTRIBUTES.to_vec(),
+        }
+    }
+}
+
+impl Sanitizer {
+    /// create a sanitizer with the default policy
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// only keep the elements with these tags
+    pub fn with_allowed_tags(mut self, tags: impl IntoIterator<Item = Tag>) -> Self {
+        self.allowed_tags = Some(tags.into_iter().collect());
+        self
+    }
+
+    /// remove elements with this tag in addition to the blocked tags
+    pub fn with_blocked_tag(mut self, tag: Tag) -> Self {
+        self.blocked_tags.push(tag);
+        self
+    }
+
+    /// allow this url scheme in addition to the allowed url schemes
+    pub fn with_allowed_url_scheme(mut self, scheme: &'static str) -> Self {
+        self.allowed_url_schemes.push(scheme);
+        self
+    }
+
+    /// returns true if elements with this tag are kept
+    pub fn is_tag_allowed(&self, tag: &Tag) -> bool {
+        let blocked = self
+            .blocked_tags
+            .iter()
+            .any(|blocked| blocked.eq_ignore_ascii_case(tag));
+        let allowed = match &self.allowed_tags {
+            Some(allowed_tags) => allowed_tags
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(tag)),
+            None => true,
+        };
+        allowed && !blocked
+    }
+
+    /// returns true if this url has no scheme or the scheme is allowed
+    pub fn is_url_allowed(&self, url: &str) -> bool {
+        // browsers ignore leading whitespace and control characters,
+        // and also the tabs and newlines inside the scheme
+        let url: String = url
+            .trim_start_matches(|c: char| c.is_ascii_whitespace() || c.is_ascii_control())
+            .chars()
+            .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+            .collect();
+        match url.find([':', '/', '?', '#']) {
+            Some(i) if url[i..].starts_with(':') => {
+                let scheme = &url[..i];
+                self.allowed_url_schemes
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(scheme))
+            }
+            _ => true,
+        }
+    }
+
+    /// returns true if this attribute is kept
+    pub fn is_attribute_allowed<MSG>(&self, attr: &Attribute<MSG>) -> bool {
+        let is_handler = attr.name.len() > 2 && attr.name[..2].eq_ignore_ascii_case("on");
+        let has_callback = attr.value.iter().any(|v| {
+            matches!(
+                v,
+                AttributeValue::EventListener(_) | AttributeValue::ComponentEventListener(_)
+            )
+        });
+        if is_handler || has_callback {
+            return false;
+        }
+        if attr.name.eq_ignore_ascii_case("style") {
+            return attr.value.iter().all(|v| match v {
+                AttributeValue::Simple(v) => self.is_style_allowed(&v.to_string()),
+                AttributeValue::Style(styles) => styles
+                    .iter()
+                    .all(|style| self.is_style_allowed(&style.value.to_string())),
+                _ => true,
+            });
+        }
+        if !self.is_url_attribute(attr.name) {
+            return true;
+        }
+        let is_srcset = attr.name.eq_ignore_ascii_case("srcset");
+        attr.value.iter().all(|v| match v {
+            AttributeValue::Simple(v) if is_srcset => srcset_urls(&v.to_string())
+                .iter()
+                .all(|url| self.is_url_allowed(url)),
+            AttributeValue::Simple(v) => self.is_url_allowed(&v.to_string()),
+            _ => true,
+        })
+    }
+
+    fn is_url_attribute(&self, name: &str) -> bool {
+        self.url_attributes
+            .iter()
+            .any(|url_attr| url_attr.eq_ignore_ascii_case(name))
+    }
+
+    /// returns true if every `url(..)` in the css is allowed
+    fn is_style_allowed(&self, css: &str) -> bool {
+        let lowercase = css.to_ascii_lowercase();
+        let mut rest = lowercase.as_str();
+        while let Some(start) = rest.find("url(") {
+            rest = &rest[start + 4..];
+            let end = rest.find(')').unwrap_or(rest.len());
+            let url = rest[..end].trim().trim_matches(['"', '\'']);
+            // css escapes can hide the scheme, ie: `url(\6a avascript:..)`
+            if url.contains('\\') || !self.is_url_allowed(url) {
+                return false;
+            }
+            rest = &rest[end..];
+        }
+        true
+    }
+
+    /// returns true if the attribute of an svg animation element with the attributes `attrs`
+    /// is kept. The values of an animation of a url attribute, such as
+    /// `<animate attributeName="href" values="..">`, are checked as urls.
+    fn is_animation_value_allowed<MSG>(
+        &self,
+        attr: &Attribute<MSG>,
+        attrs: &[Attribute<MSG>],
+    ) -> bool {
+        let is_animation_value = ANIMATION_VALUE_ATTRIBUTES
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(attr.name));
+        let animates_url = attrs.iter().any(|a| {
+            a.name.eq_ignore_ascii_case("attributeName")
+                && a.value.iter().any(|v| match v {
+                    AttributeValue::Simple(v) => self.is_url_attribute(v.to_string().trim()),
+                    _ => false,
+                })
+        });
+        if !is_animation_value || !animates_url {
+            return true;
+        }
+        attr.value.iter().all(|v| match v {
+            AttributeValue::Simple(v) => v
+                .to_string()
+                .split(';')
+                .all(|url| self.is_url_allowed(url.trim())),
+            _ => true,
+        })
+    }
+
+    /// sanitize the node, returns None if the whole node is removed
+    pub fn sanitize<MSG>(&self, node: Node<MSG>) -> Option<Node<MSG>> {
+        match node {
+            Node::Element(elm) => self.sanitize_element(elm).map(Node::Element),
+            Node::Leaf(leaf) => self.sanitize_leaf(leaf).map(Node::Leaf),
+        }
+    }
+
+    fn sanitize_nodes<MSG>(&self, nodes: Vec<Node<MSG>>) -> Vec<Node<MSG>> {
+        nodes.into_iter().filter_map(|n| self.sanitize(n)).collect()
+    }
+
+    fn sanitize_element<MSG>(&self, elm: Element<MSG>) -> Option<Element<MSG>> {
+        if !self.is_tag_allowed(&elm.tag) {
+            return None;
+        }
+        let Element {
+            namespace,
+            tag,
+            attrs,
+            children,
+            self_closing,
+        } = elm;
+        Some(Element {
+            namespace,
+            tag,
+            attrs: attrs
+                .iter()
+                .filter(|a| self.is_attribute_allowed(a))
+                .filter(|a| self.is_animation_value_allowed(a, &attrs))
+                .cloned()
+                .collect(),
+            children: self.sanitize_nodes(children),
+            self_closing,
+        })
+    }
+
+    fn sanitize_leaf<MSG>(&self, leaf: Leaf<MSG>) -> Option<Leaf<MSG>> {
+        match leaf {
+            Leaf::Symbol(symbol) => is_entity_text(&symbol).then_some(Leaf::Symbol(symbol)),
+            Leaf::NodeList(nodes) => Some(Leaf::NodeList(self.sanitize_nodes(nodes))),
+            Leaf::Fragment(nodes) => Some(Leaf::Fragment(self.sanitize_nodes(nodes))),
+            #[cfg(feature = "with-dom")]
+            Leaf::StatefulComponent(mut comp) => {
+                comp.attrs.retain(|a| self.is_attribute_allowed(a));
+                comp.children = self.sanitize_nodes(comp.children);
+                Some(Leaf::StatefulComponent(comp))
+            }
+            Leaf::StatelessComponent(mut comp) => {
+                *comp.view = self.sanitize(*comp.view)?;
+                Some(Leaf::StatelessComponent(comp))
+            }
+            Leaf::TemplatedView(mut view) => {
+                *view.view = self.sanitize(*view.view)?;
+                Some(Leaf::TemplatedView(view))
+            }
+            Leaf::Shared(node) => {
+                let node = Rc::try_unwrap(node).unwrap_or_else(|node| (*node).clone());
+                Some(Leaf::Shared(Rc::new(self.sanitize(node)?)))
+            }
+            Leaf::Suspense(mut suspense) => {
+                *suspense.view = self.sanitize(*suspense.view)?;
+                Some(Leaf::Suspense(suspense))
+            }
+            Leaf::Text(_) | Leaf::PreText(_) | Leaf::Comment(_) | Leaf::DocType(_) => Some(leaf),
+        }
+    }
+}
+
+/// the urls of the image candidates in a `srcset`, ie: `a.png 1x, b.png 2x`,
+/// following the parsing of the candidates in the html spec, so a url can have commas
+fn srcset_urls(srcset: &str) -> Vec<&str> {
+    let mut urls = vec![];
+    let mut rest = srcset;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+        if rest.is_empty() {
+            return urls;
+        }
+        let end = rest
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        let url = &rest[..end];
+        rest = &rest[end..];
+        // a url which ends with a comma has no descriptors
+        let trimmed = url.trim_end_matches(',');
+        urls.push(trimmed);
+        if trimmed.len() < url.len() {
+            continue;
+        }
+        // skip the descriptors up to the comma which is not inside parenthesis
+        let mut in_parens = false;
+        let end = rest
+            .find(|c: char| {
+                match c {
+                    '(' => in_parens = true,
+                    ')' => in_parens = false,
+                    _ => (),
+                }
+                c == ',' && !in_parens
+            })
+            .unwrap_or(rest.len());
+        rest = &rest[end..];
+    }
+}
+
+/// returns true if the symbol is plain text where every `&` starts an html entity
+/// such as `&nbsp;` or `&#169;` and there is no markup
+fn is_entity_text(symbol: &str) -> bool {
+    let mut rest = symbol;
+    while let Some(pos) = rest.find(['&', '<', '>']) {
+        rest = &rest[pos..];
+        match entity_len(rest) {
+            Some(len) => rest = &rest[len..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/// returns the length of the html entity at the start of `s`, ie: `&nbsp;` or `&#169;`
+pub(crate) fn entity_len(s: &str) -> Option<usize> {
+    let name = s.strip_prefix('&')?;
+    let end = name.find(';')?;
+    let name = &name[..end];
+    let name = name.strip_prefix('#').unwrap_or(name);
+    (!name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric())).then_some(end + 2)
+}
+
+impl<MSG> Node<MSG> {
+    /// remove event handlers, disallowed tags and unsafe urls using the default policy
+    /// of [`Sanitizer`].
+    /// If the node itself is removed, an empty node list is returned
+    pub fn sanitize(self) -> Self {
+        self.sanitize_with(&Sanitizer::default())
+    }
+
+    /// remove event handlers, disallowed tags and unsafe urls using this `sanitizer`.
+    /// If the node itself is removed, an empty node list is returned
+    pub fn sanitize_with(self, sanitizer: &Sanitizer) -> Self {
+        sanitizer
+            .sanitize(self)
+            .unwrap_or_else(|| Node::Leaf(Leaf::NodeList(vec![])))
+    }
+}
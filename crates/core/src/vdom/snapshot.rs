@@ -0,0 +1,130 @@
+//! A serializable snapshot of a node tree, such as for sending the state of the view
+//! to a logging backend
+use crate::vdom::{Attribute, Element, GroupedAttributeValues, Leaf, Node, Style, Value};
+
+/// A snapshot of a node, which keeps the structure, the attributes and the text of the node
+/// but not its event listeners.
+///
+/// Enable the `serde` feature to serialize and deserialize the snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SerializableNode {
+    /// an element
+    Element(SerializableElement),
+    /// a text node
+    Text(String),
+    /// an html entity such as `&nbsp;`
+    Symbol(String),
+    /// a comment node
+    Comment(String),
+    /// a doctype
+    DocType(String),
+    /// the nodes of a fragment or of a node list
+    Fragment(Vec<SerializableNode>),
+}
+
+/// A snapshot of an element, see [`SerializableNode`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SerializableElement {
+    /// the namespace of the element, such as the svg namespace
+    pub namespace: Option<String>,
+    /// the tag of the element
+    pub tag: String,
+    /// the attributes of the element, the attributes of the same name are merged
+    pub attributes: Vec<SerializableAttribute>,
+    /// the child nodes of the element
+    pub children: Vec<SerializableNode>,
+    /// whether the element is self closing, ie: `<br/>`
+    pub self_closing: bool,
+}
+
+/// A snapshot of an attribute, with its values rendered as in html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SerializableAttribute {
+    /// the namespace of the attribute, such as the xlink namespace
+    pub namespace: Option<String>,
+    /// the name of the attribute
+    pub name: String,
+    /// the value of the attribute
+    pub value: String,
+}
+
+impl<MSG> Node<MSG> {
+    /// Take a snapshot of this node, which can be serialized with the `serde` feature.
+    ///
+    /// The event listeners are omitted since they are closures, and so are the attributes
+    /// which only have event listeners such as `on_click`.
+    /// Components are replaced with their view, except for stateful components which are
+    /// a comment since their view lives in the DOM.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sauron::{html::*, vdom::SerializableNode, *};
+    ///
+    /// let node: Node<()> = button([class("save"), on_click(|_| ())], [text("save")]);
+    /// let SerializableNode::Element(snapshot) = node.to_serializable() else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(snapshot.tag, "button");
+    /// assert_eq!(snapshot.attributes.len(), 1);
+    /// assert_eq!(snapshot.children, [SerializableNode::Text("save".to_string())]);
+    /// ```
+    pub fn to_serializable(&self) -> SerializableNode {
+        match self {
+            Node::Element(element) => SerializableNode::Element(element.to_serializable()),
+            Node::Leaf(leaf) => leaf.to_serializable(),
+        }
+    }
+}
+
+impl<MSG> Element<MSG> {
+    fn to_serializable(&self) -> SerializableElement {
+        let attributes = Attribute::merge_attributes_of_same_name(self.attributes().iter())
+            .iter()
+            .filter_map(|attr| {
+                let GroupedAttributeValues {
+                    plain_values,
+                    styles,
+                    ..
+                } = Attribute::group_values(attr);
+                let value = Value::merge_to_string(plain_values)
+                    .or_else(|| Style::merge_to_string(styles))?;
+                Some(SerializableAttribute {
+                    namespace: attr.namespace.map(ToString::to_string),
+                    name: attr.name.to_string(),
+                    value,
+                })
+            })
+            .collect();
+        SerializableElement {
+            namespace: self.namespace.map(ToString::to_string),
+            tag: self.tag.to_string(),
+            attributes,
+            children: self.children().iter().map(Node::to_serializable).collect(),
+            self_closing: self.self_closing,
+        }
+    }
+}
+
+impl<MSG> Leaf<MSG> {
+    fn to_serializable(&self) -> SerializableNode {
+        match self {
+            Leaf::Text(text) | Leaf::PreText(text) => SerializableNode::Text(text.to_string()),
+            Leaf::Symbol(symbol) => SerializableNode::Symbol(symbol.to_string()),
+            Leaf::Comment(comment) => SerializableNode::Comment(comment.to_string()),
+            Leaf::DocType(doctype) => SerializableNode::DocType(doctype.to_string()),
+            Leaf::Fragment(nodes) | Leaf::NodeList(nodes) => {
+                SerializableNode::Fragment(nodes.iter().map(Node::to_serializable).collect())
+            }
+            Leaf::StatefulComponent(_comp) => {
+                SerializableNode::Comment("stateful component".to_string())
+            }
+            Leaf::StatelessComponent(comp) => comp.view.to_serializable(),
+            Leaf::TemplatedView(view) => view.view.to_serializable(),
+            Leaf::Shared(node) => node.to_serializable(),
+            Leaf::Suspense(suspense) => suspense.view.to_serializable(),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Suspense, shows a pending node until the future of the actual node is resolved
+use crate::vdom::{Leaf, Node};
+use derive_where::derive_where;
+use futures::future::{abortable, AbortHandle};
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+
+/// A future which stores the resolved node into its suspense,
+/// resolving to true if the view needs to be re-rendered
+type ResolveTask = Pin<Box<dyn Future<Output = bool>>>;
+
+/// Shows a pending node until the future of the actual node is resolved.
+///
+/// The future is spawned by the `Program` the first time the pending node is created in the DOM,
+/// once it resolves the `Program` re-renders the view, which then contains the resolved node.
+///
+/// Create the suspense once, such as in `init` or `update`, store it in the model and use
+/// [`Suspense::view`] in the view. Creating the suspense inside the view creates a new future on
+/// every render, which would never get to be shown.
+///
+/// Cancellation: when all the clones of the suspense are dropped before the future is resolved,
+/// such as when it is removed from the model, the future is aborted and no re-render happens.
+/// Merely removing the suspense from the view does not cancel the future.
+#[derive_where(Clone)]
+pub struct Suspense<MSG> {
+    inner: Rc<Inner<MSG>>,
+}
+
+struct Inner<MSG> {
+    pending: Node<MSG>,
+    resolved: RefCell<Option<Node<MSG>>>,
+    task: SuspenseTask,
+    abort_handle: AbortHandle,
+}
+
+impl<MSG> Drop for Inner<MSG> {
+    fn drop(&mut self) {
+        self.abort_handle.abort();
+    }
+}
+
+/// The task which resolves a suspense,
+/// it is taken by the first `Program` that creates the pending node of the suspense.
+#[derive(Clone)]
+pub struct SuspenseTask(Rc<RefCell<Option<ResolveTask>>>);
+
+impl SuspenseTask {
+    /// take the task out, returns None if it is already taken
+    pub(crate) fn take(&self) -> Option<ResolveTask> {
+        self.0.borrow_mut().take()
+    }
+}
+
+impl PartialEq for SuspenseTask {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for SuspenseTask {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SuspenseTask")
+    }
+}
+
+/// The view of a suspense which has not been resolved yet
+#[derive_where(Clone, Debug)]
+pub struct SuspenseView<MSG> {
+    /// the pending node which is shown while the suspense is not resolved
+    pub view: Box<Node<MSG>>,
+    /// the task which resolves the suspense
+    pub task: SuspenseTask,
+}
+
+impl<MSG> PartialEq for SuspenseView<MSG> {
+    fn eq(&self, other: &Self) -> bool {
+        self.task == other.task && self.view == other.view
+    }
+}
+
+impl<MSG> Suspense<MSG> {
+    /// returns true if the future of this suspense has been resolved
+    pub fn is_resolved(&self) -> bool {
+        self.inner.resolved.borrow().is_some()
+    }
+
+    /// the view of this suspense, which is the pending node until the future is resolved,
+    /// and the resolved node thereafter
+    pub fn view(&self) -> Node<MSG> {
+        match self.inner.resolved.borrow().as_ref() {
+            Some(resolved) => resolved.clone(),
+            None => Node::Leaf(Leaf::Suspense(SuspenseView {
+                view: Box::new(self.inner.pending.clone()),
+                task: self.inner.task.clone(),
+            })),
+        }
+    }
+}
+
+/// Create a suspense which shows the `pending` node until the `resolved` future completes,
+/// after which the node it resolves to is shown instead.
+///
+/// # Examples
+/// ```rust
+/// use sauron::{html::*, vdom::suspense, *};
+///
+/// let profile: vdom::Suspense<()> = suspense(
+///     text("loading.."),
+///     async { div([class("profile")], [text("Alice")]) },
+/// );
+/// assert!(!profile.is_resolved());
+/// assert_eq!(profile.view().render_to_string(), "loading..");
+/// ```
+pub fn suspense<MSG>(
+    pending: Node<MSG>,
+    resolved: impl Future<Output = Node<MSG>> + 'static,
+) -> Suspense<MSG>
+where
+    MSG: 'static,
+{
+    let (resolved, abort_handle) = abortable(resolved);
+    let inner = Rc::new_cyclic(|weak_inner: &Weak<Inner<MSG>>| {
+        let weak_inner = weak_inner.clone();
+        let task: ResolveTask = Box::pin(async move {
+            let Ok(node) = resolved.await else {
+                return false;
+            };
+            let Some(inner) = weak_inner.upgrade() else {
+                return false;
+            };
+            *inner.resolved.borrow_mut() = Some(node);
+            true
+        });
+        Inner {
+            pending,
+            resolved: RefCell::new(None),
+            task: SuspenseTask(Rc::new(RefCell::new(Some(task)))),
+            abort_handle,
+        }
+    });
+    Suspense { inner }
+}
@@ -12,6 +12,9 @@ use sauron_core::{
 use std::fmt;
 use std::io;
 use std::ops::Deref;
+pub use template::{html_template, HtmlTemplate};
+
+mod template;
 
 /// all the possible error when parsing html string
 #[derive(Debug, thiserror::Error)]
@@ -40,6 +43,20 @@ pub fn raw_html<MSG>(html: &str) -> Node<MSG> {
 }
 
 /// the document is not wrapped with html
+///
+/// The parsed tree is normalized such that rendering it with `render_to_string` and parsing
+/// the result again yields an equal tree:
+/// - attributes with the same name are merged into one, separated by a space
+/// - the html entities in the attribute values are decoded
+/// - boolean attributes such as `<input disabled>` and `<input disabled="disabled">` are kept
+///   with their value, which is empty when there is none. The other valueless attributes are skipped
+///
+/// Known cases where the rendered html is not the same as the original html,
+/// although the tree is still stable:
+/// - self closing tags are normalized, `<br>` is rendered as `<br/>`
+///   and `<circle/>` as `<circle></circle>`
+/// - comments, the doctype and non-standard attributes are dropped
+/// - whitespace-only text nodes in between elements are dropped
 pub fn parse_html<MSG>(html: &str) -> Result<Option<Node<MSG>>, ParseError> {
     let doc = Doc::parse(
         html,
@@ -80,26 +97,42 @@ fn process_node<MSG>(node: &rphtml::parser::Node) -> Result<Option<Node<MSG>>, P
             let tag_name = String::from_iter(tag.borrow().name.iter());
             if let Some(html_tag) = lookup::match_tag(&tag_name) {
                 let is_self_closing = HTML_SC_TAGS.contains(&html_tag);
-                let attributes: Vec<Attribute<MSG>> = tag
-                    .borrow()
-                    .attrs
-                    .iter()
-                    .filter_map(|attr| {
-                        attr.key.as_ref().and_then(|key| {
-                            let key = String::from_iter(key.content.iter());
-                            if let Some(attr_key) = lookup::match_attribute(&key) {
-                                let value = if let Some(value) = &attr.value {
-                                    let value = String::from_iter(value.content.iter());
-                                    AttributeValue::Simple(Value::from(value))
-                                } else {
-                                    AttributeValue::Empty
-                                };
-                                Some(Attribute::new(None, attr_key, value))
-                            } else {
-                                log::warn!("Not a standard html attribute: {}", key);
-                                None
-                            }
-                        })
+                let mut attr_values: Vec<(&'static str, String)> = vec![];
+                for attr in tag.borrow().attrs.iter() {
+                    let Some(key) = attr.key.as_ref() else {
+                        continue;
+                    };
+                    let key = String::from_iter(key.content.iter());
+                    let boolean_attr = lookup::match_boolean_attribute(&key);
+                    let Some(attr_key) = lookup::match_attribute(&key).or(boolean_attr) else {
+                        log::warn!("Not a standard html attribute: {}", key);
+                        continue;
+                    };
+                    let value = match &attr.value {
+                        Some(value) => html_escape::decode_html_entities(&String::from_iter(
+                            value.content.iter(),
+                        ))
+                        .to_string(),
+                        // a boolean attribute is set by its presence, it is rendered by its name
+                        None if boolean_attr.is_some() => String::new(),
+                        // the other valueless attributes are not rendered, so they are skipped here
+                        None => continue,
+                    };
+                    // merge the values of the same attribute the same way they are rendered,
+                    // so the parsed tree stays the same when it is rendered and parsed again
+                    if let Some((_, existing)) =
+                        attr_values.iter_mut().find(|(name, _)| *name == attr_key)
+                    {
+                        existing.push(' ');
+                        existing.push_str(&value);
+                    } else {
+                        attr_values.push((attr_key, value));
+                    }
+                }
+                let attributes: Vec<Attribute<MSG>> = attr_values
+                    .into_iter()
+                    .map(|(attr_key, value)| {
+                        Attribute::new(None, attr_key, AttributeValue::Simple(Value::from(value)))
                     })
                     .collect();
 
@@ -0,0 +1,193 @@
+use crate::parse_html;
+use sauron_core::{
+    html::{comment, node_list, text},
+    vdom::{Leaf, Node},
+};
+use std::{borrow::Cow, cell::RefCell, collections::HashMap, rc::Rc};
+
+thread_local! {
+    /// the parsed skeletons of the templates, so each template is only parsed once
+    static TEMPLATES: RefCell<HashMap<&'static str, Rc<Node<()>>>> = RefCell::new(HashMap::new());
+}
+
+/// A static html markup with `{slot}` markers, which is parsed once into a node skeleton
+/// and filled with the nodes of the slots at render time. See [`html_template`].
+#[derive(Debug, Clone)]
+pub struct HtmlTemplate {
+    skeleton: Rc<Node<()>>,
+}
+
+/// Creates a template out of a static html markup, where the text content can contain
+/// `{name}` slot markers which are replaced with nodes when the template is
+/// [filled](HtmlTemplate::fill).
+///
+/// The markup is parsed with [`parse_html`] the first time a template is created from it,
+/// subsequent calls with the same markup reuse the parsed skeleton.
+///
+/// The slot syntax:
+/// - a slot name is made of ascii letters, digits, `_` and `-`, such as `{user-name}`
+/// - slots are only recognized in the text content, not in the attribute values
+///   nor in the tag names, where the braces are kept as is
+/// - `{{` and `}}` are the literal `{` and `}`
+/// - a slot can be used multiple times, each place gets a copy of the node
+///
+/// Only the static markup is parsed as html, the slots are filled with nodes
+/// so a text which is put in a slot is never interpreted as markup when it is mounted.
+///
+/// # Panics
+/// when the markup is not a valid html
+///
+/// # Examples
+/// ```rust
+/// use sauron_core::{html::*, vdom::Node};
+/// use sauron_html_parser::html_template;
+///
+/// let card: Node<()> = html_template("<div class='card'><h1>{title}</h1><p>{{{body}}}</p></div>")
+///     .fill([("title", text("Hello")), ("body", b([], [text("world")]))]);
+/// assert_eq!(
+///     card.render_to_string(),
+///     r#"<div class="card"><h1>Hello</h1><p>{<b>world</b>}</p></div>"#
+/// );
+/// ```
+pub fn html_template(html: &'static str) -> HtmlTemplate {
+    let skeleton = TEMPLATES.with_borrow_mut(|templates| {
+        Rc::clone(templates.entry(html).or_insert_with(|| {
+            let node: Node<()> = parse_html(html)
+                .expect("the template must be a valid html")
+                .unwrap_or_else(|| node_list([]));
+            Rc::new(mark_slots(node))
+        }))
+    });
+    HtmlTemplate { skeleton }
+}
+
+impl HtmlTemplate {
+    /// Creates the node of this template with the `slots` in place of their markers.
+    /// A slot which is not supplied is rendered as nothing, and a warning is logged.
+    pub fn fill<'a, MSG: 'static>(
+        &self,
+        slots: impl IntoIterator<Item = (&'a str, Node<MSG>)>,
+    ) -> Node<MSG> {
+        let slots: HashMap<&str, Node<MSG>> = slots.into_iter().collect();
+        let mut node = Node::clone(&self.skeleton)
+            .map_msg(|_: ()| unreachable!("the template skeleton has no event listeners"));
+        fill_slots(&mut node, &slots);
+        node
+    }
+}
+
+/// replace the slot markers in the text nodes with comment nodes which hold the slot name
+fn mark_slots(mut node: Node<()>) -> Node<()> {
+    match &mut node {
+        Node::Element(element) => {
+            for child in element.children_mut() {
+                *child = mark_slots(child.clone());
+            }
+            node
+        }
+        Node::Leaf(Leaf::NodeList(nodes)) | Node::Leaf(Leaf::Fragment(nodes)) => {
+            for child in nodes.iter_mut() {
+                *child = mark_slots(child.clone());
+            }
+            node
+        }
+        Node::Leaf(Leaf::Text(content)) => {
+            let parts = split_slots(content);
+            match parts.as_slice() {
+                [TextPart::Text(t)] if t == content => node,
+                _ => node_list(parts.into_iter().map(|part| match part {
+                    TextPart::Text(t) => text(t),
+                    TextPart::Slot(name) => comment(name),
+                })),
+            }
+        }
+        _ => node,
+    }
+}
+
+/// replace the slot markers with the supplied nodes
+fn fill_slots<MSG>(node: &mut Node<MSG>, slots: &HashMap<&str, Node<MSG>>) {
+    match node {
+        Node::Element(element) => {
+            for child in element.children_mut() {
+                fill_slots(child, slots);
+            }
+        }
+        Node::Leaf(Leaf::NodeList(nodes)) | Node::Leaf(Leaf::Fragment(nodes)) => {
+            for child in nodes.iter_mut() {
+                fill_slots(child, slots);
+            }
+        }
+        Node::Leaf(Leaf::Comment(name)) => {
+            *node = slots.get(name.as_ref()).cloned().unwrap_or_else(|| {
+                log::warn!("the template slot `{name}` is not supplied");
+                node_list([])
+            });
+        }
+        _ => (),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum TextPart {
+    Text(String),
+    Slot(Cow<'static, str>),
+}
+
+/// split the text into the literal text and the slot markers
+fn split_slots(content: &str) -> Vec<TextPart> {
+    let mut parts = vec![];
+    let mut literal = String::new();
+    let mut rest = content;
+    while let Some(ch) = rest.chars().next() {
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            literal.push(ch);
+            rest = &rest[2..];
+            continue;
+        }
+        if ch == '{' {
+            if let Some(end) = rest.find('}') {
+                let name = &rest[1..end];
+                let is_slot_name = !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+                if is_slot_name {
+                    if !literal.is_empty() {
+                        parts.push(TextPart::Text(std::mem::take(&mut literal)));
+                    }
+                    parts.push(TextPart::Slot(Cow::Owned(name.to_string())));
+                    rest = &rest[end + 1..];
+                    continue;
+                }
+            }
+        }
+        literal.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    if !literal.is_empty() || parts.is_empty() {
+        parts.push(TextPart::Text(literal));
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_text_into_slots() {
+        assert_eq!(
+            split_slots("hello {name}, {{not a slot}} {a b}"),
+            vec![
+                TextPart::Text("hello ".to_string()),
+                TextPart::Slot(Cow::Borrowed("name")),
+                TextPart::Text(", {not a slot} {a b}".to_string()),
+            ]
+        );
+        assert_eq!(
+            split_slots("plain"),
+            vec![TextPart::Text("plain".to_string())]
+        );
+    }
+}
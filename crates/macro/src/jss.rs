@@ -1,8 +1,9 @@
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
-use syn::{Expr, Result, Token};
+use syn::{Expr, Lit, Result, Token};
 
 pub(crate) use style::Style;
+use style::{Annotation, Property};
 
 mod style;
 
@@ -12,6 +13,8 @@ pub(crate) struct StyleSheetWithConditionalGroup {
 }
 
 pub(crate) struct StyleSheet {
+    /// the namespace which the class names in the selectors are prefixed with
+    namespace: Option<Expr>,
     selector_with_styles: Vec<SelectorWithStyle>,
 }
 
@@ -19,11 +22,30 @@ pub(crate) struct StyleSheet {
 /// ".layer0" : {
 ///     background_color: "red",
 ///     border: (px(1), "solid", "green"),
+///     "&:hover": {
+///         background_color: "blue",
+///     },
+///     "@media screen and (max-width: 800px)": {
+///         display: "none",
+///     },
 /// }
 /// ```
 struct SelectorWithStyle {
+    annotation: Option<Annotation>,
     selector: Expr,
     style: Style,
+    nested: Vec<SelectorWithStyle>,
+}
+
+impl StyleSheet {
+    /// `<namespace> , $(<selector> : { <style> } (,)?)*`
+    pub(crate) fn parse_with_namespace(input: ParseStream) -> Result<Self> {
+        let namespace = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let mut style_sheet: Self = input.parse()?;
+        style_sheet.namespace = Some(namespace);
+        Ok(style_sheet)
+    }
 }
 
 impl Parse for StyleSheet {
@@ -37,6 +59,7 @@ impl Parse for StyleSheet {
             }
         }
         Ok(Self {
+            namespace: None,
             selector_with_styles,
         })
     }
@@ -44,18 +67,18 @@ impl Parse for StyleSheet {
 
 impl ToTokens for StyleSheet {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let expanded_selector_with_styles: Vec<_> = self
-            .selector_with_styles
-            .iter()
-            .map(|ss| {
-                quote! {
-                    #ss,
-                }
-            })
-            .collect();
-        let ss_tokens = proc_macro2::TokenStream::from_iter(expanded_selector_with_styles);
+        let namespace = match &self.namespace {
+            Some(namespace) => quote! { Some((#namespace).to_string()) },
+            None => quote! { None },
+        };
+        let rules = rules_tokens(&self.selector_with_styles, None);
         tokens.extend(quote! {
-            [#ss_tokens].join("\n")
+            {
+                let _namespace: Option<String> = #namespace;
+                let mut _rules: Vec<String> = vec![];
+                #rules
+                _rules.join("\n")
+            }
         });
     }
 }
@@ -88,42 +111,172 @@ impl Parse for StyleSheetWithConditionalGroup {
 impl ToTokens for StyleSheetWithConditionalGroup {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let conditional_group = &self.conditional_group;
-        let expanded_selector_with_styles: Vec<_> = self
-            .selector_with_styles
-            .iter()
-            .map(|ss| {
-                quote! {
-                    #ss,
-                }
-            })
-            .collect();
-        let ss_tokens = proc_macro2::TokenStream::from_iter(expanded_selector_with_styles);
+        let rules = rules_tokens(&self.selector_with_styles, None);
         tokens.extend(quote! {
-            format!("{} {{\n{}\n}}\n", #conditional_group, [#ss_tokens].join("\n"))
+            {
+                let _namespace: Option<String> = None;
+                let mut _rules: Vec<String> = vec![];
+                #rules
+                format!("{} {{\n{}\n}}\n", #conditional_group, _rules.join("\n"))
+            }
         });
     }
 }
 
 impl Parse for SelectorWithStyle {
     /// ```ignore
-    /// <expr> : { <style> }
+    /// <expr> (:)? { $(<property> : <expr> | <nested selector> (:)? { ... } (,)?)* }
     /// ```
     fn parse(input: ParseStream) -> Result<Self> {
+        let annotation = if input.peek(Token![#]) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
         let selector = input.parse::<Expr>()?;
-        input.parse::<Token![:]>()?;
+        if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+        }
         let inner;
         syn::braced!(inner in input);
-        let style = inner.parse()?;
-        Ok(Self { selector, style })
+        let mut properties = vec![];
+        let mut nested = vec![];
+        while !inner.is_empty() {
+            if is_nested_selector(&inner) {
+                nested.push(inner.parse()?);
+            } else {
+                let annotation = if inner.peek(Token![#]) {
+                    Some(inner.parse()?)
+                } else {
+                    None
+                };
+                let property: Property = inner.parse()?;
+                properties.push((annotation, property));
+            }
+            if inner.peek(Token![,]) {
+                inner.parse::<Token![,]>()?;
+            }
+        }
+        Ok(Self {
+            annotation,
+            selector,
+            style: Style { properties },
+            nested,
+        })
     }
 }
 
-impl ToTokens for SelectorWithStyle {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+/// a nested selector is followed by a block of styles, such as `"li": { .. }` or `".child" { .. }`,
+/// while a property is followed by its value. The selector itself can be anything,
+/// ie: `"&:hover"`, `"li"`, `"@media screen"`
+fn is_nested_selector(input: ParseStream) -> bool {
+    let fork = input.fork();
+    if fork.peek(Token![#]) && fork.parse::<Annotation>().is_err() {
+        return false;
+    }
+    if fork.parse::<Expr>().is_err() {
+        return false;
+    }
+    if fork.peek(Token![:]) {
+        let _ = fork.parse::<Token![:]>();
+    }
+    fork.peek(syn::token::Brace)
+}
+
+/// returns the conditional group such as `@media screen`, if the selector is one
+fn conditional_group(selector: &Expr) -> Option<String> {
+    match selector {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(lit), ..
+        }) if lit.value().starts_with('@') => Some(lit.value()),
+        _ => None,
+    }
+}
+
+/// statements which push the css rules of the selectors into `_rules`,
+/// `parent` is the resolved selector of the enclosing block
+fn rules_tokens(
+    selector_with_styles: &[SelectorWithStyle],
+    parent: Option<proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let statements = selector_with_styles.iter().map(|ss| {
+        let annotation = &ss.annotation;
+        let rules = ss.rules_tokens(parent.clone());
+        quote! {
+            #annotation
+            _rules.extend(#rules);
+        }
+    });
+    proc_macro2::TokenStream::from_iter(statements)
+}
+
+impl SelectorWithStyle {
+    /// an expression which evaluates to the list of css rules of this selector
+    fn rules_tokens(&self, parent: Option<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
         let selector = &self.selector;
-        let style = &self.style.to_tokens_with_pretty();
-        tokens.extend(quote! {
-            format!("{} {{\n{}\n}}\n", #selector, #style)
-        });
+        if let Some(conditional_group) = conditional_group(selector) {
+            // the properties of a conditional group inside a selector belongs to that selector
+            let inner_rules = match parent {
+                Some(parent) => self.selector_rules_tokens(quote! { (#parent).to_string() }),
+                None => {
+                    let rules = rules_tokens(&self.nested, None);
+                    quote! {
+                        {
+                            let mut _rules: Vec<String> = vec![];
+                            #rules
+                            _rules
+                        }
+                    }
+                }
+            };
+            quote! {
+                vec![format!("{} {{\n{}\n}}\n", #conditional_group, #inner_rules.join("\n"))]
+            }
+        } else {
+            let resolved = match parent {
+                // `&` is replaced with the parent selector, otherwise it is a descendant
+                Some(parent) => quote! {
+                    {
+                        let _nested = sauron::scope_selector(_namespace.as_deref(), &(#selector).to_string());
+                        if _nested.contains('&') {
+                            _nested.replace('&', &#parent)
+                        } else {
+                            format!("{} {}", #parent, _nested)
+                        }
+                    }
+                },
+                None => quote! {
+                    sauron::scope_selector(_namespace.as_deref(), &(#selector).to_string())
+                },
+            };
+            self.selector_rules_tokens(resolved)
+        }
+    }
+
+    /// the css rule of this selector followed by the rules of the nested selectors
+    fn selector_rules_tokens(
+        &self,
+        resolved_selector: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let style = self.style.to_tokens_with_pretty();
+        // a selector which only has nested selectors doesn't need a rule of its own
+        let has_rule = !self.style.properties.is_empty() || self.nested.is_empty();
+        let rule = if has_rule {
+            quote! {
+                _rules.push(format!("{} {{\n{}\n}}\n", _selector, #style));
+            }
+        } else {
+            quote! {}
+        };
+        let nested_rules = rules_tokens(&self.nested, Some(quote! { _selector }));
+        quote! {
+            {
+                let _selector: String = #resolved_selector;
+                let mut _rules: Vec<String> = vec![];
+                #rule
+                #nested_rules
+                _rules
+            }
+        }
     }
 }
@@ -8,7 +8,7 @@ use syn::{Expr, Ident, Lit, Result, Token};
 /// border: (px(1), "solid", "green"),
 /// ```
 pub(crate) struct Style {
-    properties: Vec<(Option<Annotation>, Property)>,
+    pub(crate) properties: Vec<(Option<Annotation>, Property)>,
 }
 
 pub(crate) struct Annotation {
@@ -19,7 +19,7 @@ pub(crate) struct Annotation {
 /// key value pair of a style
 /// `background_color: "red"`
 /// `border: (px(1), "solid", "green")`
-struct Property {
+pub(crate) struct Property {
     property: PropertyName,
     value: Expr,
 }
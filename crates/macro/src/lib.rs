@@ -239,12 +239,89 @@ pub fn extract_skip_diff(input: proc_macro::TokenStream) -> proc_macro::TokenStr
 ///     \n";
 /// assert_eq!(expected, css);
 /// ```
+///
+/// Selectors can be nested, `&` refers to the parent selector,
+/// otherwise the nested selector is a descendant of the parent.
+/// Conditional groups such as `@media` can be nested inside a selector.
+/// ```rust
+/// use sauron::jss;
+///
+/// let css = jss!(
+///     ".menu": {
+///         display: "flex",
+///         "&:hover": {
+///             opacity: 1,
+///         },
+///         ".item": {
+///             color: "red",
+///         },
+///         "@media screen and (max-width: 800px)": {
+///             display: "none",
+///         },
+///     },
+/// );
+///
+/// let expected = "\
+///     .menu {\
+///     \n  display: flex;\
+///     \n}\
+///     \n\
+///     \n.menu:hover {\
+///     \n  opacity: 1;\
+///     \n}\
+///     \n\
+///     \n.menu .item {\
+///     \n  color: red;\
+///     \n}\
+///     \n\
+///     \n@media screen and (max-width: 800px) {\
+///     \n.menu {\
+///     \n  display: none;\
+///     \n}\
+///     \n\
+///     \n}\
+///     \n";
+/// assert_eq!(expected, css);
+/// ```
 #[proc_macro]
 pub fn jss(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let style_sheet = syn::parse_macro_input!(input as jss::StyleSheet);
     style_sheet.to_token_stream().into()
 }
 
+/// build css string where the class names in the selectors are prefixed with the namespace,
+/// the same way `Component::prefix_class` does, to avoid collisions between components.
+///
+/// # Example:
+/// ```rust
+/// use sauron::jss_ns;
+///
+/// let css = jss_ns!("todo",
+///     ".item": {
+///         display: "flex",
+///         "&.done .label": {
+///             text_decoration: "line-through",
+///         },
+///     },
+/// );
+///
+/// let expected = "\
+///     .todo__item {\
+///     \n  display: flex;\
+///     \n}\
+///     \n\
+///     \n.todo__item.todo__done .todo__label {\
+///     \n  text-decoration: line-through;\
+///     \n}\
+///     \n";
+/// assert_eq!(expected, css);
+/// ```
+#[proc_macro]
+pub fn jss_ns(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let style_sheet = syn::parse_macro_input!(input with jss::StyleSheet::parse_with_namespace);
+    style_sheet.to_token_stream().into()
+}
+
 /// build css string that has media selector or any other conditional group
 ///
 /// # Example:
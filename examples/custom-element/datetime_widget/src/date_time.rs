@@ -164,28 +164,22 @@ where
         div(
             [class("datetimebox"), on_mount(Msg::Mounted)],
             [
-                input(
-                    [
-                        r#type("date"),
-                        class("datetimebox__date"),
-                        on_change(|input| {
-                            log::trace!("input: {:?}", input);
-                            Msg::DateChange(input.value())
-                        }),
-                        value(&self.date),
-                    ],
-                    [],
-                ),
-                input(
-                    [
-                        r#type("time"),
-                        class("datetimebox__time"),
-                        on_change(|input| Msg::TimeChange(input.value())),
-                        value(&self.time),
-                    ],
-                    [],
-                ),
-                input([r#type("text"), value(self.cnt)], []),
+                input([
+                    r#type("date"),
+                    class("datetimebox__date"),
+                    on_change(|input| {
+                        log::trace!("input: {:?}", input);
+                        Msg::DateChange(input.value())
+                    }),
+                    value(&self.date),
+                ]),
+                input([
+                    r#type("time"),
+                    class("datetimebox__time"),
+                    on_change(|input| Msg::TimeChange(input.value())),
+                    value(&self.time),
+                ]),
+                input([r#type("text"), value(self.cnt)]),
                 button([on_click(move |_| Msg::BtnClick)], [text("Do something")]),
                 div([class("external_children"), on_mount(Msg::ExternContMounted)], [])
             ],
@@ -10,18 +10,14 @@ pub(crate) fn textbox<MSG, V: ToString>(
     v: V,
     attributes: impl IntoIterator<Item = Attribute<MSG>>,
 ) -> Node<MSG> {
-    input([r#type("text"), class("textbox"), value(v.to_string())], []).with_attributes(attributes)
+    input([r#type("text"), class("textbox"), value(v.to_string())]).with_attributes(attributes)
 }
 
 pub(crate) fn numberbox<MSG, V: ToString>(
     v: V,
     attributes: impl IntoIterator<Item = Attribute<MSG>>,
 ) -> Node<MSG> {
-    input(
-        [r#type("number"), class("numberbox"), value(v.to_string())],
-        [],
-    )
-    .with_attributes(attributes)
+    input([r#type("number"), class("numberbox"), value(v.to_string())]).with_attributes(attributes)
 }
 
 pub(crate) fn text_link<MSG, V: ToString>(
@@ -40,7 +36,7 @@ pub(crate) fn datebox<MSG>(
     v: String,
     attributes: impl IntoIterator<Item = Attribute<MSG>>,
 ) -> Node<MSG> {
-    input([r#type("date"), class("datebox"), value(v)], []).with_attributes(attributes)
+    input([r#type("date"), class("datebox"), value(v)]).with_attributes(attributes)
 }
 
 /// accepts the checked, container attributes and the actual checkbox attributes
@@ -51,7 +47,7 @@ pub(crate) fn checkbox<MSG>(
 ) -> Node<MSG> {
     div(
         [class("checkbox")],
-        [input([r#type("checkbox")], [])
+        [input([r#type("checkbox")])
             .with_attributes(attrs_flag([("checked", "checked", checked)]))
             .with_attributes(attributes)],
     )
@@ -65,14 +61,11 @@ pub(crate) fn selector_box<MSG>(
 ) -> Node<MSG> {
     div(
         [class("selector_box")],
-        [input(
-            [
-                r#type("checkbox"),
-                class("selector_box__checkbox"),
-                style! {width: px(30)},
-            ],
-            [],
-        )
+        [input([
+            r#type("checkbox"),
+            class("selector_box__checkbox"),
+            style! {width: px(30)},
+        ])
         .with_attributes(attrs_flag([("checked", "checked", checked)]))
         .with_attributes(attributes)],
     )
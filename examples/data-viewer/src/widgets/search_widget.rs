@@ -22,14 +22,11 @@ impl SearchWidget {
                     [class("search_widget__search_icon")],
                     [assets::svg_search_icon(18, 18, "#888")],
                 ),
-                input(
-                    [
-                        r#type("text"),
-                        class("search_widget__column_filter"),
-                        style! {width: px(input_width)},
-                    ],
-                    [],
-                )
+                input([
+                    r#type("text"),
+                    class("search_widget__column_filter"),
+                    style! {width: px(input_width)},
+                ])
                 .with_attributes(attributes),
             ],
         )
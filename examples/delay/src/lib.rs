@@ -45,18 +45,15 @@ impl Application for App {
                 div(
                     [],
                     [
-                        input(
-                            [
-                                class("client"),
-                                r#type("button"),
-                                value("Click me!"),
-                                on_click(|_| {
-                                    trace!("Button is clicked");
-                                    Msg::Click
-                                }),
-                            ],
-                            [],
-                        ),
+                        input([
+                            class("client"),
+                            r#type("button"),
+                            value("Click me!"),
+                            on_click(|_| {
+                                trace!("Button is clicked");
+                                Msg::Click
+                            }),
+                        ]),
                         button(
                             [on_click(|_| Msg::CancelPrevious)],
                             [text("Cancel previous")],
@@ -85,33 +85,27 @@ impl Application for App {
                 div(
                     [class("some-class"), id("some-id"), attr("data-id", 1)],
                     [
-                        input(
-                            [
-                                class("prev_page"),
-                                r#type("button"),
-                                disabled(self.page <= 1),
-                                value("<< Prev Page"),
-                                on_click(|_| {
-                                    trace!("Button is clicked");
-                                    Msg::PrevPage
-                                }),
-                            ],
-                            [],
-                        ),
+                        input([
+                            class("prev_page"),
+                            r#type("button"),
+                            disabled(self.page <= 1),
+                            value("<< Prev Page"),
+                            on_click(|_| {
+                                trace!("Button is clicked");
+                                Msg::PrevPage
+                            }),
+                        ]),
                         text(format!("Page: {}", self.page)),
-                        input(
-                            [
-                                class("next_page"),
-                                r#type("button"),
-                                disabled(self.page >= self.data.total_pages),
-                                value("Next Page >>"),
-                                on_click(|_| {
-                                    trace!("Button is clicked");
-                                    Msg::NextPage
-                                }),
-                            ],
-                            [],
-                        ),
+                        input([
+                            class("next_page"),
+                            r#type("button"),
+                            disabled(self.page >= self.data.total_pages),
+                            value("Next Page >>"),
+                            on_click(|_| {
+                                trace!("Button is clicked");
+                                Msg::NextPage
+                            }),
+                        ]),
                     ],
                 ),
                 div([], []).with_children(self.data.data.iter().map(|user| {
@@ -121,7 +115,7 @@ impl Application for App {
                             li([], [text(user.id)]),
                             li([], [text(&user.email)]),
                             li([], [text(&user.first_name)]),
-                            li([], [img([src(&user.avatar)], [])]),
+                            li([], [img([src(&user.avatar)])]),
                         ],
                     )
                 })),
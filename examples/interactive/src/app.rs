@@ -92,14 +92,11 @@ impl Application for App {
                     [],
                     [
                         text("Your name is: "),
-                        input(
-                            [
-                                r#type("text"),
-                                on_input(|event: InputEvent| Msg::ChangeName(event.value())),
-                                placeholder("John Smith"),
-                            ],
-                            [],
-                        ),
+                        input([
+                            r#type("text"),
+                            on_input(|event: InputEvent| Msg::ChangeName(event.value())),
+                            placeholder("John Smith"),
+                        ]),
                         button(
                             [on_click(|event: MouseEvent| {
                                 trace!("Clicked at ({},{})", event.x(), event.y());
@@ -156,14 +153,11 @@ impl Application for App {
                     [],
                     [
                         text("What are you thinking right now?"),
-                        input(
-                            [
-                                r#type("text"),
-                                on_change(|event: InputEvent| Msg::ChangeThought(event.value())),
-                                placeholder("Elephants..."),
-                            ],
-                            [],
-                        ),
+                        input([
+                            r#type("text"),
+                            on_change(|event: InputEvent| Msg::ChangeThought(event.value())),
+                            placeholder("Elephants..."),
+                        ]),
                         if let Some(thought) = &self.thought {
                             text(format!("Hmmn {}... Interesting.", thought))
                         } else {
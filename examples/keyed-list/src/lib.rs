@@ -0,0 +1,126 @@
+use sauron::{html::keyed_children, jss, wasm_bindgen, *};
+
+/// the items are moved by their id, since the listeners stay attached to the moved rows
+enum Msg {
+    MoveUp(usize),
+    MoveDown(usize),
+    Add,
+}
+
+struct Item {
+    id: usize,
+    title: String,
+}
+
+struct App {
+    items: Vec<Item>,
+    next_id: usize,
+}
+
+impl App {
+    fn new() -> Self {
+        let items: Vec<Item> = ["apple", "banana", "cherry"]
+            .into_iter()
+            .enumerate()
+            .map(|(id, title)| Item {
+                id,
+                title: title.to_string(),
+            })
+            .collect();
+        App {
+            next_id: items.len(),
+            items,
+        }
+    }
+
+    /// the current position of the item with the `id` in the list
+    fn position(&self, id: usize) -> Option<usize> {
+        self.items.iter().position(|item| item.id == id)
+    }
+
+    fn view_item(&self, index: usize, item: &Item) -> Node<Msg> {
+        let id = item.id;
+        li(
+            [],
+            [
+                text(&item.title),
+                // the note is not in the model, it follows the item since its node is moved
+                input([r#type("text"), placeholder("note")]),
+                button(
+                    [on_click(move |_| Msg::MoveUp(id)), disabled(index == 0)],
+                    [text("up")],
+                ),
+                button(
+                    [
+                        on_click(move |_| Msg::MoveDown(id)),
+                        disabled(index + 1 == self.items.len()),
+                    ],
+                    [text("down")],
+                ),
+            ],
+        )
+    }
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn view(&self) -> Node<Msg> {
+        main(
+            [],
+            [
+                ul(
+                    [],
+                    keyed_children(
+                        self.items
+                            .iter()
+                            .enumerate()
+                            .map(|(index, item)| (item.id, self.view_item(index, item))),
+                    ),
+                ),
+                button([on_click(|_| Msg::Add)], [text("add")]),
+            ],
+        )
+    }
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::MoveUp(id) => {
+                if let Some(index) = self.position(id).filter(|index| *index > 0) {
+                    self.items.swap(index - 1, index);
+                }
+            }
+            Msg::MoveDown(id) => {
+                if let Some(index) = self
+                    .position(id)
+                    .filter(|index| index + 1 < self.items.len())
+                {
+                    self.items.swap(index, index + 1);
+                }
+            }
+            Msg::Add => {
+                self.items.push(Item {
+                    id: self.next_id,
+                    title: format!("item {}", self.next_id),
+                });
+                self.next_id += 1;
+            }
+        }
+        Cmd::none()
+    }
+
+    fn stylesheet() -> Vec<String> {
+        vec![jss! {
+            "body":{
+                font_family: "verdana, arial, monospace",
+            },
+        }]
+    }
+}
+
+#[wasm_bindgen(start)]
+pub fn start() {
+    console_log::init_with_level(log::Level::Trace).unwrap();
+    console_error_panic_hook::set_once();
+    Program::mount_to_body(App::new());
+}
@@ -0,0 +1,3 @@
+wasm-pack build --release --target=web &&\
+
+basic-http-server -a 0.0.0.0:4000
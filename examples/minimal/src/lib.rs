@@ -27,27 +27,21 @@ impl Application for App {
                 div(
                     [class("some-class"), id("some-id"), attr("data-id", 1)],
                     [
-                        input(
-                            [
-                                class("client"),
-                                r#type("button"),
-                                value("Click me!"),
-                                on_click(|_| {
-                                    trace!("Button is clicked");
-                                    Msg::Click
-                                }),
-                                on_mount(|m| {
-                                    log::trace!(
-                                        "input button is mounted into: {:?}",
-                                        m.target_node
-                                    );
-                                    Msg::NoOp
-                                }),
-                            ],
-                            [],
-                        ),
+                        input([
+                            class("client"),
+                            r#type("button"),
+                            value("Click me!"),
+                            on_click(|_| {
+                                trace!("Button is clicked");
+                                Msg::Click
+                            }),
+                            on_mount(|m| {
+                                log::trace!("input button is mounted into: {:?}", m.target_node);
+                                Msg::NoOp
+                            }),
+                        ]),
                         div([], [text(format!("Clicked: {}", self.click_count))]),
-                        input([r#type("text"), value(self.click_count)], []),
+                        input([r#type("text"), value(self.click_count)]),
                     ],
                 ),
             ],
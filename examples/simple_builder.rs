@@ -18,7 +18,7 @@ fn main() {
                 println!("i've been clicked");
             }),
         ],
-        [input([class("client"), r#type("checkbox")], [])],
+        [input([class("client"), r#type("checkbox")])],
     );
     let new = div(
         [
@@ -32,7 +32,7 @@ fn main() {
                 println!("i've been clicked2");
             }),
         ],
-        [input([class("client"), r#type("checkbox")], [])],
+        [input([class("client"), r#type("checkbox")])],
     );
     println!("{:#?}", old);
     println!("{:#?}", new);
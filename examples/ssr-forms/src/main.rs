@@ -32,14 +32,11 @@ fn view(form_data: Option<FormData>) -> Node<()> {
                 [],
                 [
                     text("Your name is: "),
-                    input(
-                        [
-                            attributes::name("name"),
-                            r#type("text"),
-                            placeholder("John Smith"),
-                        ],
-                        [],
-                    ),
+                    input([
+                        attributes::name("name"),
+                        r#type("text"),
+                        placeholder("John Smith"),
+                    ]),
                 ],
             ),
             if let Some(form_data) = &form_data {
@@ -74,14 +71,11 @@ fn view(form_data: Option<FormData>) -> Node<()> {
                 [],
                 [
                     text("What are you thinking right now?"),
-                    input(
-                        [
-                            attributes::name("thought"),
-                            r#type("text"),
-                            placeholder("Elephants..."),
-                        ],
-                        [],
-                    ),
+                    input([
+                        attributes::name("thought"),
+                        r#type("text"),
+                        placeholder("Elephants..."),
+                    ]),
                     if let Some(form_data) = &form_data {
                         if let Some(thought) = &form_data.thought {
                             text(format!("Hmmn {}... Interesting.", thought))
@@ -93,7 +87,7 @@ fn view(form_data: Option<FormData>) -> Node<()> {
                     },
                 ],
             ),
-            input([r#type("submit"), value("Submit")], []),
+            input([r#type("submit"), value("Submit")]),
         ],
     )
 }
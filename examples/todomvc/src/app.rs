@@ -156,15 +156,12 @@ impl Model {
         section(
             [class("main")],
             [
-                input(
-                    [
-                        class("toggle-all"),
-                        r#type("checkbox"),
-                        checked(self.is_all_completed()),
-                        on_click(|_| Msg::ToggleAll),
-                    ],
-                    [],
-                ),
+                input([
+                    class("toggle-all"),
+                    r#type("checkbox"),
+                    checked(self.is_all_completed()),
+                    on_click(|_| Msg::ToggleAll),
+                ]),
                 ul([class("todo-list")], {
                     self.entries
                         .iter()
@@ -203,24 +200,21 @@ impl Model {
             [class("header")],
             [
                 h1([], [text("todos")]),
-                input(
-                    [
-                        class("new-todo"),
-                        id("new-todo"),
-                        placeholder("What needs to be done?"),
-                        autofocus(true),
-                        value(self.value.to_string()),
-                        on_input(|v: InputEvent| Msg::Update(v.value())),
-                        on_keypress(|event: KeyboardEvent| {
-                            if event.key() == "Enter" {
-                                Msg::Add
-                            } else {
-                                Msg::NoOp
-                            }
-                        }),
-                    ],
-                    [],
-                ),
+                input([
+                    class("new-todo"),
+                    id("new-todo"),
+                    placeholder("What needs to be done?"),
+                    autofocus(true),
+                    value(self.value.to_string()),
+                    on_input(|v: InputEvent| Msg::Update(v.value())),
+                    on_keypress(|event: KeyboardEvent| {
+                        if event.key() == "Enter" {
+                            Msg::Add
+                        } else {
+                            Msg::NoOp
+                        }
+                    }),
+                ]),
             ],
         )
     }
@@ -237,15 +231,12 @@ impl Model {
                 div(
                     [class("view")],
                     [
-                        input(
-                            [
-                                class("toggle"),
-                                r#type("checkbox"),
-                                checked(entry.completed),
-                                on_click(move |_| Msg::Toggle(entry_id)),
-                            ],
-                            [],
-                        ),
+                        input([
+                            class("toggle"),
+                            r#type("checkbox"),
+                            checked(entry.completed),
+                            on_click(move |_| Msg::Toggle(entry_id)),
+                        ]),
                         label(
                             [on_doubleclick(move |_| Msg::ToggleEdit(entry_id))],
                             [text(entry.description.to_string())],
@@ -256,26 +247,21 @@ impl Model {
                         ),
                     ],
                 ),
-                input(
-                    [
-                        class("edit"),
-                        r#type("text"),
-                        hidden(!entry.editing),
-                        value(&entry.description),
-                        on_input(move |input: InputEvent| {
-                            Msg::UpdateEntry(entry_id, input.value())
-                        }),
-                        on_blur(move |_| Msg::EditingEntry(entry_id, false)),
-                        on_keypress(move |event: KeyboardEvent| {
-                            if event.key_code() == 13 {
-                                Msg::EditingEntry(entry_id, false)
-                            } else {
-                                Msg::NoOp
-                            }
-                        }),
-                    ],
-                    [],
-                ),
+                input([
+                    class("edit"),
+                    r#type("text"),
+                    hidden(!entry.editing),
+                    value(&entry.description),
+                    on_input(move |input: InputEvent| Msg::UpdateEntry(entry_id, input.value())),
+                    on_blur(move |_| Msg::EditingEntry(entry_id, false)),
+                    on_keypress(move |event: KeyboardEvent| {
+                        if event.key_code() == 13 {
+                            Msg::EditingEntry(entry_id, false)
+                        } else {
+                            Msg::NoOp
+                        }
+                    }),
+                ]),
             ],
         )
     }
@@ -64,14 +64,12 @@ where
     }
 
     fn view(&self) -> Node<Msg> {
-        input(
-            [
-                r#type("text"),
-                class("field"),
-                on_click(|_| Msg::FieldClick),
-                on_input(|input| Msg::InputChange(input.value())),
-            ],
-            [text(format!("{} ({})", self.field_name, self.field_clicks))],
-        )
+        input([
+            r#type("text"),
+            class("field"),
+            on_click(|_| Msg::FieldClick),
+            on_input(|input| Msg::InputChange(input.value())),
+            value(format!("{} ({})", self.field_name, self.field_clicks)),
+        ])
     }
 }
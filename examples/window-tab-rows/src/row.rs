@@ -60,7 +60,7 @@ impl Component for Row {
             [class("row")],
             [
                 text(&self.row_name),
-                input([class("row-selector"), r#type("checkbox")], []),
+                input([class("row-selector"), r#type("checkbox")]),
                 div(
                     [],
                     self.fields.iter().enumerate().map(|(index, field)| {
@@ -42,8 +42,11 @@ pub mod prelude {
     pub use sauron_macro::node;
 
     #[cfg(feature = "with-jss")]
-    pub use sauron_macro::{jss, jss_with_media, style};
+    #[doc(hidden)]
+    pub use sauron_core::html::scope_selector;
+    #[cfg(feature = "with-jss")]
+    pub use sauron_macro::{jss, jss_ns, jss_with_media, style};
 
     #[cfg(feature = "html-parser")]
-    pub use sauron_html_parser::{parse_html, raw_html};
+    pub use sauron_html_parser::{html_template, parse_html, raw_html, HtmlTemplate};
 }
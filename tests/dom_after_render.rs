@@ -0,0 +1,35 @@
+#![deny(warnings)]
+use sauron::*;
+use std::{cell::RefCell, rc::Rc};
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn after_render_is_called_only_when_patches_are_applied() {
+    console_error_panic_hook::set_once();
+
+    let renders = Rc::new(RefCell::new(vec![]));
+    let renders_clone = Rc::clone(&renders);
+
+    let mut simple_program = simple_program();
+    simple_program.on_after_render(move |total_patches| {
+        renders_clone.borrow_mut().push(total_patches);
+    });
+
+    let view: Node<()> = div(vec![class("after-render")], vec![text("hello")]);
+    simple_program
+        .update_dom_with_vdom(view.clone())
+        .expect("must update dom");
+    assert_eq!(renders.borrow().len(), 1);
+    assert!(renders.borrow()[0] > 0);
+
+    // nothing changed, so there is no render
+    simple_program
+        .update_dom_with_vdom(view)
+        .expect("must update dom");
+    assert_eq!(renders.borrow().len(), 1);
+}
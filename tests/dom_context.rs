@@ -0,0 +1,83 @@
+#![deny(warnings)]
+use sauron::{
+    dom::{delay, MountProcedure},
+    *,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct Theme {
+    accent: &'static str,
+}
+
+#[derive(Default)]
+struct App {
+    clicks: usize,
+}
+
+enum Msg {
+    Click,
+}
+
+/// a view function deep in the tree which reads the theme without it being passed down
+fn view_button(label: &str) -> Node<Msg> {
+    let accent = use_context::<Theme>().map(|theme| theme.accent);
+    button(
+        [
+            id("themed-button"),
+            class(accent.unwrap_or("no-theme")),
+            on_click(|_| Msg::Click),
+        ],
+        [text(label)],
+    )
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Click => {
+                // the context is only available in the view
+                assert!(use_context::<Theme>().is_none());
+                self.clicks += 1;
+            }
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div([], [view_button(&format!("clicked {}", self.clicks))])
+    }
+}
+
+fn button_class() -> String {
+    sauron_core::dom::document()
+        .get_element_by_id("themed-button")
+        .expect("must exist")
+        .class_name()
+}
+
+#[wasm_bindgen_test]
+async fn provided_context_is_available_in_the_view() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::new(App::default());
+    program.provide(Theme { accent: "tomato" });
+    program.mount(
+        &sauron_core::dom::document().body().unwrap(),
+        MountProcedure::append(),
+    );
+    assert_eq!(button_class(), "tomato");
+
+    program.dispatch(Msg::Click);
+    delay(50).await;
+    assert_eq!(program.app().clicks, 1);
+    assert_eq!(button_class(), "tomato");
+
+    // providing a value again re-renders the view with it
+    program.provide(Theme { accent: "teal" });
+    assert_eq!(button_class(), "teal");
+    assert!(use_context::<Theme>().is_none());
+}
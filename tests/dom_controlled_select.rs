@@ -0,0 +1,52 @@
+#![deny(warnings)]
+use sauron::{html::attributes::value, *};
+use test_fixtures::simple_program;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn fruits(selected: &str, options: &[&'static str]) -> Node<()> {
+    main(
+        vec![],
+        vec![select(
+            vec![id("controlled-select"), value(selected.to_string())],
+            options
+                .iter()
+                .map(|fruit| option(vec![value(*fruit)], vec![text(fruit)])),
+        )],
+    )
+}
+
+fn selected_value() -> String {
+    let select: web_sys::HtmlSelectElement = sauron_core::dom::document()
+        .get_element_by_id("controlled-select")
+        .expect("must have the select")
+        .unchecked_into();
+    select.value()
+}
+
+#[wasm_bindgen_test]
+fn select_value_is_set_after_the_options() {
+    console_error_panic_hook::set_once();
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(fruits("banana", &["apple", "banana", "cherry"]))
+        .expect("must update dom");
+    assert_eq!(selected_value(), "banana");
+
+    // the selected option is added in the same update
+    simple_program
+        .update_dom_with_vdom(fruits("durian", &["apple", "banana", "cherry", "durian"]))
+        .expect("must update dom");
+    assert_eq!(selected_value(), "durian");
+
+    // the options are changed while the value stays the same
+    simple_program
+        .update_dom_with_vdom(fruits("durian", &["durian", "elderberry"]))
+        .expect("must update dom");
+    assert_eq!(selected_value(), "durian");
+}
@@ -0,0 +1,80 @@
+#![deny(warnings)]
+use sauron::{
+    dom::{create_dom_node, delay, MountProcedure},
+    html::symbol,
+    *,
+};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct App {
+    clicks: usize,
+}
+
+enum Msg {
+    Click,
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Click => self.clicks += 1,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div([], [text(format!("clicks: {}", self.clicks))])
+    }
+}
+
+#[wasm_bindgen_test]
+async fn created_dom_node_dispatches_to_the_program() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::new(App::default());
+    program.mount(
+        &sauron_core::dom::document().body().unwrap(),
+        MountProcedure::append(),
+    );
+
+    let extra: Node<Msg> = button(
+        [id("imperative-button"), on_click(|_| Msg::Click)],
+        [text("click me")],
+    );
+    let extra_node = create_dom_node(&extra, &program);
+    // inserted manually, outside of the view of the program
+    sauron_core::dom::document()
+        .body()
+        .unwrap()
+        .append_child(&extra_node)
+        .unwrap();
+
+    let button: web_sys::HtmlElement = sauron_core::dom::document()
+        .get_element_by_id("imperative-button")
+        .expect("must be inserted")
+        .unchecked_into();
+    assert_eq!(
+        button.outer_html(),
+        r#"<button id="imperative-button">click me</button>"#
+    );
+    button.click();
+    delay(50).await;
+    assert_eq!(program.app().clicks, 1);
+}
+
+#[wasm_bindgen_test]
+fn symbol_is_created_as_a_text_node() {
+    console_error_panic_hook::set_once();
+
+    let program = Program::new(App::default());
+    let nbsp: Node<Msg> = symbol("&nbsp;");
+    let nbsp_node = create_dom_node(&nbsp, &program);
+    assert_eq!(nbsp_node.node_type(), web_sys::Node::TEXT_NODE);
+    assert_eq!(nbsp_node.text_content().as_deref(), Some("\u{a0}"));
+}
@@ -0,0 +1,67 @@
+#![deny(warnings)]
+use sauron::{
+    dom::{delay, MountProcedure},
+    *,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Profile {
+    name: Option<String>,
+}
+
+impl Application for Profile {
+    type MSG = String;
+
+    fn update(&mut self, name: String) -> Cmd<String> {
+        self.name = Some(name);
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<String> {
+        div(
+            vec![id("deferred-profile")],
+            vec![text(self.name.as_deref().unwrap_or("nobody"))],
+        )
+    }
+}
+
+fn profile_text() -> Option<String> {
+    sauron_core::dom::document()
+        .get_element_by_id("deferred-profile")
+        .and_then(|profile| profile.text_content())
+}
+
+#[wasm_bindgen_test]
+async fn first_render_waits_until_ready() {
+    console_error_panic_hook::set_once();
+
+    let document = sauron_core::dom::document();
+    let server_rendered = document.create_element("div").expect("must create");
+    server_rendered.set_id("deferred-profile");
+    server_rendered.set_text_content(Some("server rendered"));
+    document
+        .body()
+        .expect("must have a body")
+        .append_child(&server_rendered)
+        .expect("must append");
+
+    let mut program = Program::new(Profile::default());
+    program.mount_deferred(&server_rendered, MountProcedure::replace());
+    assert!(program.is_deferred());
+
+    program.dispatch("ferris".to_string());
+    delay(50).await;
+    assert_eq!(program.app().name.as_deref(), Some("ferris"));
+    assert_eq!(profile_text().as_deref(), Some("server rendered"));
+
+    program.ready();
+    assert!(!program.is_deferred());
+    assert_eq!(profile_text().as_deref(), Some("ferris"));
+
+    program.dispatch("crab".to_string());
+    delay(50).await;
+    assert_eq!(profile_text().as_deref(), Some("crab"));
+}
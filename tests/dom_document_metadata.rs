@@ -0,0 +1,53 @@
+#![deny(warnings)]
+use sauron::{dom::delay, *};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Inbox {
+    unread: usize,
+}
+
+impl Application for Inbox {
+    type MSG = usize;
+
+    fn update(&mut self, unread: usize) -> Cmd<usize> {
+        self.unread = unread;
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<usize> {
+        div(vec![], vec![text(self.unread)])
+    }
+
+    fn title(&self) -> Option<String> {
+        Some(format!("Inbox ({})", self.unread))
+    }
+
+    fn meta(&self) -> Vec<(&'static str, String)> {
+        vec![("description", format!("{} unread messages", self.unread))]
+    }
+}
+
+#[wasm_bindgen_test]
+async fn title_and_meta_follow_the_app_state() {
+    console_error_panic_hook::set_once();
+
+    let description = || {
+        sauron_core::dom::document()
+            .query_selector("head meta[name=\"description\"]")
+            .expect("must query")
+            .expect("must have the meta tag")
+            .get_attribute("content")
+    };
+
+    let mut program = Program::mount_to_body(Inbox::default());
+    assert_eq!(sauron_core::dom::document().title(), "Inbox (0)");
+    assert_eq!(description().as_deref(), Some("0 unread messages"));
+
+    program.dispatch(3);
+    delay(50).await;
+    assert_eq!(sauron_core::dom::document().title(), "Inbox (3)");
+    assert_eq!(description().as_deref(), Some("3 unread messages"));
+}
@@ -0,0 +1,61 @@
+#![deny(warnings)]
+use sauron::{dom::MountProcedure, *};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct List {
+    items: Vec<&'static str>,
+}
+
+impl Application for List {
+    type MSG = ();
+
+    fn update(&mut self, _msg: ()) -> Cmd<()> {
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<()> {
+        ul(
+            [class("list")],
+            self.items
+                .iter()
+                .map(|item| li([key(*item), class(*item)], [text(item)])),
+        )
+    }
+}
+
+#[wasm_bindgen_test]
+fn returns_the_element_of_the_key() {
+    console_error_panic_hook::set_once();
+
+    let document = sauron_core::dom::document();
+    let host = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&host).unwrap();
+    let mut program = Program::new(List {
+        items: vec!["apple", "banana", "cherry"],
+    });
+    program.mount(&host, MountProcedure::append());
+
+    let banana = program.element_for_key("banana").expect("must have banana");
+    assert_eq!(banana.class_name(), "banana");
+    assert_eq!(banana.text_content().as_deref(), Some("banana"));
+    assert!(program.element_for_key("durian").is_none());
+}
+
+#[wasm_bindgen_test]
+fn returns_the_first_element_of_a_duplicate_key() {
+    console_error_panic_hook::set_once();
+
+    let document = sauron_core::dom::document();
+    let host = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&host).unwrap();
+    let mut program = Program::new(List {
+        items: vec!["apple", "apple"],
+    });
+    program.mount(&host, MountProcedure::append());
+
+    let apple = program.element_for_key("apple").expect("must have apple");
+    let first = host.query_selector(".apple").unwrap().unwrap();
+    assert!(apple.is_same_node(Some(&first)));
+}
@@ -0,0 +1,52 @@
+#![deny(warnings)]
+use sauron::*;
+
+#[derive(Default)]
+struct Counter {
+    count: i32,
+}
+
+#[derive(Debug, PartialEq)]
+enum CounterMsg {
+    Increment,
+}
+
+impl Application for Counter {
+    type MSG = CounterMsg;
+
+    fn update(&mut self, msg: CounterMsg) -> Cmd<CounterMsg> {
+        match msg {
+            CounterMsg::Increment => self.count += 1,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<CounterMsg> {
+        button(vec![class("counter")], vec![text(self.count)])
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Msg {
+    Counter(usize, CounterMsg),
+}
+
+#[test]
+fn msgs_are_routed_to_the_embedded_instance() {
+    let mut counters: Vec<Embedded<Counter, Msg>> = (0..2)
+        .map(|index| Embedded::new(Counter::default(), move |msg| Msg::Counter(index, msg)))
+        .collect();
+
+    let msg = Msg::Counter(1, CounterMsg::Increment);
+    let Msg::Counter(index, msg) = msg;
+    let _cmd: Cmd<Msg> = counters[index].update(msg);
+
+    assert_eq!(counters[0].app.count, 0);
+    assert_eq!(counters[1].app.count, 1);
+
+    let view: Node<Msg> = div(vec![], counters.iter().map(|counter| counter.view()));
+    assert_eq!(
+        view.render_to_string(),
+        r#"<div><button class="counter">0</button><button class="counter">1</button></div>"#
+    );
+}
@@ -0,0 +1,77 @@
+#![deny(warnings)]
+use sauron::{dom::MountProcedure, *};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct Picker;
+
+impl Application for Picker {
+    type MSG = ();
+
+    fn update(&mut self, _msg: ()) -> Cmd<()> {
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<()> {
+        div([class("picker")], [text("pick a color")])
+    }
+}
+
+/// a host element in a container, which records the `detail` of the `change` events
+/// that reach the container
+fn host_in_container() -> (web_sys::Element, Rc<RefCell<Vec<String>>>) {
+    let document = sauron_core::dom::document();
+    let container = document.create_element("section").unwrap();
+    let host = document.create_element("div").unwrap();
+    container.append_child(&host).unwrap();
+    document.body().unwrap().append_child(&container).unwrap();
+
+    let details = Rc::new(RefCell::new(vec![]));
+    let details_clone = Rc::clone(&details);
+    let closure = Closure::<dyn FnMut(web_sys::Event)>::new(move |event: web_sys::Event| {
+        let event: web_sys::CustomEvent = event.unchecked_into();
+        details_clone
+            .borrow_mut()
+            .push(event.detail().as_string().unwrap_or_default());
+    });
+    container
+        .add_event_listener_with_callback("change", closure.as_ref().unchecked_ref())
+        .unwrap();
+    closure.forget();
+    (host, details)
+}
+
+#[wasm_bindgen_test]
+fn emitted_event_bubbles_from_the_mount_element() {
+    console_error_panic_hook::set_once();
+
+    let (host, details) = host_in_container();
+    let mut program = Program::new(Picker);
+    program.mount(&host, MountProcedure::append());
+
+    let not_canceled = program
+        .emit_event("change", JsValue::from_str("tomato"))
+        .expect("must emit");
+    assert!(not_canceled);
+    assert_eq!(*details.borrow(), vec!["tomato"]);
+}
+
+#[wasm_bindgen_test]
+fn emitted_event_crosses_the_shadow_root() {
+    console_error_panic_hook::set_once();
+
+    let (host, details) = host_in_container();
+    let shadow_root = host
+        .attach_shadow(&web_sys::ShadowRootInit::new(web_sys::ShadowRootMode::Open))
+        .unwrap();
+    let mut program = Program::new(Picker);
+    program.mount(&shadow_root, MountProcedure::append());
+
+    program
+        .emit_event("change", JsValue::from_str("teal"))
+        .expect("must emit");
+    assert_eq!(*details.borrow(), vec!["teal"]);
+}
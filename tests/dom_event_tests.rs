@@ -1,4 +1,10 @@
-use sauron::{html::attributes::*, html::events::*, html::*, *};
+use sauron::{
+    dom::{delay, SimulatedEvent},
+    html::attributes::*,
+    html::events::*,
+    html::*,
+    *,
+};
 use std::{cell::RefCell, rc::Rc};
 use test_fixtures::simple_program;
 use wasm_bindgen_test::*;
@@ -16,17 +22,14 @@ fn on_input_test() {
 
     let elem_id = "input-element-1";
 
-    let input: Node<()> = input(
-        vec![
-            // On input we'll set our Rc<RefCell<String>> value to the input elements value
-            id(elem_id),
-            on_input(move |event: InputEvent| {
-                *text_clone.borrow_mut() = event.value();
-            }),
-            value("End Text"),
-        ],
-        vec![],
-    );
+    let input: Node<()> = input(vec![
+        // On input we'll set our Rc<RefCell<String>> value to the input elements value
+        id(elem_id),
+        on_input(move |event: InputEvent| {
+            *text_clone.borrow_mut() = event.value();
+        }),
+        value("End Text"),
+    ]);
 
     let input_event = web_sys::InputEvent::new("input").unwrap();
 
@@ -57,27 +60,21 @@ fn added_event() {
 
     let elem_id = "input-add-event-test";
 
-    let old: Node<()> = input(
-        vec![
-            // On input we'll set our Rc<RefCell<String>> value to the input elements value
-            id(elem_id),
-            value("End Text"),
-        ],
-        vec![],
-    );
-
-    let new = input(
-        vec![
-            // On input we'll set our Rc<RefCell<String>> value to the input elements value
-            id(elem_id),
-            value("End Text"),
-            on_input(move |event: InputEvent| {
-                log::info!("input event is triggered..");
-                *text_clone.borrow_mut() = event.value();
-            }),
-        ],
-        vec![],
-    );
+    let old: Node<()> = input(vec![
+        // On input we'll set our Rc<RefCell<String>> value to the input elements value
+        id(elem_id),
+        value("End Text"),
+    ]);
+
+    let new = input(vec![
+        // On input we'll set our Rc<RefCell<String>> value to the input elements value
+        id(elem_id),
+        value("End Text"),
+        on_input(move |event: InputEvent| {
+            log::info!("input event is triggered..");
+            *text_clone.borrow_mut() = event.value();
+        }),
+    ]);
 
     let input_event = web_sys::InputEvent::new("input").unwrap();
 
@@ -115,26 +112,20 @@ fn remove_event() {
 
     let elem_id = "input-remove-event-test";
 
-    let old: Node<()> = input(
-        vec![
-            // On input we'll set our Rc<RefCell<String>> value to the input elements value
-            id(elem_id),
-            value("End Text"),
-            on_input(move |event: InputEvent| {
-                *text_clone.borrow_mut() = event.value();
-            }),
-        ],
-        vec![],
-    );
-
-    let new = input(
-        vec![
-            // On input we'll set our Rc<RefCell<String>> value to the input elements value
-            id(elem_id),
-            value("End Text"),
-        ],
-        vec![],
-    );
+    let old: Node<()> = input(vec![
+        // On input we'll set our Rc<RefCell<String>> value to the input elements value
+        id(elem_id),
+        value("End Text"),
+        on_input(move |event: InputEvent| {
+            *text_clone.borrow_mut() = event.value();
+        }),
+    ]);
+
+    let new = input(vec![
+        // On input we'll set our Rc<RefCell<String>> value to the input elements value
+        id(elem_id),
+        value("End Text"),
+    ]);
     let patch = diff(&old, &new);
     log::debug!("patch: {:?}", patch);
 
@@ -164,3 +155,90 @@ fn remove_event() {
     //the `new` vdom which has no attached event
     assert_eq!(&*text.borrow(), "Start Text");
 }
+
+#[wasm_bindgen_test]
+fn blur_passes_the_related_target() {
+    console_log::init_with_level(log::Level::Trace).ok();
+    let related = Rc::new(RefCell::new(None));
+    let related_clone = Rc::clone(&related);
+
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            input(vec![
+                id("blur-source"),
+                on_blur(move |info: FocusInfo| {
+                    *related_clone.borrow_mut() =
+                        Some((info.related_target_id, info.related_target_tag));
+                }),
+            ]),
+            button(vec![id("blur-related")], vec![]),
+        ],
+    );
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(view)
+        .expect("must update dom");
+
+    let document = sauron_core::dom::document();
+    let source = document.get_element_by_id("blur-source").unwrap();
+    let related_target = document.get_element_by_id("blur-related").unwrap();
+
+    let init = web_sys::FocusEventInit::new();
+    init.set_related_target(Some(&related_target));
+    let blur_event = web_sys::FocusEvent::new_with_focus_event_init_dict("blur", &init).unwrap();
+    web_sys::EventTarget::from(source)
+        .dispatch_event(&blur_event)
+        .unwrap();
+
+    assert_eq!(
+        *related.borrow(),
+        Some((Some("blur-related".to_string()), Some("button".to_string())))
+    );
+}
+
+struct Counter {
+    count: usize,
+}
+
+#[derive(Clone)]
+enum CounterMsg {
+    Increment,
+}
+
+impl Application for Counter {
+    type MSG = CounterMsg;
+
+    fn update(&mut self, msg: CounterMsg) -> Cmd<CounterMsg> {
+        match msg {
+            CounterMsg::Increment => self.count += 1,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<CounterMsg> {
+        button(
+            vec![id("click-msg"), on_click_msg(CounterMsg::Increment)],
+            vec![text(self.count)],
+        )
+    }
+}
+
+#[wasm_bindgen_test]
+async fn click_msg_dispatches_the_msg() {
+    console_log::init_with_level(log::Level::Trace).ok();
+
+    let program = Program::mount_to_body(Counter { count: 0 });
+    for _ in 0..2 {
+        program
+            .simulate_event("#click-msg", SimulatedEvent::Click)
+            .expect("must click");
+    }
+    delay(50).await;
+    let button = sauron_core::dom::document()
+        .get_element_by_id("click-msg")
+        .unwrap();
+    assert_eq!(program.app().count, 2);
+    assert_eq!(button.text_content().as_deref(), Some("2"));
+}
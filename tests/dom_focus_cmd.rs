@@ -0,0 +1,93 @@
+#![deny(warnings)]
+use sauron::{dom::delay, *};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Menu {
+    moved: usize,
+}
+
+enum Msg {
+    Next,
+    Previous,
+    FocusMoved,
+}
+
+impl Application for Menu {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Next => Cmd::focus_next("focus-menu", Msg::FocusMoved),
+            Msg::Previous => Cmd::focus_previous("focus-menu", Msg::FocusMoved),
+            Msg::FocusMoved => {
+                self.moved += 1;
+                Cmd::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div(
+            [id("focus-menu")],
+            (0..3).map(|i| button([id(format!("menu-item-{i}"))], [text(i)])),
+        )
+    }
+}
+
+fn active_element_id() -> Option<String> {
+    sauron_core::dom::document()
+        .active_element()
+        .map(|element| element.id())
+}
+
+fn focus(element_id: &str) {
+    sauron_core::dom::document()
+        .get_element_by_id(element_id)
+        .expect("must have the element")
+        .unchecked_into::<web_sys::HtmlElement>()
+        .focus()
+        .expect("must focus");
+}
+
+#[wasm_bindgen_test]
+async fn focus_cmd_moves_the_focus_then_dispatches_the_msg() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(Menu::default());
+    focus("menu-item-0");
+
+    program.dispatch(Msg::Next);
+    delay(50).await;
+    assert_eq!(active_element_id().as_deref(), Some("menu-item-1"));
+    assert_eq!(program.app().moved, 1);
+
+    program.dispatch(Msg::Previous);
+    program.dispatch(Msg::Previous);
+    delay(50).await;
+    assert_eq!(active_element_id().as_deref(), Some("menu-item-2"));
+    assert_eq!(program.app().moved, 3);
+}
+
+#[wasm_bindgen_test]
+async fn dropped_focus_cmd_has_no_effect() {
+    console_error_panic_hook::set_once();
+    let document = sauron_core::dom::document();
+    let container = document.create_element("div").unwrap();
+    container.set_id("dropped-focus-menu");
+    for i in 0..2 {
+        let item = document.create_element("button").unwrap();
+        item.set_id(&format!("dropped-item-{i}"));
+        container.append_child(&item).unwrap();
+    }
+    document.body().unwrap().append_child(&container).unwrap();
+    focus("dropped-item-0");
+
+    drop(Cmd::focus_next("dropped-focus-menu", ()));
+    drop(Cmd::focus_previous("dropped-focus-menu", ()));
+    delay(50).await;
+    assert_eq!(active_element_id().as_deref(), Some("dropped-item-0"));
+}
@@ -0,0 +1,100 @@
+#![deny(warnings)]
+use sauron::*;
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn focus_is_restored_when_the_focused_input_is_replaced() {
+    console_error_panic_hook::set_once();
+
+    let old: Node<()> = div(
+        vec![],
+        vec![section(
+            vec![],
+            vec![input(vec![r#type("text"), name("username")])],
+        )],
+    );
+    // the section is replaced by an article, recreating the input
+    let new: Node<()> = div(
+        vec![],
+        vec![article(
+            vec![],
+            vec![input(vec![
+                r#type("text"),
+                name("username"),
+                value("alice"),
+            ])],
+        )],
+    );
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(old)
+        .expect("must update dom");
+
+    let document = sauron_core::dom::document();
+    let query_input = || -> web_sys::HtmlInputElement {
+        document
+            .query_selector("input[name=\"username\"]")
+            .unwrap()
+            .expect("must have the input")
+            .unchecked_into()
+    };
+    let old_input = query_input();
+    old_input.set_value("alice");
+    old_input.focus().unwrap();
+    old_input.set_selection_range(1, 3).unwrap();
+
+    simple_program
+        .update_dom_with_vdom(new)
+        .expect("must update dom");
+
+    let new_input = query_input();
+    assert_ne!(old_input, new_input);
+    let active = document
+        .active_element()
+        .expect("must have an active element");
+    assert_eq!(active, *new_input.unchecked_ref::<web_sys::Element>());
+    assert_eq!(new_input.selection_start().unwrap(), Some(1));
+    assert_eq!(new_input.selection_end().unwrap(), Some(3));
+}
+
+#[wasm_bindgen_test]
+fn selection_is_kept_when_the_focused_input_is_patched() {
+    console_error_panic_hook::set_once();
+
+    let view = |class_name: &'static str| -> Node<()> {
+        div(
+            vec![class(class_name)],
+            vec![input(vec![r#type("text"), name("nickname")])],
+        )
+    };
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(view("before"))
+        .expect("must update dom");
+
+    let document = sauron_core::dom::document();
+    let input: web_sys::HtmlInputElement = document
+        .query_selector("input[name=\"nickname\"]")
+        .unwrap()
+        .expect("must have the input")
+        .unchecked_into();
+    input.set_value("bob the builder");
+    input.focus().unwrap();
+    input.set_selection_range(4, 7).unwrap();
+
+    simple_program
+        .update_dom_with_vdom(view("after"))
+        .expect("must update dom");
+
+    assert!(document.query_selector(".after").unwrap().is_some());
+    assert_eq!(input.value(), "bob the builder");
+    assert_eq!(input.selection_start().unwrap(), Some(4));
+    assert_eq!(input.selection_end().unwrap(), Some(7));
+}
@@ -0,0 +1,55 @@
+#![deny(warnings)]
+use sauron::{
+    dom::{delay, request_timeout_callback},
+    *,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Crashing;
+
+impl Application for Crashing {
+    type MSG = ();
+
+    fn update(&mut self, _msg: ()) -> Cmd<()> {
+        panic!("the app crashed");
+    }
+
+    fn view(&self) -> Node<()> {
+        div(vec![id("crashing-app")], vec![text("running")])
+    }
+}
+
+#[wasm_bindgen_test]
+async fn panic_is_shown_with_the_fallback_view() {
+    console_error_panic_hook::set_once();
+
+    let program = Program::mount_to_body(Crashing);
+    let program = (*program).clone().with_global_error_handler(|message| {
+        div(
+            vec![id("crash-screen")],
+            vec![text(format!("Something went wrong: {message}"))],
+        )
+    });
+    // a panic aborts the execution, so the app is updated in a callback of its own
+    // and the fallback view is checked from this test afterwards
+    let _handle = request_timeout_callback(
+        move || {
+            let _ = program.app_mut().update(());
+        },
+        0,
+    )
+    .expect("must set a timeout");
+    delay(50).await;
+
+    let crash_screen = document()
+        .get_element_by_id("crash-screen")
+        .expect("must render the fallback view");
+    assert!(crash_screen
+        .text_content()
+        .expect("must have text")
+        .contains("the app crashed"));
+    assert!(document().get_element_by_id("crashing-app").is_none());
+}
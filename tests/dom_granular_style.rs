@@ -0,0 +1,78 @@
+#![deny(warnings)]
+use sauron::{html::attributes::styles, *};
+use test_fixtures::simple_program;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn boxed(style: Option<Attribute<()>>) -> Node<()> {
+    main(
+        vec![],
+        vec![div([id("granular-style")].into_iter().chain(style), vec![])],
+    )
+}
+
+fn inline_style() -> web_sys::CssStyleDeclaration {
+    let element: web_sys::HtmlElement = sauron_core::dom::document()
+        .get_element_by_id("granular-style")
+        .expect("must have the element")
+        .unchecked_into();
+    element.style()
+}
+
+fn property(name: &str) -> String {
+    inline_style()
+        .get_property_value(name)
+        .expect("must get property")
+}
+
+#[wasm_bindgen_test]
+fn styles_are_patched_declaration_by_declaration() {
+    console_error_panic_hook::set_once();
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(boxed(Some(styles([("color", "red"), ("width", "10px")]))))
+        .expect("must update dom");
+    assert_eq!(property("color"), "red");
+    assert_eq!(property("width"), "10px");
+
+    // a style which is set outside of the view
+    inline_style()
+        .set_property("background-color", "blue")
+        .expect("must set property");
+
+    simple_program
+        .update_dom_with_vdom(boxed(Some(styles([("color", "green"), ("width", "10px")]))))
+        .expect("must update dom");
+    assert_eq!(property("color"), "green");
+    assert_eq!(property("width"), "10px");
+    assert_eq!(property("background-color"), "blue");
+
+    simple_program
+        .update_dom_with_vdom(boxed(Some(styles([("color", "green")]))))
+        .expect("must update dom");
+    assert_eq!(property("width"), "");
+    assert_eq!(property("background-color"), "blue");
+
+    simple_program
+        .update_dom_with_vdom(boxed(None))
+        .expect("must update dom");
+    assert_eq!(property("color"), "");
+    assert_eq!(property("background-color"), "blue");
+}
+
+#[wasm_bindgen_test]
+fn important_styles_are_set_with_priority() {
+    console_error_panic_hook::set_once();
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(boxed(Some(styles([("color", "red !important")]))))
+        .expect("must update dom");
+    assert_eq!(property("color"), "red");
+    assert_eq!(inline_style().get_property_priority("color"), "important");
+}
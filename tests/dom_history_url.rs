@@ -0,0 +1,63 @@
+#![deny(warnings)]
+use sauron::dom::{current_url, delay};
+use sauron::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Router {
+    navigated: usize,
+}
+
+enum Msg {
+    Push(&'static str),
+    Replace(&'static str),
+    UrlChanged,
+}
+
+impl Application for Router {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Push(url) => Cmd::push_url(url, Msg::UrlChanged),
+            Msg::Replace(url) => Cmd::replace_url(url, Msg::UrlChanged),
+            Msg::UrlChanged => {
+                self.navigated += 1;
+                Cmd::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div([id("history-router")], [text(self.navigated)])
+    }
+}
+
+#[wasm_bindgen_test]
+async fn push_and_replace_url_keep_the_query_and_hash() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(Router::default());
+    program.dispatch(Msg::Push("/users/1?tab=posts#top"));
+    delay(50).await;
+    assert_eq!(current_url(), "/users/1?tab=posts#top");
+    assert_eq!(program.app().navigated, 1);
+
+    program.dispatch(Msg::Replace("/users/2"));
+    delay(50).await;
+    assert_eq!(current_url(), "/users/2");
+    assert_eq!(program.app().navigated, 2);
+}
+
+#[wasm_bindgen_test]
+async fn dropped_url_cmd_has_no_effect() {
+    console_error_panic_hook::set_once();
+    let url = current_url();
+
+    drop(Cmd::push_url("/dropped-push", ()));
+    drop(Cmd::replace_url("/dropped-replace", ()));
+    delay(50).await;
+    assert_eq!(current_url(), url);
+}
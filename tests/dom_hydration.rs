@@ -0,0 +1,95 @@
+#![deny(warnings)]
+use sauron::{dom::MountProcedure, *};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct Counter {
+    count: i32,
+}
+
+enum Msg {
+    Increment,
+}
+
+impl Application for Counter {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Increment => self.count += 1,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        main(
+            [class("counter")],
+            [
+                button(
+                    [id("hydrated-increment"), on_click(|_| Msg::Increment)],
+                    [text("+")],
+                ),
+                span([id("hydrated-count")], [text(self.count)]),
+            ],
+        )
+    }
+}
+
+/// a container with the `html` rendered by the server
+fn server_rendered(html: &str) -> web_sys::Element {
+    let document = sauron_core::dom::document();
+    let container = document.create_element("div").unwrap();
+    container.set_inner_html(html);
+    document.body().unwrap().append_child(&container).unwrap();
+    container
+}
+
+fn element(element_id: &str) -> web_sys::Element {
+    sauron_core::dom::document()
+        .get_element_by_id(element_id)
+        .expect("must exist")
+}
+
+#[wasm_bindgen_test]
+async fn hydrate_reuses_the_server_rendered_elements() {
+    console_error_panic_hook::set_once();
+
+    let server_html = Counter { count: 5 }.view().render_to_string();
+    let container = server_rendered(&format!("\n  {server_html}\n"));
+    let server_button = element("hydrated-increment");
+
+    let mut program = Program::new(Counter { count: 5 });
+    program.mount(&container, MountProcedure::hydrate());
+
+    assert!(element("hydrated-increment").is_same_node(Some(&server_button)));
+    assert_eq!(container.children().length(), 1);
+
+    // the event listeners are attached to the server rendered elements
+    server_button
+        .unchecked_ref::<web_sys::HtmlElement>()
+        .click();
+    sauron::dom::delay(50).await;
+    assert_eq!(program.app().count, 6);
+    assert_eq!(element("hydrated-count").text_content().unwrap(), "6");
+    program.unmount();
+}
+
+#[wasm_bindgen_test]
+fn hydrate_patches_the_mismatches() {
+    console_error_panic_hook::set_once();
+
+    let container = server_rendered(
+        r#"<main class="stale" data-server="1"><button id="hydrated-increment">+</button><p id="hydrated-count">0</p></main>"#,
+    );
+
+    let mut program = Program::new(Counter { count: 3 });
+    program.mount(&container, MountProcedure::hydrate());
+
+    assert_eq!(
+        container.inner_html(),
+        r#"<main class="counter"><button id="hydrated-increment">+</button><span id="hydrated-count">3</span></main>"#
+    );
+    program.unmount();
+}
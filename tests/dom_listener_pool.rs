@@ -0,0 +1,184 @@
+#![deny(warnings)]
+use sauron::{
+    dom::{delay, SimulatedEvent},
+    *,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const ITEMS: usize = 1000;
+
+struct List {
+    id: &'static str,
+    /// whether the items share a callback with an id
+    shared: bool,
+    items: usize,
+    clicks: usize,
+}
+
+enum Msg {
+    Clicked,
+    Truncate(usize),
+}
+
+impl List {
+    fn new(id: &'static str, shared: bool) -> Self {
+        Self {
+            id,
+            shared,
+            items: ITEMS,
+            clicks: 0,
+        }
+    }
+}
+
+impl Application for List {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Clicked => self.clicks += 1,
+            Msg::Truncate(items) => self.items = items,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div(
+            [id(self.id)],
+            [
+                span([class("clicks")], [text(self.clicks)]),
+                ul(
+                    [],
+                    (0..self.items).map(|item| {
+                        let on_select = if self.shared {
+                            attr("click", EventCallback::with_id("select", |_| Msg::Clicked))
+                        } else {
+                            on_click(|_| Msg::Clicked)
+                        };
+                        li([on_select], [text(item)])
+                    }),
+                ),
+            ],
+        )
+    }
+}
+
+fn clicks_text(id: &str) -> String {
+    sauron_core::dom::document()
+        .query_selector(&format!("#{id} .clicks"))
+        .expect("must be a valid selector")
+        .expect("must have the clicks")
+        .text_content()
+        .expect("must have text")
+}
+
+#[wasm_bindgen_test]
+async fn each_item_gets_a_closure_without_an_id() {
+    console_error_panic_hook::set_once();
+
+    let program = Program::mount_to_body(List::new("listener-pool-plain", false));
+    delay(50).await;
+    assert_eq!(program.listener_closure_count(), ITEMS);
+}
+
+#[wasm_bindgen_test]
+async fn items_share_the_closure_of_the_same_id() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(List::new("listener-pool-shared", true));
+    delay(50).await;
+    assert_eq!(program.listener_closure_count(), 1);
+
+    program
+        .simulate_event(
+            "#listener-pool-shared li:nth-child(500)",
+            SimulatedEvent::Click,
+        )
+        .expect("must click");
+    delay(50).await;
+    assert_eq!(clicks_text("listener-pool-shared"), "1");
+
+    // the shared closure stays attached to the remaining items
+    program.dispatch(Msg::Truncate(10));
+    delay(50).await;
+    program
+        .simulate_event(
+            "#listener-pool-shared li:nth-child(10)",
+            SimulatedEvent::Click,
+        )
+        .expect("must click");
+    delay(50).await;
+    assert_eq!(clicks_text("listener-pool-shared"), "2");
+    assert_eq!(program.listener_closure_count(), 1);
+}
+
+struct Counters {
+    left: usize,
+    right: usize,
+}
+
+enum CounterMsg {
+    Increment,
+}
+
+enum PairMsg {
+    Left(CounterMsg),
+    Right(CounterMsg),
+}
+
+fn counter_view(count: usize) -> Node<CounterMsg> {
+    button(
+        [attr(
+            "click",
+            EventCallback::with_id("increment", |_| CounterMsg::Increment),
+        )],
+        [text(count)],
+    )
+}
+
+impl Application for Counters {
+    type MSG = PairMsg;
+
+    fn update(&mut self, msg: PairMsg) -> Cmd<PairMsg> {
+        match msg {
+            PairMsg::Left(CounterMsg::Increment) => self.left += 1,
+            PairMsg::Right(CounterMsg::Increment) => self.right += 1,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<PairMsg> {
+        div(
+            [id("listener-pool-mapped")],
+            [
+                counter_view(self.left).map_msg(PairMsg::Left),
+                counter_view(self.right).map_msg(PairMsg::Right),
+            ],
+        )
+    }
+}
+
+#[wasm_bindgen_test]
+async fn mapped_callbacks_of_the_same_id_dispatch_their_own_msg() {
+    console_error_panic_hook::set_once();
+
+    let program = Program::mount_to_body(Counters { left: 0, right: 0 });
+    delay(50).await;
+    assert_eq!(program.listener_closure_count(), 2);
+
+    program
+        .simulate_event(
+            "#listener-pool-mapped button:nth-child(2)",
+            SimulatedEvent::Click,
+        )
+        .expect("must click");
+    delay(50).await;
+    let text = sauron_core::dom::document()
+        .get_element_by_id("listener-pool-mapped")
+        .expect("must have the counters")
+        .text_content()
+        .expect("must have text");
+    assert_eq!(text, "01");
+}
@@ -0,0 +1,80 @@
+#![deny(warnings)]
+use sauron::{
+    dom::{delay, MountProcedure},
+    *,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct App {
+    is_shown: bool,
+    anchor: Option<RectInfo>,
+    measured: usize,
+}
+
+enum Msg {
+    Show,
+    MeasureMissing,
+    Measured(Option<RectInfo>),
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Show => {
+                self.is_shown = true;
+                // the anchor is only in the view after this update is rendered
+                Cmd::measure("measured-anchor", Msg::Measured)
+            }
+            Msg::MeasureMissing => Cmd::measure("missing-anchor", Msg::Measured),
+            Msg::Measured(rect) => {
+                self.anchor = rect;
+                self.measured += 1;
+                Cmd::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div(
+            [],
+            [if self.is_shown {
+                div(
+                    [
+                        id("measured-anchor"),
+                        style! {width: px(120), height: px(30)},
+                    ],
+                    [text("anchor")],
+                )
+            } else {
+                text("hidden")
+            }],
+        )
+    }
+}
+
+#[wasm_bindgen_test]
+async fn measure_the_element_after_it_is_rendered() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::new(App::default());
+    program.mount(
+        &sauron_core::dom::document().body().unwrap(),
+        MountProcedure::append(),
+    );
+
+    program.dispatch(Msg::Show);
+    delay(100).await;
+    assert_eq!(program.app().measured, 1);
+    let anchor = program.app().anchor.expect("must be measured");
+    assert_eq!((anchor.width, anchor.height), (120.0, 30.0));
+
+    program.dispatch(Msg::MeasureMissing);
+    delay(100).await;
+    assert_eq!(program.app().measured, 2);
+    assert_eq!(program.app().anchor, None);
+}
@@ -0,0 +1,66 @@
+#![deny(warnings)]
+use sauron::{dom::delay, *};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const ROWS: usize = 1000;
+
+#[derive(Default)]
+struct Rows {
+    clicked: Option<usize>,
+}
+
+enum Msg {
+    Click(usize),
+}
+
+impl Application for Rows {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Click(row) => self.clicked = Some(row),
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        ul(
+            [id("batched-rows")],
+            (0..ROWS).map(|row| {
+                li(
+                    [
+                        id(format!("batched-row-{row}")),
+                        on_click(move |_| Msg::Click(row)),
+                    ],
+                    [text(row)],
+                )
+            }),
+        )
+    }
+}
+
+fn mount_node() -> web_sys::Element {
+    let document = sauron_core::dom::document();
+    let mount_node = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&mount_node).unwrap();
+    mount_node
+}
+
+#[wasm_bindgen_test]
+async fn listeners_of_the_batched_tree_are_attached() {
+    console_error_panic_hook::set_once();
+
+    let program = Program::append_to_mount(Rows::default(), &mount_node());
+    let rows = sauron_core::dom::document()
+        .query_selector_all("#batched-rows li")
+        .unwrap();
+    assert_eq!(rows.length() as usize, ROWS);
+
+    let last_row: web_sys::HtmlElement = rows.get(ROWS as u32 - 1).unwrap().dyn_into().unwrap();
+    last_row.click();
+    delay(50).await;
+    assert_eq!(program.app().clicked, Some(ROWS - 1));
+}
@@ -0,0 +1,59 @@
+#![deny(warnings)]
+use sauron::{
+    dom::{MountAction, MountTarget},
+    *,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct Hello;
+
+impl Application for Hello {
+    type MSG = ();
+
+    fn update(&mut self, _msg: ()) -> Cmd<()> {
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<()> {
+        span(vec![class("hello")], vec![text("hello")])
+    }
+}
+
+#[wasm_bindgen_test]
+fn mount_to_the_first_matching_element() {
+    console_error_panic_hook::set_once();
+    let document = sauron_core::dom::document();
+    let body = document.body().expect("must have a body");
+    for _ in 0..2 {
+        let container = document.create_element("div").unwrap();
+        container.set_class_name("selector-mount");
+        body.append_child(&container).unwrap();
+    }
+
+    let _program = Program::mount_to_selector(
+        Hello,
+        ".selector-mount",
+        MountAction::Append,
+        MountTarget::MountNode,
+    )
+    .expect("must mount");
+
+    let containers = document.query_selector_all(".selector-mount").unwrap();
+    let first: web_sys::Element = containers.get(0).unwrap().unchecked_into();
+    let second: web_sys::Element = containers.get(1).unwrap().unchecked_into();
+    assert_eq!(first.inner_html(), r#"<span class="hello">hello</span>"#);
+    assert_eq!(second.inner_html(), "");
+}
+
+#[wasm_bindgen_test]
+fn no_matching_element_is_an_error() {
+    let result = Program::mount_to_selector(
+        Hello,
+        "#no-such-mount",
+        MountAction::Append,
+        MountTarget::MountNode,
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,69 @@
+#![deny(warnings)]
+use sauron::{
+    svg::{self, attributes::xlink_href},
+    *,
+};
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const XLINK: &str = "http://www.w3.org/1999/xlink";
+
+#[wasm_bindgen_test]
+fn namespaced_attributes_are_set_and_removed_with_their_namespace() {
+    console_error_panic_hook::set_once();
+
+    let old: Node<()> = svg::svg(
+        vec![],
+        vec![svg::r#use(
+            vec![id("ns-use"), attr("href", "#plain"), xlink_href("#icon")],
+            vec![],
+        )],
+    );
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(old)
+        .expect("must update dom");
+
+    let element = sauron_core::dom::document()
+        .get_element_by_id("ns-use")
+        .expect("must have the use element");
+    assert_eq!(
+        element.get_attribute_ns(Some(XLINK), "href"),
+        Some("#icon".to_string())
+    );
+    assert_eq!(element.get_attribute("href"), Some("#plain".to_string()));
+
+    let update: Node<()> = svg::svg(
+        vec![],
+        vec![svg::r#use(
+            vec![id("ns-use"), attr("href", "#plain"), xlink_href("#other")],
+            vec![],
+        )],
+    );
+    simple_program
+        .update_dom_with_vdom(update)
+        .expect("must update dom");
+    assert_eq!(
+        element.get_attribute_ns(Some(XLINK), "href"),
+        Some("#other".to_string())
+    );
+    assert_eq!(element.get_attribute("href"), Some("#plain".to_string()));
+
+    let removed: Node<()> = svg::svg(
+        vec![],
+        vec![svg::r#use(
+            vec![id("ns-use"), attr("href", "#plain")],
+            vec![],
+        )],
+    );
+    simple_program
+        .update_dom_with_vdom(removed)
+        .expect("must update dom");
+    assert_eq!(element.get_attribute_ns(Some(XLINK), "href"), None);
+    assert_eq!(element.get_attribute("href"), Some("#plain".to_string()));
+}
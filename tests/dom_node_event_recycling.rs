@@ -17,15 +17,12 @@ fn elements_with_different_event_should_not_be_recycle() {
 
     let elem_id = "input-add-event-test";
 
-    let old = input(
-        vec![
-            id(elem_id),
-            on_input(move |_event: InputEvent| {
-                *text_clone.borrow_mut() = "Old value".to_string();
-            }),
-        ],
-        vec![],
-    );
+    let old = input(vec![
+        id(elem_id),
+        on_input(move |_event: InputEvent| {
+            *text_clone.borrow_mut() = "Old value".to_string();
+        }),
+    ]);
 
     let old_clone = old.clone();
     dbg!(&old);
@@ -39,7 +36,7 @@ fn elements_with_different_event_should_not_be_recycle() {
     });
     log::info!("old: {:#?}", old);
     log::info!("cb2: {:#?}", cb2);
-    let new = input(vec![id(elem_id), cb2.clone()], vec![]);
+    let new = input(vec![id(elem_id), cb2.clone()]);
 
     let patches: Vec<Patch<()>> = diff(&old, &new);
     // FIXME: this should replace the old node with a new one since the even essentially is a new
@@ -0,0 +1,44 @@
+#![deny(warnings)]
+use sauron::*;
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn node_ref_is_set_on_mount_and_cleared_on_removal() {
+    console_error_panic_hook::set_once();
+
+    let input_ref = NodeRef::default();
+
+    let old: Node<()> = main(
+        vec![class("node-ref")],
+        vec![
+            input(vec![id("ref-input"), node_ref(&input_ref)]),
+            span(vec![], vec![text("hello")]),
+        ],
+    );
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(old)
+        .expect("must update dom");
+
+    let element = input_ref
+        .borrow()
+        .clone()
+        .expect("must be set when mounted");
+    assert_eq!(element.id(), "ref-input");
+
+    let update: Node<()> = main(
+        vec![class("node-ref")],
+        vec![span(vec![], vec![text("hello")])],
+    );
+    simple_program
+        .update_dom_with_vdom(update)
+        .expect("must update dom");
+
+    assert!(input_ref.borrow().is_none(), "must be cleared when removed");
+}
@@ -0,0 +1,66 @@
+#![deny(warnings)]
+use sauron::{dom::delay, *};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Form {
+    is_loading: bool,
+    saves: usize,
+    updates: usize,
+}
+
+enum Msg {
+    Save,
+}
+
+impl Application for Form {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        self.updates += 1;
+        match msg {
+            Msg::Save => self.saves += 1,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        let is_loading = self.is_loading;
+        button(
+            [
+                id("save-form"),
+                on_click_opt(move |_| (!is_loading).then_some(Msg::Save)),
+            ],
+            [text("save")],
+        )
+    }
+}
+
+fn click_save() {
+    let button: web_sys::HtmlElement = sauron_core::dom::document()
+        .get_element_by_id("save-form")
+        .expect("must exist")
+        .unchecked_into();
+    button.click();
+}
+
+#[wasm_bindgen_test]
+async fn no_msg_is_dispatched_when_the_listener_returns_none() {
+    console_error_panic_hook::set_once();
+
+    let program = Program::mount_to_body(Form::default());
+    click_save();
+    delay(50).await;
+    assert_eq!(program.app().saves, 1);
+
+    program.app_mut().is_loading = true;
+    program.redraw();
+    click_save();
+    delay(50).await;
+    // the click is ignored without calling update
+    assert_eq!(program.app().saves, 1);
+    assert_eq!(program.app().updates, 1);
+}
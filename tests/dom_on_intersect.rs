@@ -0,0 +1,51 @@
+#![deny(warnings)]
+use sauron::{dom::delay, *};
+use std::{cell::RefCell, rc::Rc};
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn on_intersect_is_called_when_the_element_is_visible() {
+    console_error_panic_hook::set_once();
+
+    let infos: Rc<RefCell<Vec<IntersectInfo>>> = Rc::new(RefCell::new(vec![]));
+    let infos_clone = Rc::clone(&infos);
+
+    let old: Node<()> = main(
+        vec![],
+        vec![div(
+            vec![
+                id("intersect-observed"),
+                intersect_threshold([0.0, 1.0]),
+                on_intersect(move |info| infos_clone.borrow_mut().push(info)),
+            ],
+            vec![text("content")],
+        )],
+    );
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(old)
+        .expect("must update dom");
+
+    delay(100).await;
+    let first = infos
+        .borrow()
+        .first()
+        .copied()
+        .expect("must observe the initial intersection");
+    assert!(first.is_intersecting);
+    assert!(first.intersection_ratio > 0.0);
+
+    // removing the element disconnects the observer
+    simple_program
+        .update_dom_with_vdom(main(vec![], vec![]))
+        .expect("must update dom");
+    let observed = infos.borrow().len();
+    delay(100).await;
+    assert_eq!(observed, infos.borrow().len());
+}
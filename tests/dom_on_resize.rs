@@ -0,0 +1,49 @@
+#![deny(warnings)]
+use sauron::{dom::delay, *};
+use std::{cell::RefCell, rc::Rc};
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn on_resize_is_called_with_the_element_size() {
+    console_error_panic_hook::set_once();
+
+    let sizes: Rc<RefCell<Vec<SizeInfo>>> = Rc::new(RefCell::new(vec![]));
+    let sizes_clone = Rc::clone(&sizes);
+
+    let old: Node<()> = main(
+        vec![],
+        vec![div(
+            vec![
+                id("resize-observed"),
+                on_resize(move |size| sizes_clone.borrow_mut().push(size)),
+            ],
+            vec![text("content")],
+        )],
+    );
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(old)
+        .expect("must update dom");
+
+    delay(100).await;
+    let first = sizes
+        .borrow()
+        .first()
+        .copied()
+        .expect("must observe the initial size");
+    assert!(first.width > 0.0);
+
+    // removing the element disconnects the observer
+    simple_program
+        .update_dom_with_vdom(main(vec![], vec![]))
+        .expect("must update dom");
+    let observed = sizes.borrow().len();
+    delay(100).await;
+    assert_eq!(observed, sizes.borrow().len());
+}
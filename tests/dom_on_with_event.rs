@@ -0,0 +1,108 @@
+#![deny(warnings)]
+use sauron::{dom::events::EventHandle, *};
+use std::{cell::RefCell, rc::Rc};
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Editor {
+    has_unsaved_changes: bool,
+    leave_attempts: usize,
+}
+
+enum Msg {
+    Leave(EventHandle),
+}
+
+impl Application for Editor {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Leave(handle) => {
+                self.leave_attempts += 1;
+                if self.has_unsaved_changes {
+                    assert!(handle.prevent_default());
+                }
+            }
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        a(
+            [
+                id("leave-editor"),
+                href("#settings"),
+                on_with_event("click", Msg::Leave),
+            ],
+            [text("settings")],
+        )
+    }
+}
+
+fn dispatch_click(target: web_sys::Element) -> web_sys::MouseEvent {
+    let init = web_sys::MouseEventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    let click = web_sys::MouseEvent::new_with_mouse_event_init_dict("click", &init).unwrap();
+    web_sys::EventTarget::from(target)
+        .dispatch_event(&click)
+        .unwrap();
+    click
+}
+
+fn leave_link() -> web_sys::Element {
+    sauron_core::dom::document()
+        .get_element_by_id("leave-editor")
+        .expect("must exist")
+}
+
+#[wasm_bindgen_test]
+fn update_prevents_default_based_on_the_state() {
+    console_error_panic_hook::set_once();
+
+    let program = Program::mount_to_body(Editor::default());
+    let click = dispatch_click(leave_link());
+    // the msg is updated while the event is dispatched, before the click returns
+    assert_eq!(program.app().leave_attempts, 1);
+    assert!(!click.default_prevented());
+
+    program.app_mut().has_unsaved_changes = true;
+    let click = dispatch_click(leave_link());
+    assert_eq!(program.app().leave_attempts, 2);
+    assert!(click.default_prevented());
+}
+
+#[wasm_bindgen_test]
+fn prevent_default_has_no_effect_after_the_dispatch() {
+    let handle = Rc::new(RefCell::new(None));
+    let handle_clone = Rc::clone(&handle);
+    let view: Node<()> = button(
+        [
+            id("late-prevent-default"),
+            on_with_event("click", move |event| {
+                *handle_clone.borrow_mut() = Some(event);
+            }),
+        ],
+        [],
+    );
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(view)
+        .expect("must update dom");
+
+    let button = sauron_core::dom::document()
+        .get_element_by_id("late-prevent-default")
+        .unwrap();
+    let click = dispatch_click(button);
+
+    let handle = handle.borrow_mut().take().expect("must have the event");
+    assert!(!handle.is_dispatching());
+    assert!(!handle.prevent_default());
+    assert!(!click.default_prevented());
+}
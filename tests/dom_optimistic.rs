@@ -0,0 +1,91 @@
+#![deny(warnings)]
+use sauron::{dom::delay, *};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Clone)]
+struct Comments {
+    id: &'static str,
+    comments: Vec<String>,
+}
+
+#[derive(Clone)]
+enum Msg {
+    Post(String),
+}
+
+impl Application for Comments {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Post(comment) => self.comments.push(comment),
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        ul(
+            [id(self.id)],
+            self.comments.iter().map(|comment| li([], [text(comment)])),
+        )
+    }
+}
+
+fn comments_text(id: &str) -> String {
+    sauron_core::dom::document()
+        .get_element_by_id(id)
+        .expect("must have the comments")
+        .text_content()
+        .expect("must have text")
+}
+
+#[wasm_bindgen_test]
+async fn rollback_keeps_the_later_msgs() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(Comments {
+        id: "optimistic-rollback",
+        comments: vec!["first".to_string()],
+    });
+    delay(50).await;
+
+    program
+        .optimistic("req-1", Msg::Post("pending".to_string()))
+        .expect("must update");
+    assert_eq!(comments_text("optimistic-rollback"), "firstpending");
+    assert!(program
+        .optimistic("req-1", Msg::Post("again".to_string()))
+        .is_err());
+
+    program.dispatch(Msg::Post("typed".to_string()));
+    delay(50).await;
+    assert_eq!(comments_text("optimistic-rollback"), "firstpendingtyped");
+
+    program.rollback("req-1").expect("must roll back");
+    assert_eq!(comments_text("optimistic-rollback"), "firsttyped");
+    assert!(program.rollback("req-1").is_err());
+}
+
+#[wasm_bindgen_test]
+async fn committed_update_is_kept() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(Comments {
+        id: "optimistic-commit",
+        comments: vec![],
+    });
+    delay(50).await;
+
+    program
+        .optimistic(1, Msg::Post("saved".to_string()))
+        .expect("must update");
+    program
+        .optimistic(2, Msg::Post("rejected".to_string()))
+        .expect("must update");
+    assert!(program.commit(1));
+    program.rollback(2).expect("must roll back");
+    assert_eq!(comments_text("optimistic-commit"), "saved");
+    assert!(!program.commit(2));
+}
@@ -0,0 +1,97 @@
+#![deny(warnings)]
+use sauron::{vdom::shared, *};
+use std::rc::Rc;
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn scores(values: [&str; 3]) -> Node<()> {
+    ul(
+        vec![id("patch-at-scores")],
+        values.map(|value| li(vec![], vec![text(value)])),
+    )
+}
+
+fn scores_html() -> String {
+    sauron_core::dom::document()
+        .get_element_by_id("patch-at-scores")
+        .expect("must have the list")
+        .outer_html()
+}
+
+#[wasm_bindgen_test]
+fn patch_at_replaces_the_node_in_vdom_and_dom() {
+    console_error_panic_hook::set_once();
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(scores(["1", "2", "3"]))
+        .expect("must update dom");
+
+    simple_program
+        .patch_at([1], li(vec![class("changed")], vec![text("20")]))
+        .expect("must patch");
+    assert_eq!(
+        scores_html(),
+        r#"<ul id="patch-at-scores"><li>1</li><li class="changed">20</li><li>3</li></ul>"#
+    );
+
+    // the vdom is patched as well, so the next diff only changes the last item
+    let mut view = scores(["1", "20", "30"]);
+    view.replace_node_at_path([1], li(vec![class("changed")], vec![text("20")]));
+    let total_patches = simple_program
+        .update_dom_with_vdom(view)
+        .expect("must update dom");
+    assert_eq!(total_patches, 1);
+    assert_eq!(
+        scores_html(),
+        r#"<ul id="patch-at-scores"><li>1</li><li class="changed">20</li><li>30</li></ul>"#
+    );
+}
+
+#[wasm_bindgen_test]
+fn patch_at_errors_on_a_path_out_of_range() {
+    console_error_panic_hook::set_once();
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(scores(["1", "2", "3"]))
+        .expect("must update dom");
+
+    assert!(simple_program.patch_at([3], li(vec![], vec![])).is_err());
+    assert!(simple_program.patch_at([0, 0, 0], text("x")).is_err());
+    assert_eq!(
+        scores_html(),
+        r#"<ul id="patch-at-scores"><li>1</li><li>2</li><li>3</li></ul>"#
+    );
+}
+
+#[wasm_bindgen_test]
+fn patch_at_replaces_the_node_inside_a_shared_node() {
+    console_error_panic_hook::set_once();
+
+    let mut simple_program = simple_program();
+    let shared_scores = Rc::new(scores(["1", "2", "3"]));
+    simple_program
+        .update_dom_with_vdom(div(vec![], vec![shared(Rc::clone(&shared_scores))]))
+        .expect("must update dom");
+
+    simple_program
+        .patch_at([0, 1], li(vec![], vec![text("20")]))
+        .expect("must patch the child of the shared node");
+    assert_eq!(
+        scores_html(),
+        r#"<ul id="patch-at-scores"><li>1</li><li>20</li><li>3</li></ul>"#
+    );
+    // the shared node is copied before it is patched
+    assert_eq!(*shared_scores, scores(["1", "2", "3"]));
+
+    // the vdom matches the DOM, so the same view is not patched again
+    let total_patches = simple_program
+        .update_dom_with_vdom(div(vec![], vec![scores(["1", "20", "3"])]))
+        .expect("must update dom");
+    assert_eq!(total_patches, 0);
+}
@@ -0,0 +1,120 @@
+#![deny(warnings)]
+use sauron::{html::portal, *};
+use std::{cell::Cell, rc::Rc};
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn portal_is_mounted_into_the_target_and_removed_with_it() {
+    console_error_panic_hook::set_once();
+    let document = web_sys::window().unwrap().document().unwrap();
+
+    let target = document.create_element("section").unwrap();
+    target.set_id("portal-target");
+    document.body().unwrap().append_child(&target).unwrap();
+
+    let clicked = Rc::new(Cell::new(false));
+    let clicked_clone = Rc::clone(&clicked);
+
+    let old: Node<()> = main(
+        vec![class("portal-test")],
+        vec![
+            span(vec![], vec![text("inline")]),
+            portal(
+                "#portal-target",
+                [button(
+                    vec![
+                        id("portal-button"),
+                        on_click(move |_| clicked_clone.set(true)),
+                    ],
+                    vec![text("in portal")],
+                )],
+            ),
+        ],
+    );
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(old)
+        .expect("must update dom");
+
+    let button = document
+        .query_selector("#portal-target #portal-button")
+        .unwrap()
+        .expect("must be mounted into the target");
+    let container = document.query_selector(".portal-test").unwrap().unwrap();
+    assert!(container
+        .query_selector("#portal-button")
+        .unwrap()
+        .is_none());
+
+    let click_event = web_sys::MouseEvent::new("click").unwrap();
+    button.dispatch_event(&click_event).unwrap();
+    assert!(clicked.get());
+
+    let update: Node<()> = main(
+        vec![class("portal-test")],
+        vec![span(vec![], vec![text("inline")])],
+    );
+    simple_program
+        .update_dom_with_vdom(update)
+        .expect("must update dom");
+    assert!(document.get_element_by_id("portal-button").is_none());
+    assert_eq!(target.child_element_count(), 0);
+}
+
+#[wasm_bindgen_test]
+fn portal_is_moved_when_the_target_selector_changes() {
+    console_error_panic_hook::set_once();
+    let document = web_sys::window().unwrap().document().unwrap();
+
+    let first = document.create_element("section").unwrap();
+    first.set_id("first-target");
+    document.body().unwrap().append_child(&first).unwrap();
+    let second = document.create_element("section").unwrap();
+    second.set_id("second-target");
+    document.body().unwrap().append_child(&second).unwrap();
+
+    let view = |target_selector: &str| -> Node<()> {
+        main(
+            vec![class("portal-move-test")],
+            vec![portal(
+                target_selector,
+                [span(vec![id("moving-portal")], vec![text("moving")])],
+            )],
+        )
+    };
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(view("#first-target"))
+        .expect("must update dom");
+    assert!(document
+        .query_selector("#first-target #moving-portal")
+        .unwrap()
+        .is_some());
+
+    simple_program
+        .update_dom_with_vdom(view("#second-target"))
+        .expect("must update dom");
+    assert_eq!(first.child_element_count(), 0);
+    assert!(document
+        .query_selector("#second-target #moving-portal")
+        .unwrap()
+        .is_some());
+    let container = document
+        .query_selector(".portal-move-test")
+        .unwrap()
+        .unwrap();
+    assert_eq!(container.child_element_count(), 0);
+    assert!(container.get_attribute("portal_target").is_none());
+    assert!(second
+        .first_element_child()
+        .unwrap()
+        .get_attribute("portal_target")
+        .is_none());
+}
@@ -0,0 +1,68 @@
+#![deny(warnings)]
+use sauron::{html::attributes::preserve_scroll, *};
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// a pane which is 50px high and shows the `lines` of 20px each
+fn pane(tag: &'static str, lines: usize) -> Node<()> {
+    main(
+        vec![],
+        vec![html::element(
+            tag,
+            [
+                id("scroll-pane"),
+                preserve_scroll(),
+                style! {height: px(50), overflow: "auto"},
+            ],
+            (0..lines).map(|i| div([style! {height: px(20)}], [text(i)])),
+        )],
+    )
+}
+
+fn pane_element() -> web_sys::Element {
+    sauron_core::dom::document()
+        .get_element_by_id("scroll-pane")
+        .expect("must have the pane")
+}
+
+#[wasm_bindgen_test]
+fn scroll_position_is_kept_when_the_pane_is_replaced() {
+    console_error_panic_hook::set_once();
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(pane("section", 10))
+        .expect("must update dom");
+    pane_element().set_scroll_top(40);
+
+    // the section is replaced by an article
+    simple_program
+        .update_dom_with_vdom(pane("article", 10))
+        .expect("must update dom");
+    let element = pane_element();
+    assert_eq!(element.tag_name().to_lowercase(), "article");
+    assert_eq!(element.scroll_top(), 40);
+}
+
+#[wasm_bindgen_test]
+fn pane_scrolled_to_the_bottom_stays_at_the_bottom() {
+    console_error_panic_hook::set_once();
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(pane("section", 10))
+        .expect("must update dom");
+    let element = pane_element();
+    element.set_scroll_top(element.scroll_height());
+    assert_eq!(element.scroll_top(), 150);
+
+    // new lines are appended
+    simple_program
+        .update_dom_with_vdom(pane("section", 12))
+        .expect("must update dom");
+    assert_eq!(pane_element().scroll_top(), 190);
+}
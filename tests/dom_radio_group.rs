@@ -0,0 +1,120 @@
+#![deny(warnings)]
+use sauron::*;
+use test_fixtures::simple_program;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const SIZES: [&str; 3] = ["small", "medium", "large"];
+
+fn sizes(selected: &str) -> Node<()> {
+    main(
+        vec![],
+        vec![fieldset(
+            vec![],
+            SIZES.iter().map(|size| {
+                input(vec![
+                    r#type("radio"),
+                    name("size"),
+                    id(*size),
+                    value(*size),
+                    checked(*size == selected),
+                ])
+            }),
+        )],
+    )
+}
+
+fn radio(size: &str) -> web_sys::HtmlInputElement {
+    sauron_core::dom::document()
+        .get_element_by_id(size)
+        .expect("must have the radio")
+        .unchecked_into()
+}
+
+fn checked_sizes() -> Vec<&'static str> {
+    SIZES
+        .iter()
+        .copied()
+        .filter(|size| radio(size).checked())
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn radio_group_follows_the_state() {
+    console_error_panic_hook::set_once();
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(sizes("medium"))
+        .expect("must update dom");
+    assert_eq!(checked_sizes(), vec!["medium"]);
+
+    simple_program
+        .update_dom_with_vdom(sizes("large"))
+        .expect("must update dom");
+    assert_eq!(checked_sizes(), vec!["large"]);
+
+    // the user checks another radio, which the state doesn't accept
+    radio("small").click();
+    assert_eq!(checked_sizes(), vec!["small"]);
+    simple_program
+        .update_dom_with_vdom(sizes("large"))
+        .expect("must update dom");
+    assert_eq!(checked_sizes(), vec!["large"]);
+
+    // the state changes back to a radio which the browser has unchecked
+    radio("small").click();
+    simple_program
+        .update_dom_with_vdom(sizes("medium"))
+        .expect("must update dom");
+    assert_eq!(checked_sizes(), vec!["medium"]);
+}
+
+/// a group which none of the radios is checked in the view, next to a text which changes
+fn uncontrolled_colors(label: &str) -> Node<()> {
+    main(
+        vec![],
+        vec![
+            span(vec![], vec![text(label)]),
+            fieldset(
+                vec![],
+                ["red", "blue"].iter().map(|color| {
+                    input(vec![
+                        r#type("radio"),
+                        name("color"),
+                        id(*color),
+                        value(*color),
+                    ])
+                }),
+            ),
+        ],
+    )
+}
+
+#[wasm_bindgen_test]
+fn uncontrolled_radio_group_keeps_the_choice_of_the_user() {
+    console_error_panic_hook::set_once();
+
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(uncontrolled_colors("before"))
+        .expect("must update dom");
+    radio("blue").click();
+
+    // an unrelated change of the view doesn't clear the checked radio
+    simple_program
+        .update_dom_with_vdom(uncontrolled_colors("after"))
+        .expect("must update dom");
+    assert!(radio("blue").checked());
+    assert!(!radio("red").checked());
+
+    // and neither does a render without any patches
+    simple_program
+        .update_dom_with_vdom(uncontrolled_colors("after"))
+        .expect("must update dom");
+    assert!(radio("blue").checked());
+}
@@ -0,0 +1,49 @@
+#![deny(warnings)]
+use sauron::*;
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// an app which view depends on a counter that is changed outside of `update`
+struct ExternalCounter {
+    count: Rc<Cell<i32>>,
+}
+
+impl Application for ExternalCounter {
+    type MSG = ();
+
+    fn update(&mut self, _msg: ()) -> Cmd<()> {
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<()> {
+        div(vec![id("redraw-counter")], vec![text(self.count.get())])
+    }
+}
+
+#[wasm_bindgen_test]
+fn redraw_reflects_external_state() {
+    console_error_panic_hook::set_once();
+
+    let count = Rc::new(Cell::new(0));
+    let program = Program::mount_to_body(ExternalCounter {
+        count: Rc::clone(&count),
+    });
+
+    let counter = || {
+        sauron_core::dom::document()
+            .get_element_by_id("redraw-counter")
+            .expect("must have the counter")
+            .text_content()
+            .expect("must have text")
+    };
+    assert_eq!(counter(), "0");
+
+    count.set(42);
+    assert_eq!(counter(), "0", "must not update without a redraw");
+
+    program.redraw();
+    assert_eq!(counter(), "42");
+}
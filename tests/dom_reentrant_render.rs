@@ -0,0 +1,63 @@
+#![deny(warnings)]
+use sauron::{dom::delay, *};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Todos {
+    items: Vec<String>,
+    mounted: usize,
+}
+
+enum Msg {
+    Add(String),
+    Mounted,
+}
+
+impl Application for Todos {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Add(item) => self.items.push(item),
+            Msg::Mounted => self.mounted += 1,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        ul(
+            vec![id("reentrant-todos")],
+            self.items
+                .iter()
+                .map(|item| li(vec![on_mount(|_| Msg::Mounted)], vec![text(item)])),
+        )
+    }
+}
+
+fn rendered_items() -> u32 {
+    sauron_core::dom::document()
+        .query_selector_all("#reentrant-todos li")
+        .expect("must query")
+        .length()
+}
+
+#[wasm_bindgen_test]
+async fn render_requested_from_a_lifecycle_callback() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(Todos::default());
+    let redraw_program = (*program).clone();
+    // redraws synchronously while the patches of the current render are applied
+    program.on_after_render(move |_| redraw_program.redraw());
+
+    program.dispatch(Msg::Add("write tests".to_string()));
+    delay(50).await;
+    program.dispatch(Msg::Add("fix bugs".to_string()));
+    delay(50).await;
+
+    assert_eq!(rendered_items(), 2);
+    // the on_mount of each item dispatched a msg while the DOM was being patched
+    assert_eq!(program.app().mounted, 2);
+}
@@ -0,0 +1,100 @@
+#![deny(warnings)]
+use sauron::{dom::delay, *};
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// a resource which counts how many times it has been dropped
+struct Tracked(Rc<Cell<usize>>);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+struct Panels {
+    panels: Vec<&'static str>,
+}
+
+enum Msg {
+    Set(Vec<&'static str>),
+}
+
+impl Application for Panels {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Set(panels) => self.panels = panels,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div(
+            [id("resource-panels")],
+            self.panels
+                .iter()
+                .map(|panel| div([key(*panel), id(format!("panel-{panel}"))], [text(panel)])),
+        )
+    }
+}
+
+fn panel(name: &str) -> web_sys::Element {
+    sauron_core::dom::document()
+        .get_element_by_id(&format!("panel-{name}"))
+        .expect("must exist")
+}
+
+#[wasm_bindgen_test]
+async fn resources_are_released_with_their_element() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(Panels {
+        panels: vec!["chart", "map", "feed"],
+    });
+    let dropped = Rc::new(Cell::new(0));
+    for name in ["chart", "map", "feed"] {
+        program.register_resource(&panel(name), Tracked(Rc::clone(&dropped)));
+    }
+    assert_eq!(program.resource_count(), 3);
+
+    // the reordered keyed elements are moved, and keep their resources
+    program.dispatch(Msg::Set(vec!["feed", "chart", "map"]));
+    delay(50).await;
+    assert_eq!(dropped.get(), 0);
+    assert_eq!(program.resource_count(), 3);
+
+    program.dispatch(Msg::Set(vec!["feed", "chart"]));
+    delay(50).await;
+    assert_eq!(dropped.get(), 1);
+    assert_eq!(program.resource_count(), 2);
+
+    program.unmount();
+    assert_eq!(dropped.get(), 3);
+    assert_eq!(program.resource_count(), 0);
+    assert!(sauron_core::dom::document()
+        .get_element_by_id("resource-panels")
+        .is_none());
+}
+
+#[wasm_bindgen_test]
+async fn unmounted_program_is_not_rendered_again() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(Panels {
+        panels: vec!["chart"],
+    });
+    program.unmount();
+
+    // a msg which arrives after the unmount, such as from a running cmd, is dropped
+    program.dispatch(Msg::Set(vec!["chart", "map"]));
+    delay(50).await;
+    program.redraw();
+    assert!(sauron_core::dom::document()
+        .get_element_by_id("resource-panels")
+        .is_none());
+    assert_eq!(program.app().panels, vec!["chart"]);
+}
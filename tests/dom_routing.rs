@@ -0,0 +1,156 @@
+#![deny(warnings)]
+use sauron::{
+    dom::{current_url, delay, MountProcedure},
+    *,
+};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Page {
+    Home,
+    Item(usize),
+    NotFound(String),
+}
+
+struct App {
+    page: Page,
+    /// the url of the page which the app is started from, where the test returns to
+    start_url: String,
+}
+
+enum Msg {
+    UrlChanged(String),
+    ShowItem(usize),
+}
+
+impl Page {
+    fn from_url(url: &str, start_url: &str) -> Self {
+        if url == start_url {
+            return Page::Home;
+        }
+        match url.strip_prefix("/items/").map(str::parse) {
+            Some(Ok(item)) => Page::Item(item),
+            _ => Page::NotFound(url.to_string()),
+        }
+    }
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::UrlChanged(url) => self.page = Page::from_url(&url, &self.start_url),
+            Msg::ShowItem(item) => self.page = Page::Item(item),
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div(
+            [id("routing-app")],
+            [
+                a([id("item-link"), href("/items/2")], [text("item 2")]),
+                a([id("missing-link"), href("/missing")], [text("missing")]),
+                a(
+                    [id("new-tab-link"), href("/items/3"), target("_blank")],
+                    [text("item 3 in a new tab")],
+                ),
+                button(
+                    [id("show-item"), on_click(|_| Msg::ShowItem(7))],
+                    [text("show")],
+                ),
+                text(format!("{:?}", self.page)),
+            ],
+        )
+    }
+
+    fn route(&self) -> Option<String> {
+        Some(match &self.page {
+            Page::Home => self.start_url.clone(),
+            Page::Item(item) => format!("/items/{item}"),
+            Page::NotFound(url) => url.clone(),
+        })
+    }
+
+    fn on_route_change(&self, url: &str) -> Option<Msg> {
+        Some(Msg::UrlChanged(url.to_string()))
+    }
+}
+
+fn click(element_id: &str) {
+    let element: web_sys::HtmlElement = sauron_core::dom::document()
+        .get_element_by_id(element_id)
+        .expect("must exist")
+        .unchecked_into();
+    element.click();
+}
+
+/// stop the default action of the clicks which are left to the browser,
+/// so the test page doesn't navigate away
+fn prevent_navigation(element_id: &str) {
+    let closure = Closure::<dyn FnMut(web_sys::Event)>::new(|event: web_sys::Event| {
+        event.prevent_default();
+    });
+    sauron_core::dom::document()
+        .get_element_by_id(element_id)
+        .expect("must exist")
+        .add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
+        .expect("must add listener");
+    closure.forget();
+}
+
+#[wasm_bindgen_test]
+async fn links_and_history_change_the_route() {
+    console_error_panic_hook::set_once();
+
+    let start_url = current_url();
+    let mut program = Program::new(App {
+        page: Page::NotFound(String::new()),
+        start_url: start_url.clone(),
+    });
+    program.mount(
+        &sauron_core::dom::document().body().unwrap(),
+        MountProcedure::append(),
+    );
+    delay(50).await;
+    // the app is updated with the url it is started from
+    assert_eq!(program.app().page, Page::Home);
+
+    click("item-link");
+    delay(50).await;
+    assert_eq!(program.app().page, Page::Item(2));
+    assert_eq!(current_url(), "/items/2");
+
+    // an url which the app doesn't recognize
+    click("missing-link");
+    delay(50).await;
+    assert_eq!(program.app().page, Page::NotFound("/missing".to_string()));
+    assert_eq!(current_url(), "/missing");
+
+    // the link to a new tab is left to the browser
+    prevent_navigation("new-tab-link");
+    click("new-tab-link");
+    delay(50).await;
+    assert_eq!(program.app().page, Page::NotFound("/missing".to_string()));
+
+    // changing the route in update pushes the url
+    click("show-item");
+    delay(50).await;
+    assert_eq!(program.app().page, Page::Item(7));
+    assert_eq!(current_url(), "/items/7");
+
+    // the back button
+    sauron_core::dom::history().back().expect("must go back");
+    delay(100).await;
+    assert_eq!(program.app().page, Page::NotFound("/missing".to_string()));
+    assert_eq!(current_url(), "/missing");
+
+    program.unmount();
+    sauron_core::dom::history()
+        .replace_state_with_url(&JsValue::NULL, "", Some(&start_url))
+        .expect("must restore the url");
+}
@@ -0,0 +1,93 @@
+#![deny(warnings)]
+use sauron::{
+    dom::{delay, SimulatedEvent},
+    html::attributes::value,
+    *,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Form {
+    clicks: usize,
+    name: String,
+    last_key: String,
+}
+
+enum Msg {
+    Click,
+    Name(String),
+    Key(String),
+}
+
+impl Application for Form {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Click => self.clicks += 1,
+            Msg::Name(name) => self.name = name,
+            Msg::Key(key) => self.last_key = key,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div(
+            vec![id("simulated-form")],
+            vec![
+                button(
+                    vec![class("increment"), on_click(|_| Msg::Click)],
+                    vec![text("+")],
+                ),
+                input(vec![
+                    class("name"),
+                    value(&self.name),
+                    on_input(|event| Msg::Name(event.value())),
+                    on_keydown(|event| Msg::Key(event.key())),
+                ]),
+                span(
+                    vec![class("summary")],
+                    vec![text(format!(
+                        "{} {} {}",
+                        self.clicks, self.name, self.last_key
+                    ))],
+                ),
+            ],
+        )
+    }
+}
+
+fn summary() -> Option<String> {
+    sauron_core::dom::document()
+        .query_selector("#simulated-form .summary")
+        .expect("must query")
+        .expect("must have the summary")
+        .text_content()
+}
+
+#[wasm_bindgen_test]
+async fn simulated_events_dispatch_msgs() {
+    console_error_panic_hook::set_once();
+
+    let program = Program::mount_to_body(Form::default());
+    program
+        .simulate_event(".increment", SimulatedEvent::Click)
+        .expect("must click");
+    program
+        .simulate_event(".increment", SimulatedEvent::Click)
+        .expect("must click");
+    program
+        .simulate_event(".name", SimulatedEvent::Input("ferris".to_string()))
+        .expect("must input");
+    program
+        .simulate_event(".name", SimulatedEvent::KeyDown("Enter".to_string()))
+        .expect("must press a key");
+    delay(50).await;
+    assert_eq!(summary().as_deref(), Some("2 ferris Enter"));
+
+    assert!(program
+        .simulate_event(".missing", SimulatedEvent::Click)
+        .is_err());
+}
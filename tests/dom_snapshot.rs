@@ -0,0 +1,32 @@
+#![deny(warnings)]
+use sauron::{html::*, vdom::SerializableNode, *};
+use test_fixtures::simple_program;
+use wasm_bindgen_test::*;
+
+mod test_fixtures;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn snapshot_is_the_current_view() {
+    console_error_panic_hook::set_once();
+
+    let view: Node<()> = main(
+        vec![class("app")],
+        vec![button(vec![on_click(|_| ())], vec![text("save")])],
+    );
+    let mut simple_program = simple_program();
+    simple_program
+        .update_dom_with_vdom(view.clone())
+        .expect("must update dom");
+
+    let snapshot = simple_program.snapshot();
+    assert_eq!(snapshot, view.to_serializable());
+    let SerializableNode::Element(main) = snapshot else {
+        panic!("must be an element");
+    };
+    let SerializableNode::Element(button) = &main.children[0] else {
+        panic!("must be an element");
+    };
+    assert!(button.attributes.is_empty());
+}
@@ -0,0 +1,71 @@
+#![deny(warnings)]
+use sauron::{dom::MountProcedure, *};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// count the style elements in the document head which contains exactly `style`
+fn injected_count(style: &str) -> usize {
+    let head = sauron_core::dom::document()
+        .head()
+        .expect("must have a head");
+    let styles = head.query_selector_all("style").expect("must query");
+    (0..styles.length())
+        .filter_map(|i| styles.item(i))
+        .filter(|node| node.text_content().as_deref() == Some(style))
+        .count()
+}
+
+struct Styled;
+
+impl Application for Styled {
+    type MSG = ();
+
+    fn update(&mut self, _msg: ()) -> Cmd<()> {
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<()> {
+        div(vec![class("styled")], vec![])
+    }
+
+    fn stylesheet() -> Vec<String> {
+        vec![".styled{color:red;}".to_string()]
+    }
+}
+
+struct Unstyled;
+
+impl Application for Unstyled {
+    type MSG = ();
+
+    fn update(&mut self, _msg: ()) -> Cmd<()> {
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<()> {
+        div(vec![class("unstyled")], vec![])
+    }
+
+    fn stylesheet() -> Vec<String> {
+        vec![".unstyled{color:blue;}".to_string()]
+    }
+}
+
+#[wasm_bindgen_test]
+fn stylesheet_is_injected_once_when_mounted_again() {
+    console_error_panic_hook::set_once();
+
+    Program::mount_to_body(Styled);
+    Program::mount_to_body(Styled);
+    assert_eq!(injected_count(".styled{color:red;}"), 1);
+}
+
+#[wasm_bindgen_test]
+fn style_injection_can_be_disabled() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::new(Unstyled).with_style_injection(false);
+    program.mount(&sauron_core::dom::util::body(), MountProcedure::append());
+    assert_eq!(injected_count(".unstyled{color:blue;}"), 0);
+}
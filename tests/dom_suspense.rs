@@ -0,0 +1,46 @@
+#![deny(warnings)]
+use sauron::{dom::delay, vdom::suspense, *};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct Profile {
+    profile: vdom::Suspense<()>,
+}
+
+impl Application for Profile {
+    type MSG = ();
+
+    fn update(&mut self, _msg: ()) -> Cmd<()> {
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<()> {
+        div(vec![id("suspense-profile")], vec![self.profile.view()])
+    }
+}
+
+#[wasm_bindgen_test]
+async fn resolved_node_replaces_the_pending_node() {
+    console_error_panic_hook::set_once();
+
+    let profile = suspense(text("loading.."), async {
+        delay(10).await;
+        span(vec![class("name")], vec![text("Alice")])
+    });
+    let _program = Program::mount_to_body(Profile {
+        profile: profile.clone(),
+    });
+
+    let content = || {
+        sauron_core::dom::document()
+            .get_element_by_id("suspense-profile")
+            .expect("must have the profile")
+            .inner_html()
+    };
+    assert_eq!(content(), "loading..");
+
+    delay(100).await;
+    assert!(profile.is_resolved());
+    assert_eq!(content(), r#"<span class="name">Alice</span>"#);
+}
@@ -0,0 +1,94 @@
+#![deny(warnings)]
+use sauron::{dom::delay, *};
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct Cart {
+    items: Vec<String>,
+    total: u32,
+    views: Rc<Cell<usize>>,
+}
+
+enum Msg {
+    Add(String, u32),
+}
+
+impl Application for Cart {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Add(item, price) => {
+                self.items.push(item);
+                self.total += price;
+            }
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        self.views.set(self.views.get() + 1);
+        div(
+            [id("transaction-cart")],
+            [text(format!("{}: {}", self.items.join(","), self.total))],
+        )
+    }
+}
+
+fn cart_text() -> String {
+    sauron_core::dom::document()
+        .get_element_by_id("transaction-cart")
+        .expect("must have the cart")
+        .text_content()
+        .expect("must have text")
+}
+
+#[wasm_bindgen_test]
+async fn transaction_renders_once() {
+    console_error_panic_hook::set_once();
+
+    let views = Rc::new(Cell::new(0));
+    let mut program = Program::mount_to_body(Cart {
+        items: vec![],
+        total: 0,
+        views: Rc::clone(&views),
+    });
+    delay(50).await;
+    let views_before = views.get();
+
+    let mut dispatcher = (*program).clone();
+    let count = program
+        .transaction(|cart| {
+            cart.items.push("apple".to_string());
+            cart.total += 3;
+            // queued until the transaction is done
+            dispatcher.dispatch(Msg::Add("pear".to_string(), 4));
+            cart.items.push("fig".to_string());
+            cart.total += 5;
+            cart.items.len()
+        })
+        .expect("must not be borrowed");
+    assert_eq!(count, 2);
+    assert_eq!(views.get(), views_before + 1);
+    assert_eq!(cart_text(), "apple,fig: 8");
+
+    delay(50).await;
+    assert_eq!(cart_text(), "apple,fig,pear: 12");
+}
+
+#[wasm_bindgen_test]
+fn transaction_errors_when_the_app_is_borrowed() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::new(Cart {
+        items: vec![],
+        total: 0,
+        views: Rc::new(Cell::new(0)),
+    });
+    let other = program.clone();
+    let _cart = other.app();
+    assert!(program.transaction(|cart| cart.total += 1).is_err());
+}
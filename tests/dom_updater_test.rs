@@ -51,16 +51,13 @@ fn updates_active_closure_on_replace() {
 
     let elem_id = "update-active-closures-on-replace";
 
-    let replace_node = input(
-        vec![
-            id(elem_id),
-            on_input(move |event: sauron_core::html::events::InputEvent| {
-                *text_clone.borrow_mut() = event.value();
-            }),
-            value("End Text"),
-        ],
-        vec![],
-    );
+    let replace_node = input(vec![
+        id(elem_id),
+        on_input(move |event: sauron_core::html::events::InputEvent| {
+            *text_clone.borrow_mut() = event.value();
+        }),
+        value("End Text"),
+    ]);
 
     // New node replaces old node.
     // We are testing that we've stored this new node's closures even though `new` will be dropped
@@ -105,16 +102,13 @@ async fn updates_active_closures_on_append() {
     {
         let append_node = div(
             vec![],
-            vec![input(
-                vec![
-                    id(elem_id),
-                    on_input(move |event: sauron_core::html::events::InputEvent| {
-                        *text_clone.borrow_mut() = event.value();
-                    }),
-                    value("End Text"),
-                ],
-                vec![],
-            )],
+            vec![input(vec![
+                id(elem_id),
+                on_input(move |event: sauron_core::html::events::InputEvent| {
+                    *text_clone.borrow_mut() = event.value();
+                }),
+                value("End Text"),
+            ])],
         );
 
         // New node gets appended into the DOM.
@@ -0,0 +1,49 @@
+#![deny(warnings)]
+use sauron::{html::attributes::*, *};
+
+#[test]
+fn aria_states_render_explicit_values() {
+    let view: Node<()> = div(
+        [aria_expanded(true), aria_hidden(false), aria_busy(false)],
+        [
+            button([aria_pressed(Some(true))], [text("on")]),
+            button([aria_pressed(Some(false))], [text("off")]),
+            div(
+                [attr("role", "checkbox"), aria_checked(None)],
+                [text("some")],
+            ),
+            div(
+                [
+                    attr("role", "tab"),
+                    aria_selected(false),
+                    aria_disabled(true),
+                ],
+                [],
+            ),
+        ],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        concat!(
+            r#"<div aria-expanded="true" aria-hidden="false" aria-busy="false">"#,
+            r#"<button aria-pressed="true">on</button>"#,
+            r#"<button aria-pressed="false">off</button>"#,
+            r#"<div role="checkbox" aria-checked="mixed">some</div>"#,
+            r#"<div role="tab" aria-selected="false" aria-disabled="true"></div>"#,
+            r#"</div>"#,
+        )
+    );
+}
+
+#[test]
+fn false_aria_state_is_kept_as_a_value() {
+    let view: Node<()> = input([
+        aria_required(false),
+        aria_invalid(false),
+        aria_readonly(false),
+    ]);
+    let element = view.element_ref().expect("must be an element");
+    for name in ["aria-required", "aria-invalid", "aria-readonly"] {
+        assert_eq!(element.first_value(&name), Some(&Value::from("false")));
+    }
+}
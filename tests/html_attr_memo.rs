@@ -0,0 +1,66 @@
+#![deny(warnings)]
+use sauron::{
+    html::attributes::{attr_lazy, attr_memo},
+    *,
+};
+use std::cell::Cell;
+
+#[test]
+fn attr_lazy_is_evaluated_right_away() {
+    let calls = Cell::new(0);
+    let attribute: Attribute<()> = attr_lazy("title", || {
+        calls.set(calls.get() + 1);
+        "computed"
+    });
+    assert_eq!(calls.get(), 1);
+    assert_eq!(attribute, title("computed"));
+}
+
+#[test]
+fn attr_memo_is_only_computed_when_the_key_changes() {
+    let calls = Cell::new(0);
+    let item_view = |id: usize, version: u32| -> Node<()> {
+        li(
+            [attr_memo((id, version), "title", || {
+                calls.set(calls.get() + 1);
+                format!("item {id} version {version}")
+            })],
+            [],
+        )
+    };
+    let list = |versions: &[u32]| -> Node<()> {
+        ul(
+            [],
+            versions
+                .iter()
+                .enumerate()
+                .map(|(id, version)| item_view(id, *version)),
+        )
+    };
+
+    let first = list(&[1, 1, 1]);
+    assert_eq!(calls.get(), 3);
+    // only the second item is changed
+    let second = list(&[1, 2, 1]);
+    assert_eq!(calls.get(), 4);
+    assert_eq!(
+        second.render_to_string(),
+        r#"<ul><li title="item 0 version 1"></li><li title="item 1 version 2"></li><li title="item 2 version 1"></li></ul>"#
+    );
+    assert_ne!(first, second);
+}
+
+#[test]
+fn attr_memo_in_different_places_dont_share_the_values() {
+    let heading: Attribute<()> = attr_memo(1, "title", || "heading");
+    let footer: Attribute<()> = attr_memo(1, "title", || "footer");
+    assert_eq!(heading, title("heading"));
+    assert_eq!(footer, title("footer"));
+}
+
+#[test]
+fn attr_memo_with_different_names_dont_share_the_values() {
+    let memo = |name| -> Attribute<()> { attr_memo(1, name, move || name) };
+    assert_eq!(memo("title"), title("title"));
+    assert_eq!(memo("alt"), alt("alt"));
+}
@@ -187,3 +187,68 @@ fn should_merge_classes_flag() {
         )
     );
 }
+
+#[test]
+fn attribute_values_from_rust_types() {
+    use std::borrow::Cow;
+    let name = String::from("sauron");
+    let view: Node<()> = div(
+        vec![
+            attr("data-id", 42_u16),
+            attr("data-offset", -3_i8),
+            attr("data-ratio", 1.0_f32),
+            attr("data-scale", 0.5_f64),
+            attr("data-key", 'k'),
+            attr("data-name", &name),
+            attr("data-kind", Cow::from("crate")),
+            attr("data-hidden", false),
+            attr("data-missing", None::<u64>),
+        ],
+        vec![],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        r#"<div data-id="42" data-offset="-3" data-ratio="1" data-scale="0.5" data-key="k" data-name="sauron" data-kind="crate" data-hidden="false"></div>"#
+    );
+}
+
+#[test]
+fn presence_attributes_are_rendered_without_value() {
+    use sauron::html::attributes::presence_attr;
+
+    let view: Node<()> = form(
+        vec![presence_attr("novalidate")],
+        vec![input(vec![
+            presence_attr("required"),
+            presence_attr("autofocus"),
+        ])],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        "<form novalidate><input required autofocus/></form>"
+    );
+}
+
+#[test]
+fn removing_a_presence_attribute_removes_it() {
+    use sauron::html::attributes::presence_attr;
+
+    let old: Node<()> = input(vec![presence_attr("required")]);
+    let new: Node<()> = input(vec![]);
+    assert_eq!(
+        diff(&old, &new),
+        vec![Patch::remove_attributes(
+            &"input",
+            TreePath::new([]),
+            vec![&presence_attr("required")]
+        )]
+    );
+}
+
+#[test]
+fn empty_values_of_non_boolean_attributes_are_kept() {
+    use sauron::html::attributes::presence_attr;
+
+    let view: Node<()> = img(vec![alt(""), presence_attr("ismap")]);
+    assert_eq!(view.render_to_string(), r#"<img alt="" ismap/>"#);
+}
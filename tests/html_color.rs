@@ -0,0 +1,68 @@
+#![deny(warnings)]
+use sauron::{
+    svg::attributes::{presentation, Presentation},
+    vdom::Color,
+    *,
+};
+
+#[test]
+fn colors_are_serialized_as_hex_or_rgba() {
+    assert_eq!(Color::rgb(0, 128, 255).to_string(), "#0080ff");
+    assert_eq!(Color::rgba(0, 128, 255, 1.0).to_string(), "#0080ff");
+    assert_eq!(
+        Color::rgba(0, 128, 255, 0.25).to_string(),
+        "rgba(0, 128, 255, 0.25)"
+    );
+}
+
+#[test]
+fn alpha_is_clamped() {
+    assert_eq!(Color::rgba(1, 2, 3, 1.5).alpha(), 1.0);
+    assert_eq!(Color::rgba(1, 2, 3, -0.5).alpha(), 0.0);
+    assert_eq!(Color::rgba(1, 2, 3, f32::NAN).alpha(), 1.0);
+}
+
+#[test]
+fn parse_hex_colors() {
+    assert_eq!(Color::hex("#fff"), Ok(Color::rgb(255, 255, 255)));
+    assert_eq!(Color::hex("#1A2b3C"), Ok(Color::rgb(26, 43, 60)));
+    assert_eq!(Color::hex("#0000"), Ok(Color::rgba(0, 0, 0, 0.0)));
+    assert_eq!(
+        Color::hex("#ff000080").map(|c| c.components()),
+        Ok((255, 0, 0))
+    );
+    assert_eq!("#abc".parse::<Color>(), Ok(Color::rgb(170, 187, 204)));
+
+    for invalid in ["fff", "#ff", "#fffff", "#ggg", "#+ff", "#ff\u{e9}"] {
+        assert!(Color::hex(invalid).is_err(), "{invalid} must be invalid");
+    }
+}
+
+#[test]
+fn colors_in_attributes_and_styles() {
+    let node: Node<()> = rect(
+        [
+            fill(Color::rgb(255, 0, 0)),
+            style! {stroke: Color::rgba(0, 0, 0, 0.5)},
+        ],
+        [],
+    );
+    assert_eq!(
+        node.render_to_string(),
+        r##"<rect fill="#ff0000" style="stroke:rgba(0, 0, 0, 0.5);"></rect>"##
+    );
+
+    let attrs: Vec<Attribute<()>> = presentation(&Presentation {
+        fill: Some(Color::hex("#0f0").unwrap().into()),
+        ..Default::default()
+    });
+    assert_eq!(attrs, vec![attr("fill", Color::rgb(0, 255, 0))]);
+}
+
+#[test]
+fn color_value_equality() {
+    let value: Value = Color::rgb(1, 2, 3).into();
+    assert_eq!(value, Value::Color(Color::rgb(1, 2, 3)));
+    assert_ne!(value, Value::from("#010203"));
+    assert_eq!(value.as_f64(), None);
+}
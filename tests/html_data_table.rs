@@ -0,0 +1,43 @@
+#![deny(warnings)]
+use sauron::{html::data_table, *};
+
+#[test]
+fn data_table_wraps_the_cells() {
+    let rows = [vec![1, 2], vec![3, 4]];
+    let node: Node<()> = data_table(
+        [],
+        [text("a"), text("b")],
+        rows.iter()
+            .map(|row| row.iter().map(text).collect::<Vec<_>>()),
+    );
+    let expected: Node<()> = table(
+        [],
+        [
+            thead([], [tr([], [th([], [text("a")]), th([], [text("b")])])]),
+            tbody(
+                [],
+                [
+                    tr([], [td([], [text(1)]), td([], [text(2)])]),
+                    tr([], [td([], [text(3)]), td([], [text(4)])]),
+                ],
+            ),
+        ],
+    );
+    assert_eq!(node, expected);
+}
+
+#[test]
+fn data_table_keeps_the_td_and_th_cells() {
+    let node: Node<()> = data_table(
+        [class("grid")],
+        [],
+        [[
+            th([scope("row")], [text("total")]),
+            td([class("sum")], [text(10)]),
+        ]],
+    );
+    assert_eq!(
+        node.render_to_string(),
+        r#"<table class="grid"><tbody><tr><th scope="row">total</th><td class="sum">10</td></tr></tbody></table>"#
+    );
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "chrono")]
+#![deny(warnings)]
+use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc};
+use sauron::{html::attributes::datetime_attr, *};
+
+#[test]
+fn date_time_with_offset() {
+    let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+    let meeting = offset.with_ymd_and_hms(2026, 10, 15, 8, 30, 0).unwrap();
+    let view: Node<()> = time([datetime_attr("datetime", &meeting)], [text("meeting")]);
+    assert_eq!(
+        view.render_to_string(),
+        r#"<time datetime="2026-10-15T08:30:00+02:00">meeting</time>"#
+    );
+
+    let utc = meeting.with_timezone(&Utc);
+    let view: Node<()> = time([datetime_attr("datetime", &utc)], []);
+    assert_eq!(
+        view.render_to_string(),
+        r#"<time datetime="2026-10-15T06:30:00Z"></time>"#
+    );
+}
+
+#[test]
+fn local_date_time_date_and_time() {
+    let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+    let time = NaiveTime::from_hms_milli_opt(9, 5, 0, 250).unwrap();
+    let view: Node<()> = div(
+        [],
+        [
+            input([
+                r#type("datetime-local"),
+                datetime_attr("value", &date.and_hms_opt(9, 5, 0).unwrap()),
+            ]),
+            input([r#type("date"), datetime_attr("min", &date)]),
+            input([r#type("time"), datetime_attr("value", &time)]),
+        ],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        concat!(
+            r#"<div><input type="datetime-local" value="2026-01-05T09:05:00"/>"#,
+            r#"<input type="date" min="2026-01-05"/>"#,
+            r#"<input type="time" value="09:05:00.250"/></div>"#
+        )
+    );
+}
+
+#[test]
+fn fraction_of_a_second_is_truncated_to_milliseconds() {
+    let clock = NaiveTime::from_hms_nano_opt(9, 5, 0, 123_456_789).unwrap();
+    let date_time = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap().and_time(clock);
+    let utc = Utc.from_utc_datetime(&date_time);
+    // less than a millisecond is left out
+    let below_millis = NaiveTime::from_hms_nano_opt(9, 5, 0, 999).unwrap();
+    let view: Node<()> = div(
+        [],
+        [
+            input([r#type("time"), datetime_attr("value", &clock)]),
+            input([r#type("datetime-local"), datetime_attr("value", &date_time)]),
+            input([r#type("time"), datetime_attr("value", &below_millis)]),
+            time([datetime_attr("datetime", &utc)], []),
+        ],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        concat!(
+            r#"<div><input type="time" value="09:05:00.123"/>"#,
+            r#"<input type="datetime-local" value="2026-01-05T09:05:00.123"/>"#,
+            r#"<input type="time" value="09:05:00"/>"#,
+            r#"<time datetime="2026-01-05T09:05:00.123Z"></time></div>"#
+        )
+    );
+}
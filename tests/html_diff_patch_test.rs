@@ -10,24 +10,18 @@ fn nodes_with_event_must_be_replaced() {
         println!("input event is triggered");
     });
 
-    let old: Node<()> = input(
-        vec![
-            // On input we'll set our Rc<RefCell<String>> value to the input elements value
-            id(elem_id),
-            value("End Text"),
-            event1.clone(),
-        ],
-        vec![],
-    );
-
-    let new = input(
-        vec![
-            // On input we'll set our Rc<RefCell<String>> value to the input elements value
-            id(elem_id),
-            value("End Text"),
-        ],
-        vec![],
-    );
+    let old: Node<()> = input(vec![
+        // On input we'll set our Rc<RefCell<String>> value to the input elements value
+        id(elem_id),
+        value("End Text"),
+        event1.clone(),
+    ]);
+
+    let new = input(vec![
+        // On input we'll set our Rc<RefCell<String>> value to the input elements value
+        id(elem_id),
+        value("End Text"),
+    ]);
     let patch = diff(&old, &new);
     println!("patch: {:#?}", patch);
 
@@ -0,0 +1,32 @@
+#![deny(warnings)]
+use sauron::{html::entity_text, *};
+
+#[test]
+fn entities_are_emitted_verbatim() {
+    let view: Node<()> = p(
+        vec![],
+        vec![entity_text("&copy;&nbsp;2024 &#8212; &#x2014;")],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        "<p>&copy;&nbsp;2024 &#8212; &#x2014;</p>"
+    );
+}
+
+#[test]
+fn markup_and_stray_ampersands_are_escaped() {
+    let view: Node<()> = entity_text("<script>a && b;</script> &nbsp &amp;");
+    assert_eq!(
+        view.render_to_string(),
+        "&lt;script&gt;a &amp;&amp; b;&lt;/script&gt; &amp;nbsp &amp;"
+    );
+}
+
+#[test]
+fn entity_text_survives_sanitize() {
+    let view: Node<()> = div(vec![], vec![entity_text("Tom & Jerry&trade;")]);
+    assert_eq!(
+        view.sanitize().render_to_string(),
+        "<div>Tom &amp; Jerry&trade;</div>"
+    );
+}
@@ -0,0 +1,60 @@
+#![deny(warnings)]
+use sauron::{
+    html::{
+        attributes::{AutocompleteToken, InputMode},
+        lookup,
+    },
+    *,
+};
+
+#[test]
+fn typed_form_attributes() {
+    let view: Node<()> = form(
+        [],
+        [
+            input([
+                inputmode(InputMode::Email),
+                autocomplete(&[AutocompleteToken::Email]),
+            ]),
+            input([
+                autocomplete(&[AutocompleteToken::Billing, AutocompleteToken::CcNumber]),
+                inputmode(InputMode::Numeric),
+            ]),
+            input([autocomplete(&[AutocompleteToken::Off]), spellcheck(false)]),
+            textarea([spellcheck(true)], []),
+        ],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        concat!(
+            r#"<form><input inputmode="email" autocomplete="email"/>"#,
+            r#"<input autocomplete="billing cc-number" inputmode="numeric"/>"#,
+            r#"<input autocomplete="off" spellcheck="false"/>"#,
+            r#"<textarea spellcheck="true"></textarea></form>"#,
+        )
+    );
+}
+
+#[test]
+fn form_attributes_are_still_standard_attributes() {
+    for name in ["autocomplete", "inputmode", "spellcheck"] {
+        assert_eq!(lookup::match_attribute(name), Some(name));
+    }
+}
+
+#[test]
+fn form_attributes_can_still_be_set_from_a_string() {
+    let view: Node<()> = input([
+        attr("autocomplete", "off"),
+        attr("inputmode", "decimal"),
+        attr("spellcheck", "false"),
+    ]);
+    assert_eq!(
+        view,
+        input([
+            autocomplete(&[AutocompleteToken::Off]),
+            inputmode(InputMode::Decimal),
+            spellcheck(false),
+        ])
+    );
+}
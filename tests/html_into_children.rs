@@ -0,0 +1,31 @@
+#![deny(warnings)]
+use sauron::*;
+
+#[test]
+fn single_node_child() {
+    let view: Node<()> = p(vec![], text("hello"));
+    assert_eq!(view, p(vec![], vec![text("hello")]));
+}
+
+#[test]
+fn optional_child() {
+    let some: Node<()> = div(vec![], Some(span(vec![], vec![])));
+    let none: Node<()> = div(vec![], None);
+    assert_eq!(some.render_to_string(), "<div><span></span></div>");
+    assert_eq!(none.render_to_string(), "<div></div>");
+}
+
+#[test]
+fn vec_array_and_iterator_children() {
+    let from_vec: Node<()> = ul(vec![], vec![li(vec![], text("1")), li(vec![], text("2"))]);
+    let from_array: Node<()> = ul([], [li([], text("1")), li([], text("2"))]);
+    let from_iter: Node<()> = ul([], (1..=2).map(|i| li([], text(i))));
+    assert_eq!(from_vec, from_array);
+    assert_eq!(from_vec, from_iter);
+}
+
+#[test]
+fn builder_accepts_a_single_child() {
+    let view: Node<()> = div(vec![], vec![]).with_children(text("child"));
+    assert_eq!(view.render_to_string(), "<div>child</div>");
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "serde")]
+#![deny(warnings)]
+use sauron::{html::attributes::json_attr, *};
+use std::collections::BTreeMap;
+
+#[test]
+fn json_attr_encodes_the_value() {
+    let config = BTreeMap::from([("columns", 3), ("rows", 2)]);
+    let view: Node<()> = div([json_attr("data-config", &config)], []);
+    assert_eq!(
+        view.render_to_string(),
+        r#"<div data-config="{&quot;columns&quot;:3,&quot;rows&quot;:2}"></div>"#
+    );
+}
+
+#[test]
+fn json_attr_escapes_the_quotes_and_ampersands_in_strings() {
+    let labels = vec![r#"say "hi""#, "salt & pepper", "<b>"];
+    let view: Node<()> = div([json_attr("data-labels", &labels)], []);
+    assert_eq!(
+        view.render_to_string(),
+        r#"<div data-labels="[&quot;say \&quot;hi\&quot;&quot;,&quot;salt &amp; pepper&quot;,&quot;<b>&quot;]"></div>"#
+    );
+}
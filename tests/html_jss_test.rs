@@ -153,3 +153,73 @@ fn test_jss_ns_with_media_query() {
             \n";
     assert_eq!(expected, css);
 }
+
+#[test]
+fn test_jss_nested_selectors() {
+    let css = jss!(
+        ".menu": {
+            display: "flex",
+            "&:hover": {
+                opacity: 1,
+            },
+            ".item" {
+                color: "red",
+                "&.active": {
+                    color: "blue",
+                },
+            },
+        },
+    );
+    let expected = ".menu {\n  display: flex;\n}\n\n.menu:hover {\n  opacity: 1;\n}\n\n.menu .item {\n  color: red;\n}\n\n.menu .item.active {\n  color: blue;\n}\n";
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_jss_nested_tag_selectors() {
+    let css = jss!(
+        ".menu": {
+            display: "flex",
+            "li": {
+                color: "red",
+                "a" {
+                    color: "blue",
+                },
+            },
+        },
+    );
+    let expected = ".menu {\n  display: flex;\n}\n\n.menu li {\n  color: red;\n}\n\n.menu li a {\n  color: blue;\n}\n";
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_jss_media_query() {
+    let css = jss!(
+        ".layer": {
+            width: "50%",
+            "@media screen and (max-width: 800px)": {
+                width: "100%",
+            },
+        },
+        "@media print": {
+            ".layer": {
+                display: "none",
+            },
+        },
+    );
+    let expected = ".layer {\n  width: 50%;\n}\n\n@media screen and (max-width: 800px) {\n.layer {\n  width: 100%;\n}\n\n}\n\n@media print {\n.layer {\n  display: none;\n}\n\n}\n";
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_jss_ns_scoped_class_names() {
+    let css = jss_ns!("app",
+        ".frame": {
+            display: "block",
+            "a[href$=\".pdf\"]": {
+                color: "red",
+            },
+        },
+    );
+    let expected = ".app__frame {\n  display: block;\n}\n\n.app__frame a[href$=\".pdf\"] {\n  color: red;\n}\n";
+    assert_eq!(expected, css);
+}
@@ -0,0 +1,41 @@
+#![deny(warnings)]
+use sauron::{
+    html::keyed_children,
+    vdom::{diff::*, patch::*},
+    *,
+};
+
+fn view(items: &[(usize, &str)]) -> Node<()> {
+    ul(
+        [],
+        keyed_children(items.iter().map(|(id, title)| (*id, li([], [text(title)])))),
+    )
+}
+
+#[test]
+fn keys_are_attached_to_the_children() {
+    let children: Vec<Node<()>> = keyed_children([("a", div([], [])), ("b", span([], []))]);
+    assert_eq!(children[0].first_value(&"key"), Some(&"a".into()));
+    assert_eq!(children[1].first_value(&"key"), Some(&"b".into()));
+}
+
+#[test]
+fn non_element_children_are_kept_unkeyed() {
+    let children: Vec<Node<()>> = keyed_children([(1, text("plain"))]);
+    assert_eq!(children, [text("plain")]);
+}
+
+#[test]
+fn reordered_children_are_moved() {
+    let old = view(&[(1, "apple"), (2, "banana"), (3, "cherry")]);
+    let new = view(&[(3, "cherry"), (2, "banana"), (1, "apple")]);
+
+    assert_eq!(
+        diff(&old, &new),
+        vec![Patch::move_before_node(
+            Some(&"li"),
+            TreePath::new([0]),
+            [TreePath::new([2]), TreePath::new([1])]
+        )]
+    );
+}
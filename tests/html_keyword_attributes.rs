@@ -0,0 +1,28 @@
+#![deny(warnings)]
+use sauron::{
+    html::attributes::{async_, for_, loop_, r#for, type_},
+    *,
+};
+
+#[test]
+fn keyword_attribute_aliases_render_the_attribute_name() {
+    let view: Node<()> = div(
+        [],
+        [
+            label([for_("email")], [text("Email")]),
+            input([id("email"), type_("email")]),
+            video([loop_("")], []),
+            script([async_(""), src("app.js")], []),
+        ],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        r#"<div><label for="email">Email</label><input id="email" type="email"/><video loop></video><script async src="app.js"></script></div>"#
+    );
+}
+
+#[test]
+fn aliases_are_the_same_as_the_raw_identifiers() {
+    assert_eq!(for_::<_, ()>("name"), r#for("name"));
+    assert_eq!(type_::<_, ()>("text"), r#type("text"));
+}
@@ -0,0 +1,63 @@
+#![deny(warnings)]
+use sauron::{
+    html::{ol_of, ol_of_with_attrs, ul_of, ul_of_with_attrs},
+    vdom::diff,
+    *,
+};
+
+#[test]
+fn items_are_wrapped_in_li() {
+    let node: Node<()> = ol_of(
+        vec![class("steps")],
+        vec![text("one"), li(vec![class("current")], vec![text("two")])],
+    );
+    assert_eq!(
+        node,
+        ol(
+            vec![class("steps")],
+            vec![
+                li(vec![], vec![text("one")]),
+                li(vec![class("current")], vec![text("two")]),
+            ]
+        )
+    );
+}
+
+#[test]
+fn empty_list() {
+    let node: Node<()> = ul_of(vec![], vec![]);
+    assert_eq!(node.render_to_string(), "<ul></ul>");
+}
+
+#[test]
+fn item_attributes_are_set_on_li() {
+    let node: Node<()> = ol_of_with_attrs(
+        vec![],
+        vec![
+            (vec![key(1)], text("one")),
+            (
+                vec![key(2), class("done")],
+                li(vec![id("two")], vec![text("two")]),
+            ),
+        ],
+    );
+    assert_eq!(
+        node.render_to_string(),
+        r#"<ol><li key="1">one</li><li id="two" key="2" class="done">two</li></ol>"#
+    );
+}
+
+#[test]
+fn keyed_items_are_diffed_by_key() {
+    let old: Node<()> = ul_of_with_attrs(
+        vec![],
+        vec![(vec![key(1)], text("one")), (vec![key(2)], text("two"))],
+    );
+    let new: Node<()> = ul_of_with_attrs(vec![], vec![(vec![key(2)], text("two"))]);
+    let patches = diff(&old, &new);
+    assert_eq!(patches.len(), 1);
+    assert_eq!(
+        patches[0],
+        Patch::remove_node(Some(&"li"), TreePath::new(vec![0]))
+    );
+}
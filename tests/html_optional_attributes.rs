@@ -0,0 +1,56 @@
+#![deny(warnings)]
+use sauron::{
+    html::{
+        attributes::{title, *},
+        *,
+    },
+    *,
+};
+
+#[test]
+fn none_value_is_not_rendered() {
+    let img_title: Option<&str> = None;
+    let view: Node<()> = img(vec![src("a.jpg"), title(img_title)]);
+    assert_eq!(view.render_to_string(), r#"<img src="a.jpg"/>"#);
+
+    let view: Node<()> = img(vec![src("a.jpg"), title(Some("hello"))]);
+    assert_eq!(
+        view.render_to_string(),
+        r#"<img src="a.jpg" title="hello"/>"#
+    );
+}
+
+#[test]
+fn some_to_none_removes_the_attribute() {
+    let old: Node<()> = div(vec![title(Some("hello"))], vec![]);
+    let new: Node<()> = div(vec![title(None::<String>)], vec![]);
+    assert_eq!(
+        diff(&old, &new),
+        vec![Patch::remove_attributes(
+            &"div",
+            TreePath::new(vec![]),
+            vec![&title(Some("hello"))]
+        )]
+    );
+}
+
+#[test]
+fn none_to_some_adds_the_attribute() {
+    let old: Node<()> = div(vec![title(None::<String>)], vec![]);
+    let new: Node<()> = div(vec![title(Some("hello"))], vec![]);
+    assert_eq!(
+        diff(&old, &new),
+        vec![Patch::add_attributes(
+            &"div",
+            TreePath::new(vec![]),
+            vec![&title(Some("hello"))]
+        )]
+    );
+}
+
+#[test]
+fn none_to_none_has_no_patches() {
+    let old: Node<()> = div(vec![title(None::<String>)], vec![]);
+    let new: Node<()> = div(vec![title(None::<String>)], vec![]);
+    assert_eq!(diff(&old, &new), vec![]);
+}
@@ -1,6 +1,7 @@
 use sauron::html::lookup::match_tag;
+use sauron::html::{b, text};
 use sauron::vdom::Node;
-use sauron_html_parser::parse_html;
+use sauron_html_parser::{html_template, parse_html};
 
 #[test]
 fn should_match_tags() {
@@ -41,3 +42,108 @@ fn test_node_list() {
     println!("render: {}", node.render_to_string());
     assert_eq!(expected, node.render_to_string());
 }
+
+/// parse the html, render it and parse it again which must result to the same tree
+fn assert_round_trip(html: &str) {
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    let rendered = node.render_to_string();
+    let reparsed: Node<()> = parse_html(&rendered)
+        .ok()
+        .flatten()
+        .expect("must parse again");
+    assert_eq!(node, reparsed, "not idempotent: {html} -> {rendered}");
+    assert_eq!(rendered, reparsed.render_to_string());
+}
+
+#[test]
+fn test_round_trip() {
+    assert_round_trip(r#"<div class="a b" id="x"><p>hello <b>world</b></p></div>"#);
+    assert_round_trip(r#"<ul><li>one</li><li>two</li></ul>"#);
+    assert_round_trip(r#"<p>one<br>two</p><hr/>"#);
+    assert_round_trip(r#"<div>a &lt; b &amp; c</div>"#);
+    assert_round_trip(r#"<div>text1<!--comment-->text2</div>"#);
+    assert_round_trip(r#"<div><p>unclosed</div>"#);
+    assert_round_trip(r#"<svg><circle cx="1"/></svg>"#);
+}
+
+#[test]
+fn test_round_trip_merges_duplicate_attributes() {
+    let html = r#"<div class="a" class="b">x</div>"#;
+    assert_round_trip(html);
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(node.render_to_string(), r#"<div class="a b">x</div>"#);
+}
+
+#[test]
+fn test_round_trip_escapes_attribute_values() {
+    let html = r#"<div title='say "hi"'><a href="?a=1&amp;b=2">link</a></div>"#;
+    assert_round_trip(html);
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(
+        node.render_to_string(),
+        r#"<div title="say &quot;hi&quot;"><a href="?a=1&amp;b=2">link</a></div>"#
+    );
+}
+
+#[test]
+fn test_round_trip_keeps_boolean_attributes() {
+    assert_round_trip(r#"<select><option value="1" selected>1</option></select>"#);
+    assert_round_trip(r#"<input disabled="disabled">"#);
+    assert_round_trip(r#"<input type="checkbox" checked>"#);
+    assert_round_trip(r#"<details open=""><summary>more</summary></details>"#);
+    let render = |html: &str| -> String {
+        let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+        node.render_to_string()
+    };
+    assert_eq!(
+        render(r#"<select><option value="1" selected>1</option></select>"#),
+        r#"<select><option value="1" selected>1</option></select>"#
+    );
+    assert_eq!(
+        render(r#"<input disabled="disabled">"#),
+        r#"<input disabled="disabled"/>"#
+    );
+    assert_eq!(
+        render(r#"<input type="checkbox" checked>"#),
+        r#"<input type="checkbox" checked/>"#
+    );
+    assert_eq!(
+        render(r#"<details open=""><summary>more</summary></details>"#),
+        r#"<details open><summary>more</summary></details>"#
+    );
+}
+
+#[test]
+fn html_template_fills_the_slots() {
+    let greeting = |name: &str| -> Node<()> {
+        html_template("<p class=\"greeting\">Hello {name}, you have {count} new {{messages}}</p>")
+            .fill([("name", b([], [text(name)])), ("count", text(3))])
+    };
+    assert_eq!(
+        greeting("Ana").render_to_string(),
+        r#"<p class="greeting">Hello <b>Ana</b>, you have 3 new {messages}</p>"#
+    );
+    // the skeleton is reused for the same markup
+    assert_eq!(
+        greeting("Bo").render_to_string(),
+        r#"<p class="greeting">Hello <b>Bo</b>, you have 3 new {messages}</p>"#
+    );
+}
+
+#[test]
+fn html_template_missing_and_repeated_slots() {
+    let node: Node<()> = html_template("<ul><li>{item}</li><li>{item}</li><li>{missing}</li></ul>")
+        .fill([("item", text("same"))]);
+    assert_eq!(
+        node.render_to_string(),
+        "<ul><li>same</li><li>same</li><li></li></ul>"
+    );
+}
+
+#[test]
+fn test_round_trip_keeps_empty_attribute_values() {
+    let html = r#"<img src="a.png" alt="">"#;
+    assert_round_trip(html);
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(node.render_to_string(), r#"<img src="a.png" alt=""/>"#);
+}
@@ -0,0 +1,61 @@
+#![deny(warnings)]
+use sauron::{
+    html::pre_text,
+    vdom::{diff, SerializeOptions},
+    *,
+};
+
+#[test]
+fn whitespace_is_not_normalized() {
+    let view: Node<()> = td(
+        vec![],
+        vec![
+            pre_text("  a  \n\tb"),
+            span(vec![], vec![text("  c  \n d")]),
+        ],
+    );
+    let options = SerializeOptions {
+        normalize_whitespace: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        view.render_to_string_with_options(&options),
+        "<td>  a  \n\tb<span> c d</span></td>"
+    );
+}
+
+#[test]
+fn pretty_html_does_not_indent_around_pre_text() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            p(vec![], vec![text("output")]),
+            pre_text("line 1\n  line 2\n"),
+            p(vec![], vec![text("end")]),
+        ],
+    );
+    assert_eq!(
+        view.render_to_string_pretty(),
+        "<div>\n  <p>output</p>line 1\n  line 2\n<p>end</p>\n</div>"
+    );
+}
+
+#[test]
+fn whitespace_only_pre_text_is_not_trimmed() {
+    let view: Node<()> = div(vec![], vec![text("  "), pre_text("  "), text("x")]);
+    assert_eq!(
+        view.trim_whitespace_text(),
+        div(vec![], vec![pre_text("  "), text("x")])
+    );
+}
+
+#[test]
+fn pre_text_is_diffed_like_text() {
+    let old: Node<()> = div(vec![], vec![pre_text("a\n b")]);
+    let same: Node<()> = div(vec![], vec![pre_text("a\n b")]);
+    let changed: Node<()> = div(vec![], vec![pre_text("a\n  b")]);
+    let plain: Node<()> = div(vec![], vec![text("a\n b")]);
+    assert!(diff(&old, &same).is_empty());
+    assert_eq!(diff(&old, &changed).len(), 1);
+    assert_eq!(diff(&old, &plain).len(), 1);
+}
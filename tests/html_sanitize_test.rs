@@ -0,0 +1,185 @@
+#![deny(warnings)]
+use sauron::{
+    html::{attributes::*, events::*, *},
+    vdom::{Leaf, Sanitizer},
+    *,
+};
+
+#[test]
+fn strips_event_listeners_and_handler_attributes() {
+    let view: Node<()> = div(
+        vec![
+            class("box"),
+            on_click(|_| ()),
+            attr("onmouseover", "alert(1)"),
+        ],
+        vec![text("hello")],
+    );
+    assert_eq!(
+        view.sanitize().render_to_string(),
+        r#"<div class="box">hello</div>"#
+    );
+}
+
+#[test]
+fn removes_blocked_tags_with_their_children() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            script(vec![], vec![text("alert(1)")]),
+            style(vec![], vec![text("body{}")]),
+            p(vec![], vec![text("safe")]),
+        ],
+    );
+    assert_eq!(view.sanitize().render_to_string(), "<div><p>safe</p></div>");
+}
+
+#[test]
+fn removes_unsafe_urls() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            a(vec![href("javascript:alert(1)")], vec![text("bad")]),
+            a(vec![href("  JaVa\tScript:alert(1)")], vec![text("bad")]),
+            a(vec![href("https://example.com")], vec![text("good")]),
+            a(vec![href("/relative/path?x=a:b")], vec![text("relative")]),
+        ],
+    );
+    assert_eq!(
+        view.sanitize().render_to_string(),
+        r#"<div><a>bad</a><a>bad</a><a href="https://example.com">good</a><a href="/relative/path?x=a:b">relative</a></div>"#
+    );
+}
+
+#[test]
+fn removes_non_entity_symbols() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            html::symbol("&nbsp;"),
+            Node::Leaf(Leaf::Symbol("<img>".into())),
+        ],
+    );
+    assert_eq!(view.sanitize().render_to_string(), "<div>&nbsp;</div>");
+}
+
+#[test]
+fn configurable_allowlist() {
+    let sanitizer = Sanitizer::new()
+        .with_allowed_tags(["div", "b"])
+        .with_allowed_url_scheme("data");
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            b(vec![], vec![text("bold")]),
+            i(vec![], vec![text("italic")]),
+            img(vec![src("data:image/png;base64,AAAA")]),
+        ],
+    );
+    assert_eq!(
+        view.sanitize_with(&sanitizer).render_to_string(),
+        "<div><b>bold</b></div>"
+    );
+    assert!(sanitizer.is_url_allowed("data:image/png;base64,AAAA"));
+}
+
+#[test]
+fn removes_svg_and_math() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            svg::svg(vec![], vec![]),
+            html::element("math", vec![], vec![]),
+            p(vec![], vec![text("safe")]),
+        ],
+    );
+    assert_eq!(view.sanitize().render_to_string(), "<div><p>safe</p></div>");
+}
+
+#[test]
+fn removes_unsafe_values_of_animated_urls() {
+    let sanitizer = Sanitizer {
+        blocked_tags: vec![],
+        ..Sanitizer::default()
+    };
+    let view: Node<()> = html::element(
+        "a",
+        vec![],
+        vec![
+            html::element(
+                "animate",
+                vec![
+                    attr("attributeName", "href"),
+                    attr("values", "https://example.com;javascript:alert(1)"),
+                ],
+                vec![],
+            ),
+            html::element(
+                "set",
+                vec![
+                    attr("attributeName", "href"),
+                    attr("to", "javascript:alert(1)"),
+                ],
+                vec![],
+            ),
+            html::element(
+                "set",
+                vec![attr("attributeName", "fill"), attr("to", "red")],
+                vec![],
+            ),
+        ],
+    );
+    assert_eq!(
+        view.sanitize_with(&sanitizer).render_to_string(),
+        r#"<a><animate attributeName="href"></animate><set attributeName="href"></set><set attributeName="fill" to="red"></set></a>"#
+    );
+}
+
+#[test]
+fn checks_each_candidate_of_srcset() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            img(vec![attr("srcset", "a.png 1x, javascript:alert(1) 2x")]),
+            img(vec![attr("srcset", "a.png,javascript:alert(1)")]),
+            img(vec![attr(
+                "srcset",
+                "a.png 1x, https://example.com/b,c.png 2x",
+            )]),
+        ],
+    );
+    assert_eq!(
+        view.sanitize().render_to_string(),
+        r#"<div><img/><img/><img srcset="a.png 1x, https://example.com/b,c.png 2x"/></div>"#
+    );
+}
+
+#[test]
+fn removes_unsafe_urls_in_style() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            p(
+                vec![attr("style", "background: url(javascript:alert(1))")],
+                vec![],
+            ),
+            p(
+                vec![attr("style", "background: URL( 'JavaScript:alert(1)' )")],
+                vec![],
+            ),
+            p(
+                vec![style! {background: "url(\"javascript:alert(1)\")"}],
+                vec![],
+            ),
+            p(
+                vec![attr("style", "background: url(\\6a avascript:alert(1))")],
+                vec![],
+            ),
+            p(vec![attr("style", "background: url(/bg.png)")], vec![]),
+        ],
+    );
+    assert_eq!(
+        view.sanitize().render_to_string(),
+        r#"<div><p></p><p></p><p></p><p></p><p style="background: url(/bg.png)"></p></div>"#
+    );
+}
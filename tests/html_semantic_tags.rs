@@ -0,0 +1,39 @@
+#![deny(warnings)]
+use sauron::html::{source, tags::slot, track};
+use sauron::*;
+
+#[test]
+fn less_common_semantic_tags() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            dialog(vec![attr("open", true)], vec![text("dialog")]),
+            details(vec![], vec![summary(vec![], vec![text("more")])]),
+            picture(
+                vec![],
+                vec![
+                    source(vec![attr("srcset", "a.webp")]),
+                    img(vec![src("a.png")]),
+                ],
+            ),
+            video(vec![], vec![track(vec![attr("kind", "captions")])]),
+            output(vec![], vec![text("1")]),
+            progress(vec![value(50), max(100)], vec![]),
+            meter(vec![value(0.5)], vec![]),
+            datalist(vec![id("list")], vec![]),
+            template(vec![], vec![slot(vec![name("content")], vec![])]),
+        ],
+    );
+    let expected = "<div>\
+        <dialog open=\"true\">dialog</dialog>\
+        <details><summary>more</summary></details>\
+        <picture><source srcset=\"a.webp\"/><img src=\"a.png\"/></picture>\
+        <video><track kind=\"captions\"/></video>\
+        <output>1</output>\
+        <progress value=\"50\" max=\"100\"></progress>\
+        <meter value=\"0.5\"></meter>\
+        <datalist id=\"list\"></datalist>\
+        <template><slot name=\"content\"></slot></template>\
+        </div>";
+    assert_eq!(view.render_to_string(), expected);
+}
@@ -0,0 +1,46 @@
+#![deny(warnings)]
+use sauron::{
+    html::attributes::{sizes_entries, srcset_entries, SrcsetDescriptor},
+    *,
+};
+
+#[test]
+fn width_descriptors() {
+    let view: Node<()> = img(vec![
+        src("photo-640.jpg"),
+        srcset_entries([
+            ("photo-640.jpg", SrcsetDescriptor::Width(640)),
+            ("photo-1280.jpg", SrcsetDescriptor::Width(1280)),
+        ]),
+        sizes_entries(
+            [
+                ("(max-width: 480px)", "100vw"),
+                ("(max-width: 960px)", "50vw"),
+            ],
+            "640px",
+        ),
+    ]);
+    assert_eq!(
+        view.render_to_string(),
+        r#"<img src="photo-640.jpg" srcset="photo-640.jpg 640w, photo-1280.jpg 1280w" sizes="(max-width: 480px) 100vw, (max-width: 960px) 50vw, 640px"/>"#
+    );
+}
+
+#[test]
+fn density_descriptors() {
+    let attr: Attribute<()> = srcset_entries([
+        ("icon.png".to_string(), SrcsetDescriptor::Density(1.0)),
+        ("icon@1.5x.png".to_string(), SrcsetDescriptor::Density(1.5)),
+        ("icon@2x.png".to_string(), SrcsetDescriptor::Density(2.0)),
+    ]);
+    assert_eq!(
+        attr.render_to_string(),
+        r#"srcset="icon.png 1x, icon@1.5x.png 1.5x, icon@2x.png 2x""#
+    );
+}
+
+#[test]
+fn sizes_with_only_the_default() {
+    let attr: Attribute<()> = sizes_entries(Vec::<(&str, &str)>::new(), "100vw");
+    assert_eq!(attr.render_to_string(), r#"sizes="100vw""#);
+}
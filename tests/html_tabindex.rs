@@ -0,0 +1,29 @@
+#![deny(warnings)]
+use sauron::{html::lookup, *};
+use sauron_html_parser::parse_html;
+
+#[test]
+fn tabindex_values() {
+    let view: Node<()> = ul(
+        [],
+        [
+            li([tabindex(0)], [text("in order")]),
+            li([tabindex(-1)], [text("focusable")]),
+            li([tabindex(2)], [text("ahead")]),
+        ],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        r#"<ul><li tabindex="0">in order</li><li tabindex="-1">focusable</li><li tabindex="2">ahead</li></ul>"#
+    );
+}
+
+#[test]
+fn tabindex_is_still_a_standard_attribute() {
+    assert_eq!(lookup::match_attribute("tabindex"), Some("tabindex"));
+    let parsed: Node<()> = parse_html(r#"<div tabindex="0">item</div>"#)
+        .ok()
+        .flatten()
+        .expect("must parse");
+    assert_eq!(parsed.render_to_string(), r#"<div tabindex="0">item</div>"#);
+}
@@ -60,10 +60,7 @@ fn test_macros_trailing_commas_in_attribute_and_children() {
 
 #[test]
 fn test_macros_with_lines() {
-    let html: Node<()> = div(
-        vec![class("class1"), class("class2")],
-        vec![input(vec![], vec![text("This is an input")])],
-    );
+    let html: Node<()> = div(vec![class("class1"), class("class2")], vec![input(vec![])]);
     let attrs = html.attributes().unwrap();
     println!("attrs: {:#?}", attrs);
 }
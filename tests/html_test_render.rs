@@ -21,10 +21,7 @@ fn test_checked() {
 fn test_self_closing_tag() {
     let view1: Node<()> = main(
         vec![class("container")],
-        vec![
-            input(vec![r#type("text")], vec![]),
-            img(vec![src("image1.jpg")], vec![]),
-        ],
+        vec![input(vec![r#type("text")]), img(vec![src("image1.jpg")])],
     );
 
     let expected = r#"<main class="container"><input type="text"/><img src="image1.jpg"/></main>"#;
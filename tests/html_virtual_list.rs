@@ -0,0 +1,41 @@
+#![deny(warnings)]
+use sauron::{
+    html::{virtual_list, VirtualListConfig},
+    *,
+};
+
+#[test]
+fn visible_range_is_clamped_to_the_items() {
+    let config = VirtualListConfig::new(100, 20.0, 200.0);
+    assert_eq!(config.visible_range(), 0..15);
+    assert_eq!(config.with_scroll_top(1900.0).visible_range(), 90..100);
+    assert_eq!(config.with_overscan(0).visible_range(), 0..10);
+    assert_eq!(VirtualListConfig::new(3, 20.0, 200.0).visible_range(), 0..3);
+    assert_eq!(VirtualListConfig::new(0, 20.0, 200.0).visible_range(), 0..0);
+}
+
+#[test]
+fn only_the_visible_items_are_rendered() {
+    let config = VirtualListConfig::new(100_000, 20.0, 100.0)
+        .with_scroll_top(2000.0)
+        .with_overscan(1);
+    let view: Node<()> = virtual_list(
+        config,
+        [class("rows")],
+        |_| (),
+        |index| text(format!("row {index}")),
+    );
+
+    let rows: Vec<_> = view
+        .descendants()
+        .filter_map(|node| node.as_text())
+        .collect();
+    assert_eq!(
+        rows,
+        ["row 99", "row 100", "row 101", "row 102", "row 103", "row 104", "row 105"]
+    );
+
+    let html = view.render_to_string();
+    assert!(html.contains("height:2000000px;"));
+    assert!(html.contains(r#"key="100" style="position:absolute;top:2000px;"#));
+}
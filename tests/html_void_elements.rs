@@ -0,0 +1,25 @@
+#![deny(warnings)]
+use sauron::{html::html_element, *};
+
+#[test]
+fn void_elements_only_take_attributes() {
+    let view: Node<()> = div(
+        [],
+        [
+            img([src("logo.png"), alt("logo")]),
+            br([]),
+            input([r#type("text")]),
+        ],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        r#"<div><img src="logo.png" alt="logo"/><br/><input type="text"/></div>"#
+    );
+}
+
+#[test]
+fn children_of_a_self_closing_element_are_dropped() {
+    let view: Node<()> = html_element(None, "img", [src("logo.png")], [text("logo")], true);
+    assert_eq!(view.children(), &[]);
+    assert_eq!(view.render_to_string(), r#"<img src="logo.png"/>"#);
+}
@@ -0,0 +1,22 @@
+#![deny(warnings)]
+use sauron::*;
+
+#[test]
+fn owned_attribute_value_outlives_the_node() {
+    let value = {
+        let link: Node<()> = a([href("/docs"), class("nav")], [text("docs")]);
+        link.get_attribute_value_owned(&"href")
+    };
+    assert_eq!(value, Some(Value::from("/docs")));
+}
+
+#[test]
+fn owned_attribute_value_of_element() {
+    let view: Node<()> = input([r#type("text"), value("sauron")]);
+    let element = view.element_ref().expect("must be an element");
+    assert_eq!(
+        element.get_attribute_value_owned(&"value"),
+        element.first_value(&"value").cloned()
+    );
+    assert_eq!(element.get_attribute_value_owned(&"placeholder"), None);
+}
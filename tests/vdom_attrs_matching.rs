@@ -0,0 +1,42 @@
+#![deny(warnings)]
+use sauron::*;
+
+#[test]
+fn attrs_matching_keeps_the_order_and_duplicates() {
+    let view: Node<()> = button(
+        [
+            attr("aria-label", "close"),
+            class("icon"),
+            attr("aria-pressed", false),
+            on_click(|_| ()),
+            attr("aria-label", "dismiss"),
+        ],
+        [],
+    );
+    let element = view.element_ref().expect("must be an element");
+    let aria: Vec<_> = element
+        .attrs_matching(|name| name.starts_with("aria-"))
+        .into_iter()
+        .map(|att| (att.name, att.value()[0].get_simple().map(|v| v.to_string())))
+        .collect();
+    assert_eq!(
+        aria,
+        [
+            ("aria-label", Some("close".to_string())),
+            ("aria-pressed", Some("false".to_string())),
+            ("aria-label", Some("dismiss".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn attrs_matching_includes_event_listeners() {
+    let view: Node<()> = input([r#type("text"), on_input(|_| ()), on_click(|_| ())]);
+    let element = view.element_ref().expect("must be an element");
+    let events = element.attrs_matching(|name| name == "input" || name == "click");
+    assert_eq!(events.len(), 2);
+    assert!(events.iter().all(|att| att.is_event_listener()));
+    assert!(element
+        .attrs_matching(|name| name.starts_with("data-"))
+        .is_empty());
+}
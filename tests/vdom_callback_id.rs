@@ -0,0 +1,41 @@
+#![deny(warnings)]
+use sauron::*;
+
+#[derive(Debug, PartialEq)]
+enum Msg {
+    Remove(usize),
+    Archive(usize),
+}
+
+/// the closure is created with a different type in each branch,
+/// which are the same logical handler
+fn row(item: usize, from_search: bool) -> Node<Msg> {
+    let on_remove: EventCallback<Msg> = if from_search {
+        EventCallback::with_id(("remove", item), move |_| Msg::Remove(item))
+    } else {
+        EventCallback::with_id(("remove", item), move |_| Msg::Remove(item))
+    };
+    li([attr("click", on_remove)], [text(item)])
+}
+
+#[test]
+fn same_id_callbacks_are_equal() {
+    assert!(diff(&row(1, true), &row(1, false)).is_empty());
+}
+
+#[test]
+fn different_id_callbacks_are_not_equal() {
+    let old = row(1, true);
+    let new = row(2, true);
+    assert!(!diff(&old, &new).is_empty());
+}
+
+#[test]
+fn callback_with_id_is_not_equal_to_callback_without_id() {
+    let with_id: EventCallback<Msg> = EventCallback::with_id(3, |_| Msg::Archive(3));
+    let without_id: EventCallback<Msg> = EventCallback::from(|_| Msg::Archive(3));
+    assert_ne!(with_id, without_id);
+    assert_eq!(with_id, with_id.clone());
+    let mapped = with_id.clone().map_msg(|msg| msg);
+    assert_eq!(mapped, with_id.map_msg(|msg| msg));
+}
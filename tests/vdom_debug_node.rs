@@ -0,0 +1,64 @@
+#![deny(warnings)]
+use sauron::{html::*, vdom::DebugNode, *};
+
+#[test]
+fn debug_node_ignores_callbacks_and_attribute_order() {
+    let first: Node<()> = div(
+        [class("card"), id("main"), on_click(|_| ())],
+        [input([r#type("text"), value("hello"), on_input(|_| ())])],
+    );
+    let second: Node<()> = div(
+        [on_click(|_| ()), id("main"), class("card")],
+        [input([value("hello"), on_input(|_| ()), r#type("text")])],
+    );
+    assert_eq!(first.to_debug(), second.to_debug());
+    assert_eq!(
+        first.to_debug(),
+        DebugNode::element(
+            "div",
+            [("id", "main"), ("class", "card")],
+            [DebugNode::element(
+                "input",
+                [("type", "text"), ("value", "hello")],
+                []
+            )]
+        )
+    );
+}
+
+#[test]
+fn debug_node_flattens_fragments_and_merges_attributes() {
+    let view: Node<()> = ul(
+        [class("list"), class("dense")],
+        [
+            li([], [text("one")]),
+            fragment([li([], [text("two")]), li([], [text("three")])]),
+            comment("end"),
+        ],
+    );
+    assert_eq!(
+        view.to_debug(),
+        DebugNode::element(
+            "ul",
+            [("class", "list dense")],
+            [
+                DebugNode::element("li", [], [DebugNode::text("one")]),
+                DebugNode::element("li", [], [DebugNode::text("two")]),
+                DebugNode::element("li", [], [DebugNode::text("three")]),
+                DebugNode::Comment("end".to_string()),
+            ]
+        )
+    );
+}
+
+#[test]
+fn debug_node_of_a_root_fragment() {
+    let view: Node<()> = fragment([span([], [text("a")]), text("b")]);
+    assert_eq!(
+        view.to_debug(),
+        DebugNode::Fragment(vec![
+            DebugNode::element("span", [], [DebugNode::text("a")]),
+            DebugNode::text("b"),
+        ])
+    );
+}
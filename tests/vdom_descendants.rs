@@ -0,0 +1,46 @@
+#![deny(warnings)]
+use sauron::{vdom::fragment, *};
+
+#[test]
+fn descendants_are_in_document_order() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            h1(vec![], vec![text("title")]),
+            ul(
+                vec![],
+                vec![li(vec![], vec![text("one")]), li(vec![], vec![text("two")])],
+            ),
+            fragment([span(vec![], vec![])]),
+        ],
+    );
+    let visited: Vec<String> = view
+        .descendants()
+        .map(|node| match (node.tag(), node.as_text()) {
+            (Some(tag), _) => tag.to_string(),
+            (None, Some(text)) => format!("'{text}'"),
+            (None, None) => "fragment".to_string(),
+        })
+        .collect();
+    assert_eq!(
+        visited,
+        vec!["h1", "'title'", "ul", "li", "'one'", "li", "'two'", "fragment", "span"]
+    );
+}
+
+#[test]
+fn descendant_elements_skips_the_leaf_nodes() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            text("intro"),
+            p(vec![], vec![text("body"), a(vec![href("/more")], vec![])]),
+        ],
+    );
+    let tags: Vec<&str> = view
+        .descendant_elements()
+        .map(|element| *element.tag())
+        .collect();
+    assert_eq!(tags, vec!["p", "a"]);
+    assert!(text::<()>("leaf").descendants().next().is_none());
+}
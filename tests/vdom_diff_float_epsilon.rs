@@ -0,0 +1,104 @@
+#![deny(warnings)]
+use sauron::{
+    html::attributes::styles,
+    svg::{attributes::*, *},
+    vdom::{diff, diff_with_options, DiffOptions},
+    *,
+};
+
+fn tolerant() -> DiffOptions {
+    DiffOptions {
+        float_epsilon: Some(1e-3),
+    }
+}
+
+#[test]
+fn exact_by_default() {
+    let old: Node<()> = rect(vec![x(10.0), y(20.0_f32)], vec![]);
+    let new: Node<()> = rect(vec![x(10.0000001), y(20.0_f32)], vec![]);
+    assert_eq!(diff(&old, &new).len(), 1);
+    assert_eq!(
+        diff_with_options(&old, &new, &DiffOptions::default()).len(),
+        1
+    );
+}
+
+#[test]
+fn float_changes_within_the_tolerance_are_ignored() {
+    let old: Node<()> = rect(vec![x(10.0), y(20.0_f32)], vec![]);
+    let new: Node<()> = rect(vec![x(10.0000001), y(20.0001_f32)], vec![]);
+    assert!(diff_with_options(&old, &new, &tolerant()).is_empty());
+}
+
+#[test]
+fn float_changes_beyond_the_tolerance_are_patched() {
+    let old: Node<()> = rect(vec![x(10.0), y(20.0)], vec![]);
+    let new: Node<()> = rect(vec![x(10.01), y(20.0)], vec![]);
+    let patches = diff_with_options(&old, &new, &tolerant());
+    assert_eq!(patches.len(), 1);
+}
+
+#[test]
+fn style_values_are_compared_with_the_tolerance() {
+    let old: Node<()> = circle(vec![styles([("opacity", 0.5)])], vec![]);
+    let same: Node<()> = circle(vec![styles([("opacity", 0.5000002)])], vec![]);
+    let changed: Node<()> = circle(vec![styles([("opacity", 0.6)])], vec![]);
+    assert!(diff_with_options(&old, &same, &tolerant()).is_empty());
+    assert_eq!(diff_with_options(&old, &changed, &tolerant()).len(), 1);
+}
+
+#[test]
+fn non_float_values_are_compared_exactly() {
+    let old: Node<()> = rect(vec![x(10), class("a")], vec![]);
+    let new: Node<()> = rect(vec![x(11), class("a")], vec![]);
+    assert_eq!(diff_with_options(&old, &new, &tolerant()).len(), 1);
+}
+
+#[test]
+fn skipped_values_are_kept_so_tiny_steps_do_not_drift() {
+    let options = tolerant();
+    let mut current: Node<()> = rect(vec![x(10.0)], vec![]);
+    let mut patched_at = vec![];
+    for step in 1..=4 {
+        let mut new: Node<()> = rect(vec![x(10.0 + 0.0006 * f64::from(step))], vec![]);
+        if !diff_with_options(&current, &new, &options).is_empty() {
+            patched_at.push(step);
+        }
+        options.keep_skipped_values(&current, &mut new);
+        current = new;
+    }
+    // the view is compared against the value in the DOM, which is moved away by
+    // more than the tolerance on the second step
+    assert_eq!(patched_at, vec![2, 4]);
+    assert_eq!(current, rect(vec![x(10.0024)], vec![]));
+}
+
+#[test]
+fn skipped_values_are_kept_in_the_reordered_keyed_children() {
+    let options = tolerant();
+    let old: Node<()> = svg(
+        vec![],
+        vec![
+            rect(vec![key("a"), x(1.0)], vec![]),
+            rect(vec![key("b"), x(2.0)], vec![]),
+        ],
+    );
+    let mut new: Node<()> = svg(
+        vec![],
+        vec![
+            rect(vec![key("b"), x(2.0001)], vec![]),
+            rect(vec![key("a"), x(1.5)], vec![]),
+        ],
+    );
+    options.keep_skipped_values(&old, &mut new);
+    assert_eq!(
+        new,
+        svg(
+            vec![],
+            vec![
+                rect(vec![key("b"), x(2.0)], vec![]),
+                rect(vec![key("a"), x(1.5)], vec![]),
+            ],
+        )
+    );
+}
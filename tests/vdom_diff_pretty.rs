@@ -0,0 +1,40 @@
+#![deny(warnings)]
+use sauron::{vdom::diff_pretty, *};
+
+#[test]
+fn same_trees_have_no_markers() {
+    let view: Node<()> = div(vec![class("app")], vec![text("hello")]);
+    assert_eq!(
+        diff_pretty(&view, &view),
+        "  <div class=\"app\">hello</div>"
+    );
+}
+
+#[test]
+fn changed_attributes_and_children_are_marked() {
+    let old: Node<()> = div(
+        vec![class("app")],
+        vec![
+            span(vec![], vec![text("name")]),
+            input(vec![r#type("text")]),
+        ],
+    );
+    let new: Node<()> = div(
+        vec![class("app")],
+        vec![
+            span(vec![], vec![text("name")]),
+            input(vec![r#type("password")]),
+            button(vec![], vec![text("login")]),
+        ],
+    );
+    let expected = [
+        "  <div class=\"app\">",
+        "    <span>name</span>",
+        "-   <input type=\"text\"/>",
+        "+   <input type=\"password\"/>",
+        "+   <button>login</button>",
+        "  </div>",
+    ]
+    .join("\n");
+    assert_eq!(diff_pretty(&old, &new), expected);
+}
@@ -199,3 +199,19 @@ fn replace_and_skip_in_sub_nodes() {
         )],
     );
 }
+
+#[test]
+fn changed_portal_target_must_replace_the_portal() {
+    let old: Node<()> = element("div", vec![attr("portal_target", "#a")], vec![]);
+    let new: Node<()> = element("div", vec![attr("portal_target", "#b")], vec![]);
+
+    let diff = diff(&old, &new);
+    assert_eq!(
+        diff,
+        vec![Patch::replace_node(
+            Some(&"div"),
+            TreePath::new(vec![]),
+            vec![&new]
+        )],
+    );
+}
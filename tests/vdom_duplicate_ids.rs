@@ -0,0 +1,25 @@
+#![deny(warnings)]
+use sauron::*;
+
+#[test]
+fn unique_ids_have_no_duplicates() {
+    let view: Node<()> = div(
+        vec![id("app")],
+        vec![input(vec![id("name")]), input(vec![id("age")])],
+    );
+    assert!(view.duplicate_ids().is_empty());
+}
+
+#[test]
+fn duplicate_ids_are_reported_once() {
+    let view: Node<()> = div(
+        vec![id("app")],
+        vec![
+            input(vec![id("name")]),
+            input(vec![id("name")]),
+            span(vec![id("app")], vec![]),
+            input(vec![id("name")]),
+        ],
+    );
+    assert_eq!(view.duplicate_ids(), vec!["name", "app"]);
+}
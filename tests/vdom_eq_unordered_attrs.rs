@@ -0,0 +1,58 @@
+#![deny(warnings)]
+use sauron::{
+    html::{
+        attributes::{title, *},
+        *,
+    },
+    *,
+};
+
+fn to_element(node: Node<()>) -> Element<()> {
+    node.take_element().expect("must be an element")
+}
+
+#[test]
+fn attribute_order_is_insignificant() {
+    let a = to_element(div(
+        vec![class("a"), id("x")],
+        vec![span(vec![title("t"), class("c")], vec![])],
+    ));
+    let b = to_element(div(
+        vec![id("x"), class("a")],
+        vec![span(vec![class("c"), title("t")], vec![])],
+    ));
+    assert_ne!(a, b);
+    assert!(a.eq_unordered_attrs(&b));
+}
+
+#[test]
+fn children_order_is_significant() {
+    let a = to_element(div(vec![], vec![text("1"), text("2")]));
+    let b = to_element(div(vec![], vec![text("2"), text("1")]));
+    assert!(!a.eq_unordered_attrs(&b));
+}
+
+#[test]
+fn duplicate_attributes_are_compared_as_multiset() {
+    let a = to_element(div(vec![class("a"), class("a"), class("b")], vec![]));
+    let b = to_element(div(vec![class("b"), class("a"), class("b")], vec![]));
+    let c = to_element(div(vec![class("a"), class("b"), class("a")], vec![]));
+    assert!(!a.eq_unordered_attrs(&b));
+    assert!(a.eq_unordered_attrs(&c));
+}
+
+#[test]
+fn optional_attribute_order_is_insignificant() {
+    let a = to_element(span(vec![title(Some("t")), class("c")], vec![]));
+    let b = to_element(span(vec![class("c"), title(Some("t"))], vec![]));
+    assert!(a.eq_unordered_attrs(&b));
+}
+
+#[test]
+fn optional_attribute_values_are_compared() {
+    let a = to_element(span(vec![title(Some("t")), class("c")], vec![]));
+    let b = to_element(span(vec![class("c"), title(None::<&str>)], vec![]));
+    let c = to_element(span(vec![class("c"), title(Some("u"))], vec![]));
+    assert!(!a.eq_unordered_attrs(&b));
+    assert!(!a.eq_unordered_attrs(&c));
+}
@@ -0,0 +1,30 @@
+#![deny(warnings)]
+use sauron::{
+    html::{fragment, node_list},
+    *,
+};
+use std::collections::BTreeSet;
+
+#[test]
+fn event_names_of_nested_fragments() {
+    let view: Node<()> = main(
+        [],
+        [
+            fragment([
+                button([on_click(|_| ()), on_mouseover(|_| ())], [text("save")]),
+                input([on_input(|_| ()), value("name")]),
+            ]),
+            node_list([form([on_submit(|_| ())], [])]),
+        ],
+    );
+    assert_eq!(
+        view.event_names(),
+        BTreeSet::from(["click", "input", "mouseover", "submit"])
+    );
+}
+
+#[test]
+fn no_event_names_without_listeners() {
+    let view: Node<()> = div([class("static"), id("content")], [text("hello")]);
+    assert!(view.event_names().is_empty());
+}
@@ -0,0 +1,22 @@
+#![deny(warnings)]
+use sauron::{html::attributes::*, *};
+
+#[test]
+fn get_events_returns_all_listeners_of_the_event() {
+    let view: Node<()> = button(
+        vec![
+            class("btn"),
+            on_click(|_| ()),
+            on_mouseover(|_| ()),
+            on_click(|_| ()),
+        ],
+        vec![text("click me")],
+    );
+    let element = view.element_ref().expect("must be an element");
+
+    assert_eq!(element.get_events(&"click").len(), 2);
+    assert_eq!(element.get_events(&"mouseover").len(), 1);
+    assert!(element.get_events(&"class").is_empty());
+    assert!(element.get_event(&"click").is_some());
+    assert!(element.get_event(&"keydown").is_none());
+}
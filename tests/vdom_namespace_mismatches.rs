@@ -0,0 +1,62 @@
+#![deny(warnings)]
+use sauron::{
+    html::html_element,
+    svg::{attributes::*, *},
+    *,
+};
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+#[test]
+fn well_formed_svg_has_no_mismatches() {
+    let view: Node<()> = div(
+        vec![],
+        vec![svg(
+            vec![],
+            vec![
+                circle(vec![r(5)], vec![]),
+                foreign_object(vec![], vec![p(vec![], vec![text("html in svg")])]),
+            ],
+        )],
+    );
+    assert!(view.namespace_mismatches().is_empty());
+}
+
+#[test]
+fn html_tag_in_svg_namespace() {
+    let view: Node<()> = svg(
+        vec![],
+        vec![html_element(
+            Some(SVG_NAMESPACE),
+            "div",
+            vec![],
+            vec![],
+            false,
+        )],
+    );
+    assert_eq!(
+        view.namespace_mismatches(),
+        ["`div` is an html element but is created in the svg namespace"]
+    );
+}
+
+#[test]
+fn svg_tag_without_namespace() {
+    let view: Node<()> = div(
+        vec![],
+        vec![html_element(None, "circle", vec![], vec![], false)],
+    );
+    assert_eq!(
+        view.namespace_mismatches(),
+        ["`circle` is an svg element but is created without the svg namespace"]
+    );
+}
+
+#[test]
+fn html_element_directly_inside_svg() {
+    let view: Node<()> = svg(vec![], vec![g(vec![], vec![span(vec![], vec![])])]);
+    assert_eq!(
+        view.namespace_mismatches(),
+        ["`span` is created in the html namespace inside the svg element `g`, put it inside a `foreignObject`"]
+    );
+}
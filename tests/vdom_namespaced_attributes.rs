@@ -0,0 +1,77 @@
+#![deny(warnings)]
+use sauron::{
+    svg::{self, attributes::xlink_href},
+    vdom::{patch::*, *},
+};
+
+const XLINK: &str = "http://www.w3.org/1999/xlink";
+
+#[test]
+fn namespaced_attribute_is_distinct_from_plain_attribute() {
+    let old: Node<()> = element("use", vec![attr_ns(Some(XLINK), "href", "#icon")], vec![]);
+    let new: Node<()> = element("use", vec![attr("href", "#icon")], vec![]);
+
+    assert_eq!(
+        diff(&old, &new),
+        vec![
+            Patch::add_attributes(&"use", TreePath::new(vec![]), vec![&attr("href", "#icon")]),
+            Patch::remove_attributes(
+                &"use",
+                TreePath::new(vec![]),
+                vec![&attr_ns(Some(XLINK), "href", "#icon")]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn namespaced_and_plain_attribute_side_by_side() {
+    let old: Node<()> = element(
+        "use",
+        vec![attr("href", "#a"), attr_ns(Some(XLINK), "href", "#a")],
+        vec![],
+    );
+    let new: Node<()> = element(
+        "use",
+        vec![attr("href", "#a"), attr_ns(Some(XLINK), "href", "#b")],
+        vec![],
+    );
+
+    assert_eq!(
+        diff(&old, &new),
+        vec![Patch::add_attributes(
+            &"use",
+            TreePath::new(vec![]),
+            vec![&attr_ns(Some(XLINK), "href", "#b")]
+        )]
+    );
+}
+
+#[test]
+fn unchanged_namespaced_attribute_has_no_patch() {
+    let old: Node<()> = svg::r#use(vec![xlink_href("#icon")], vec![]);
+    let new: Node<()> = svg::r#use(vec![xlink_href("#icon")], vec![]);
+    assert_eq!(diff(&old, &new), vec![]);
+}
+
+#[test]
+fn namespaced_attribute_is_rendered_with_its_prefix() {
+    let view: Node<()> = svg::r#use(vec![xlink_href("#icon"), attr("href", "#icon")], vec![]);
+    assert_eq!(
+        view.render_to_string(),
+        r##"<use xlink:href="#icon" href="#icon"></use>"##
+    );
+}
+
+#[test]
+fn attributes_from_pairs_share_the_namespace() {
+    let view: Node<()> = element(
+        "use",
+        sauron::html::attributes::attrs_ns(Some(XLINK), [("href", "#icon"), ("title", "icon")]),
+        vec![],
+    );
+    assert_eq!(
+        view.render_to_string(),
+        r##"<use xlink:href="#icon" xlink:title="icon"></use>"##
+    );
+}
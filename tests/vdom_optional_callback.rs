@@ -0,0 +1,24 @@
+#![deny(warnings)]
+use sauron::vdom::Callback;
+
+#[test]
+fn optional_callback_produces_no_output() {
+    let even = Callback::<i32, i32>::from_optional(|x| (x % 2 == 0).then_some(x));
+    assert_eq!(even.try_emit(4), Some(4));
+    assert_eq!(even.try_emit(3), None);
+}
+
+#[test]
+fn mapped_optional_callback_keeps_the_missing_output() {
+    let even = Callback::<i32, i32>::from_optional(|x| (x % 2 == 0).then_some(x));
+    let doubled = even.map_msg(|x| x * 2);
+    assert_eq!(doubled.try_emit(4), Some(8));
+    assert_eq!(doubled.try_emit(3), None);
+}
+
+#[test]
+fn callback_always_produces_an_output() {
+    let callback = Callback::<i32, i32>::from(|x| x + 1);
+    assert_eq!(callback.emit(1), 2);
+    assert_eq!(callback.try_emit(1), Some(2));
+}
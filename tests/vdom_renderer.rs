@@ -0,0 +1,261 @@
+#![deny(warnings)]
+use sauron::{
+    html::*,
+    vdom::{apply_patches, DebugNode, Renderer},
+    *,
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// a node of the in-memory backend
+#[derive(Debug)]
+enum MemoryNode {
+    Element {
+        tag: String,
+        attributes: Vec<(String, String)>,
+        children: Vec<Handle>,
+    },
+    Text(String),
+}
+
+type Handle = Rc<RefCell<MemoryNode>>;
+
+/// a backend which keeps the nodes in memory, to check the patches are applied
+/// the same way outside of the browser
+struct MemoryRenderer;
+
+fn position(children: &[Handle], target: &Handle) -> usize {
+    children
+        .iter()
+        .position(|child| Rc::ptr_eq(child, target))
+        .expect("must be a child")
+}
+
+fn with_children<R>(node: &Handle, f: impl FnOnce(&mut Vec<Handle>) -> R) -> R {
+    match &mut *node.borrow_mut() {
+        MemoryNode::Element { children, .. } => f(children),
+        MemoryNode::Text(_) => unreachable!("a text has no children"),
+    }
+}
+
+fn attribute_value(attr: &Attribute<()>) -> Option<String> {
+    let values: Vec<String> = attr
+        .value
+        .iter()
+        .filter_map(|value| value.get_simple())
+        .map(|value| value.to_string())
+        .collect();
+    (!values.is_empty()).then(|| values.join(" "))
+}
+
+fn to_debug(node: &Handle) -> DebugNode {
+    match &*node.borrow() {
+        MemoryNode::Element {
+            tag,
+            attributes,
+            children,
+        } => {
+            let mut attributes = attributes.clone();
+            attributes.sort();
+            DebugNode::Element {
+                tag: tag.clone(),
+                attributes,
+                children: children.iter().map(to_debug).collect(),
+            }
+        }
+        MemoryNode::Text(text) => DebugNode::text(text),
+    }
+}
+
+impl Renderer<()> for MemoryRenderer {
+    type Node = Handle;
+    type Attribute = (String, Option<String>);
+    type Error = String;
+
+    fn create_node(&self, node: &Node<()>) -> Handle {
+        let node = match node {
+            Node::Element(element) => MemoryNode::Element {
+                tag: element.tag().to_string(),
+                attributes: Attribute::merge_attributes_of_same_name(element.attributes().iter())
+                    .iter()
+                    .filter_map(|attr| Some((attr.name.to_string(), attribute_value(attr)?)))
+                    .collect(),
+                children: element
+                    .children()
+                    .iter()
+                    .map(|child| self.create_node(child))
+                    .collect(),
+            },
+            Node::Leaf(leaf) => MemoryNode::Text(leaf.as_text().unwrap_or_default().to_string()),
+        };
+        Rc::new(RefCell::new(node))
+    }
+
+    fn create_attribute(&self, attr: &Attribute<()>) -> (String, Option<String>) {
+        (attr.name.to_string(), attribute_value(attr))
+    }
+
+    fn child_node(&self, node: &Handle, index: usize) -> Option<Handle> {
+        match &*node.borrow() {
+            MemoryNode::Element { children, .. } => children.get(index).cloned(),
+            MemoryNode::Text(_) => None,
+        }
+    }
+
+    fn insert_before(
+        &self,
+        parent: &Handle,
+        target: &Handle,
+        nodes: Vec<Handle>,
+    ) -> Result<(), String> {
+        with_children(parent, |children| {
+            let idx = position(children, target);
+            children.splice(idx..idx, nodes);
+        });
+        Ok(())
+    }
+
+    fn insert_after(
+        &self,
+        parent: &Handle,
+        target: &Handle,
+        nodes: Vec<Handle>,
+    ) -> Result<(), String> {
+        with_children(parent, |children| {
+            let idx = position(children, target) + 1;
+            children.splice(idx..idx, nodes);
+        });
+        Ok(())
+    }
+
+    fn append_children(&self, target: &Handle, nodes: Vec<Handle>) -> Result<(), String> {
+        with_children(target, |children| children.extend(nodes));
+        Ok(())
+    }
+
+    fn clear_children(&self, target: &Handle) -> Result<(), String> {
+        with_children(target, |children| children.clear());
+        Ok(())
+    }
+
+    fn remove_node(&self, parent: &Handle, target: &Handle) -> Result<(), String> {
+        with_children(parent, |children| {
+            children.remove(position(children, target))
+        });
+        Ok(())
+    }
+
+    fn move_before(
+        &self,
+        parent: &Handle,
+        target: &Handle,
+        nodes: Vec<Handle>,
+    ) -> Result<(), String> {
+        with_children(parent, |children| {
+            children.retain(|child| !nodes.iter().any(|node| Rc::ptr_eq(child, node)))
+        });
+        self.insert_before(parent, target, nodes)
+    }
+
+    fn move_after(
+        &self,
+        parent: &Handle,
+        target: &Handle,
+        nodes: Vec<Handle>,
+    ) -> Result<(), String> {
+        with_children(parent, |children| {
+            children.retain(|child| !nodes.iter().any(|node| Rc::ptr_eq(child, node)))
+        });
+        self.insert_after(parent, target, nodes)
+    }
+
+    fn replace_node(
+        &self,
+        parent: &Handle,
+        target: &Handle,
+        replacement: Vec<Handle>,
+    ) -> Result<(), String> {
+        with_children(parent, |children| {
+            let idx = position(children, target);
+            children.splice(idx..=idx, replacement);
+        });
+        Ok(())
+    }
+
+    fn set_attributes(
+        &self,
+        target: &Handle,
+        attrs: Vec<(String, Option<String>)>,
+    ) -> Result<(), String> {
+        let MemoryNode::Element { attributes, .. } = &mut *target.borrow_mut() else {
+            return Err("only an element has attributes".to_string());
+        };
+        for (attr_name, value) in attrs {
+            attributes.retain(|(name, _)| *name != attr_name);
+            if let Some(value) = value {
+                attributes.push((attr_name, value));
+            }
+        }
+        Ok(())
+    }
+
+    fn remove_attributes(
+        &self,
+        target: &Handle,
+        attrs: Vec<(String, Option<String>)>,
+    ) -> Result<(), String> {
+        let MemoryNode::Element { attributes, .. } = &mut *target.borrow_mut() else {
+            return Err("only an element has attributes".to_string());
+        };
+        attributes.retain(|(name, _)| attrs.iter().all(|(attr_name, _)| attr_name != name));
+        Ok(())
+    }
+}
+
+/// render the `old` view in memory, apply the patches to the `new` view and return the result
+fn render_and_patch(old: &Node<()>, new: &Node<()>) -> DebugNode {
+    let renderer = MemoryRenderer;
+    let mount = renderer.create_node(&div([], []));
+    let root = renderer.create_node(old);
+    renderer
+        .append_children(&mount, vec![root.clone()])
+        .unwrap();
+    let patches = diff(old, new);
+    let root = apply_patches(&renderer, &mount, &root, &patches).unwrap();
+    to_debug(&root)
+}
+
+#[test]
+fn patches_attributes_and_children() {
+    let old: Node<()> = ul(
+        [class("list"), id("todos")],
+        [li([], [text("one")]), li([], [text("two")])],
+    );
+    let new: Node<()> = ul(
+        [class("list done"), on_click(|_| ())],
+        [
+            li([], [text("one")]),
+            li([], [text("2")]),
+            li([], [text("three")]),
+        ],
+    );
+    assert_eq!(render_and_patch(&old, &new), new.to_debug());
+}
+
+#[test]
+fn patches_keyed_children() {
+    let items = |keys: &[&'static str]| -> Node<()> {
+        ul([], keys.iter().map(|k| li([key(*k)], [text(k)])))
+    };
+    let old = items(&["a", "b", "c", "d"]);
+    let new = items(&["d", "a", "c"]);
+    assert_eq!(render_and_patch(&old, &new), new.to_debug());
+    let new = items(&["a", "b", "e", "c", "d"]);
+    assert_eq!(render_and_patch(&old, &new), new.to_debug());
+}
+
+#[test]
+fn replaces_the_root() {
+    let old: Node<()> = div([class("old")], [text("old")]);
+    let new: Node<()> = section([class("new")], [span([], [text("new")])]);
+    assert_eq!(render_and_patch(&old, &new), new.to_debug());
+}
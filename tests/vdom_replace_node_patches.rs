@@ -52,3 +52,73 @@ fn test_multiple_replace() {
         ]
     );
 }
+
+#[test]
+fn tag_change_replaces_the_element_without_patching_it() {
+    let children = || vec![element("b", vec![], vec![leaf("bold")]), leaf("text")];
+    let old: Node<()> = element("div", vec![attr("id", "x")], children());
+    let new: Node<()> = element("span", vec![attr("id", "x")], children());
+
+    let patches = diff(&old, &new);
+    assert_eq!(
+        patches,
+        vec![Patch::replace_node(
+            Some(&"div"),
+            TreePath::new(vec![]),
+            vec![&new]
+        )]
+    );
+}
+
+#[test]
+fn tag_change_of_a_child_only_replaces_the_child() {
+    let old: Node<()> = element(
+        "main",
+        vec![attr("class", "container")],
+        vec![
+            element("div", vec![attr("id", "x")], vec![leaf("same")]),
+            element("p", vec![], vec![leaf("untouched")]),
+        ],
+    );
+    let new_child = element("section", vec![attr("id", "x")], vec![leaf("same")]);
+    let new: Node<()> = element(
+        "main",
+        vec![attr("class", "container")],
+        vec![
+            new_child.clone(),
+            element("p", vec![], vec![leaf("untouched")]),
+        ],
+    );
+
+    let patches = diff(&old, &new);
+    assert_eq!(
+        patches,
+        vec![Patch::replace_node(
+            Some(&"div"),
+            TreePath::new(vec![0]),
+            vec![&new_child]
+        )]
+    );
+}
+
+#[test]
+fn namespace_change_replaces_the_element() {
+    let old: Node<()> = element("a", vec![attr("href", "#top")], vec![leaf("top")]);
+    let new: Node<()> = element_ns(
+        Some("http://www.w3.org/2000/svg"),
+        "a",
+        vec![attr("href", "#top")],
+        vec![leaf("top")],
+        false,
+    );
+
+    let patches = diff(&old, &new);
+    assert_eq!(
+        patches,
+        vec![Patch::replace_node(
+            Some(&"a"),
+            TreePath::new(vec![]),
+            vec![&new]
+        )]
+    );
+}
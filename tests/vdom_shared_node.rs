@@ -0,0 +1,60 @@
+#![deny(warnings)]
+use sauron::{
+    vdom::{diff, patch::*, shared},
+    *,
+};
+use std::rc::Rc;
+
+#[test]
+fn same_shared_node_has_no_patches() {
+    let chrome: Rc<Node<()>> = Rc::new(nav(
+        vec![class("chrome")],
+        (0..100).map(|i| a(vec![href(format!("/{i}"))], vec![text(i)])),
+    ));
+    let old: Node<()> = div(vec![], vec![shared(Rc::clone(&chrome)), text("old")]);
+    let new: Node<()> = div(vec![], vec![shared(Rc::clone(&chrome)), text("new")]);
+
+    assert_eq!(
+        diff(&old, &new),
+        vec![Patch::replace_node(
+            None,
+            TreePath::new(vec![1]),
+            vec![&text("new")]
+        )]
+    );
+}
+
+#[test]
+fn different_shared_nodes_are_diffed_by_content() {
+    let old_chrome: Rc<Node<()>> = Rc::new(nav(vec![class("old")], vec![]));
+    let new_chrome: Rc<Node<()>> = Rc::new(nav(vec![class("new")], vec![]));
+    let old: Node<()> = div(vec![], vec![shared(old_chrome)]);
+    let new: Node<()> = div(vec![], vec![shared(new_chrome)]);
+
+    assert_eq!(
+        diff(&old, &new),
+        vec![Patch::add_attributes(
+            &"nav",
+            TreePath::new(vec![0]),
+            vec![&class("new")]
+        )]
+    );
+}
+
+#[test]
+fn shared_node_is_diffed_against_an_unshared_node() {
+    let old: Node<()> = div(vec![], vec![shared(Rc::new(span(vec![], vec![])))]);
+    let new: Node<()> = div(vec![], vec![span(vec![], vec![])]);
+    assert!(diff(&old, &new).is_empty());
+}
+
+#[test]
+fn shared_node_is_rendered_in_place() {
+    let chrome: Rc<Node<()>> = Rc::new(header(vec![], vec![text("title")]));
+    let view: Node<()> = div(vec![], vec![shared(chrome), p(vec![], vec![text("body")])]);
+    assert_eq!(
+        view.render_to_string(),
+        "<div><header>title</header><p>body</p></div>"
+    );
+    assert_eq!(view.count_by_tag()["header"], 1);
+}
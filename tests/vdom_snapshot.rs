@@ -0,0 +1,60 @@
+#![deny(warnings)]
+use sauron::{
+    html::*,
+    vdom::{SerializableAttribute, SerializableElement, SerializableNode},
+    *,
+};
+
+fn attribute(name: &str, value: &str) -> SerializableAttribute {
+    SerializableAttribute {
+        namespace: None,
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn snapshot_keeps_the_structure_attributes_and_text() {
+    let view: Node<()> = div(
+        [class("card"), class("active"), style! {display: "flex"}],
+        [
+            input([r#type("text"), value("hello"), on_input(|_| ())]),
+            comment("note"),
+            text("body"),
+        ],
+    );
+    let expected = SerializableNode::Element(SerializableElement {
+        namespace: None,
+        tag: "div".to_string(),
+        attributes: vec![
+            attribute("class", "card active"),
+            attribute("style", "display:flex;"),
+        ],
+        children: vec![
+            SerializableNode::Element(SerializableElement {
+                namespace: None,
+                tag: "input".to_string(),
+                attributes: vec![attribute("type", "text"), attribute("value", "hello")],
+                children: vec![],
+                self_closing: true,
+            }),
+            SerializableNode::Comment("note".to_string()),
+            SerializableNode::Text("body".to_string()),
+        ],
+        self_closing: false,
+    });
+    assert_eq!(view.to_serializable(), expected);
+}
+
+#[test]
+fn snapshot_of_fragments_and_shared_nodes() {
+    let shared_node: Node<()> = vdom::shared(std::rc::Rc::new(text("shared")));
+    let view: Node<()> = vdom::fragment([html::symbol("&nbsp;"), shared_node]);
+    assert_eq!(
+        view.to_serializable(),
+        SerializableNode::Fragment(vec![
+            SerializableNode::Symbol("&nbsp;".to_string()),
+            SerializableNode::Text("shared".to_string()),
+        ])
+    );
+}
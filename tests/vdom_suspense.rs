@@ -0,0 +1,58 @@
+#![deny(warnings)]
+use sauron::{
+    html::main,
+    vdom::{diff, patch::*, suspense},
+    *,
+};
+
+#[test]
+fn pending_node_is_rendered_until_resolved() {
+    let profile: vdom::Suspense<()> = suspense(div(vec![class("spinner")], vec![]), async {
+        div(vec![class("profile")], vec![text("Alice")])
+    });
+    assert!(!profile.is_resolved());
+
+    let view: Node<()> = main(vec![], vec![profile.view()]);
+    assert_eq!(
+        view.render_to_string(),
+        r#"<main><div class="spinner"></div></main>"#
+    );
+}
+
+#[test]
+fn same_pending_suspense_has_no_patches() {
+    let profile: vdom::Suspense<()> = suspense(text("loading.."), async { text("done") });
+    let old: Node<()> = div(vec![], vec![profile.view()]);
+    let new: Node<()> = div(vec![], vec![profile.view()]);
+    assert_eq!(diff(&old, &new), vec![]);
+}
+
+#[test]
+fn new_suspense_replaces_the_old_node() {
+    let profile: vdom::Suspense<()> = suspense(text("loading.."), async { text("done") });
+    let old: Node<()> = div(vec![], vec![text("nothing")]);
+    let new: Node<()> = div(vec![], vec![profile.view()]);
+    assert_eq!(
+        diff(&old, &new),
+        vec![Patch::replace_node(
+            None,
+            TreePath::new(vec![0]),
+            vec![&new.children()[0]]
+        )]
+    );
+}
+
+#[test]
+fn pending_node_is_diffed_against_the_resolved_node() {
+    let profile: vdom::Suspense<()> = suspense(text("loading.."), async { text("done") });
+    let old: Node<()> = div(vec![], vec![profile.view()]);
+    let new: Node<()> = div(vec![], vec![text("done")]);
+    assert_eq!(
+        diff(&old, &new),
+        vec![Patch::replace_node(
+            None,
+            TreePath::new(vec![0]),
+            vec![&text("done")]
+        )]
+    );
+}
@@ -57,3 +57,23 @@ fn node_count6() {
     assert_eq!(6, old.node_count());
     assert_eq!(5, old.descendant_node_count());
 }
+
+#[test]
+fn count_by_tag() {
+    let view: Node<()> = element(
+        "ul",
+        vec![],
+        vec![
+            element("li", vec![], vec![leaf("1")]),
+            element("li", vec![], vec![element("b", vec![], vec![])]),
+            fragment([element("li", vec![], vec![]), element("b", vec![], vec![])]),
+        ],
+    );
+
+    let counts = view.count_by_tag();
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts["ul"], 1);
+    assert_eq!(counts["li"], 3);
+    assert_eq!(counts["b"], 2);
+    assert!(leaf::<()>("text").count_by_tag().is_empty());
+}
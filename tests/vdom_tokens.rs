@@ -0,0 +1,53 @@
+#![deny(warnings)]
+use sauron::{html::attributes::*, *};
+
+fn to_element(node: Node<()>) -> sauron::vdom::Element<()> {
+    match node {
+        Node::Element(element) => element,
+        _ => panic!("expecting an element"),
+    }
+}
+
+#[test]
+fn add_token() {
+    let mut element = to_element(a(vec![rel("noopener")], vec![]));
+    element.add_token("rel", "noreferrer");
+    element.add_token("rel", "noopener");
+    assert_eq!(element.tokens(&"rel"), vec!["noopener", "noreferrer"]);
+    assert_eq!(
+        Node::Element(element).render_to_string(),
+        r#"<a rel="noopener noreferrer"></a>"#
+    );
+}
+
+#[test]
+fn add_token_to_absent_attribute() {
+    let mut element = to_element(iframe(vec![], vec![]));
+    element.add_token("sandbox", "allow-scripts");
+    assert!(element.has_token(&"sandbox", "allow-scripts"));
+    assert_eq!(
+        Node::Element(element).render_to_string(),
+        r#"<iframe sandbox="allow-scripts"></iframe>"#
+    );
+}
+
+#[test]
+fn remove_token() {
+    let mut element = to_element(a(
+        vec![rel("noopener noreferrer"), rel("external"), class("link")],
+        vec![],
+    ));
+    element.remove_token(&"rel", "noreferrer");
+    assert_eq!(element.tokens(&"rel"), vec!["noopener", "external"]);
+
+    element.remove_token(&"rel", "noopener");
+    element.remove_token(&"rel", "external");
+    assert!(element.attribute_value(&"rel").is_none());
+
+    // removing from an absent attribute is a no-op
+    element.remove_token(&"sandbox", "allow-scripts");
+    assert_eq!(
+        Node::Element(element).render_to_string(),
+        r#"<a class="link"></a>"#
+    );
+}
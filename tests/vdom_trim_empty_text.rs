@@ -0,0 +1,58 @@
+#![deny(warnings)]
+use sauron::{vdom::fragment, *};
+
+#[test]
+fn empty_text_nodes_are_removed() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            text(""),
+            span(vec![], vec![text(""), text("hello")]),
+            text(" "),
+            fragment([text(""), text("world")]),
+        ],
+    );
+    let expected: Node<()> = div(
+        vec![],
+        vec![
+            span(vec![], vec![text("hello")]),
+            text(" "),
+            fragment([text("world")]),
+        ],
+    );
+    assert_eq!(view.trim_empty_text(), expected);
+}
+
+#[test]
+fn whitespace_only_text_nodes_are_removed() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            text("\n  "),
+            span(vec![], vec![text(" "), text("hello")]),
+            text(""),
+        ],
+    );
+    let expected: Node<()> = div(vec![], vec![span(vec![], vec![text("hello")])]);
+    assert_eq!(view.trim_whitespace_text(), expected);
+}
+
+#[test]
+fn whitespace_sensitive_elements_are_kept_intact() {
+    let view: Node<()> = div(
+        vec![],
+        vec![
+            pre(vec![], vec![text(""), text("  "), text("code")]),
+            textarea(vec![], vec![text(" ")]),
+        ],
+    );
+    assert_eq!(view.clone().trim_whitespace_text(), view);
+}
+
+#[test]
+fn separators_in_between_remaining_text_nodes_are_kept() {
+    let view: Node<()> = div(vec![], vec![text("a"), text("b"), text("")]);
+    let trimmed = view.trim_empty_text();
+    assert_eq!(trimmed, div(vec![], vec![text("a"), text("b")]));
+    assert_eq!(trimmed.render_to_string(), "<div>a<!--separator-->b</div>");
+}
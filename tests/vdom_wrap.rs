@@ -0,0 +1,28 @@
+#![deny(warnings)]
+use sauron::{html::*, *};
+
+#[test]
+fn wrap_an_element() {
+    let node: Node<()> = span([], [text("item")]).wrap("li", [class("entry")]);
+    let expected: Node<()> = li([class("entry")], [span([], [text("item")])]);
+    assert_eq!(node, expected);
+}
+
+#[test]
+fn wrap_a_text_node() {
+    let node: Node<()> = text("hello").wrap("p", []);
+    assert_eq!(node.render_to_string(), "<p>hello</p>");
+}
+
+#[test]
+fn wrap_can_be_chained() {
+    let card = |content: Node<()>| {
+        content
+            .wrap("div", [class("card-body")])
+            .wrap("div", [class("card")])
+    };
+    assert_eq!(
+        card(text("content")).render_to_string(),
+        r#"<div class="card"><div class="card-body">content</div></div>"#
+    );
+}